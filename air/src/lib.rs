@@ -269,6 +269,21 @@ impl PublicInputs {
             stack_outputs,
         }
     }
+
+    /// Returns the info of the program the proof is for.
+    pub fn program_info(&self) -> &ProgramInfo {
+        &self.program_info
+    }
+
+    /// Returns the initial state of the operand stack.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the final state of the operand stack.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
 }
 
 impl Serializable for PublicInputs {
@@ -94,7 +94,7 @@ impl Assembler {
             Instruction::Ext2Inv => ext2_ops::ext2_inv(span),
 
             // ----- u32 manipulation -------------------------------------------------------------
-            Instruction::U32Test => span.add_ops([Dup0, U32split, Swap, Drop, Eqz]),
+            Instruction::U32Test => u32_ops::u32test(span),
             Instruction::U32TestW => u32_ops::u32testw(span),
             Instruction::U32Assert => span.add_ops([Pad, U32assert2(ZERO), Drop]),
             Instruction::U32AssertWithError(err_code) => {
@@ -110,12 +110,16 @@ impl Assembler {
             }
 
             Instruction::U32Cast => span.add_ops([U32split, Drop]),
+            Instruction::U32FromBytes => u32_ops::u32frombytes(span),
+            Instruction::U32ToBytes => u32_ops::u32tobytes(span),
             Instruction::U32Split => span.add_op(U32split),
 
             Instruction::U32CheckedAdd => u32_ops::u32add(span, Checked, None),
             Instruction::U32CheckedAddImm(v) => u32_ops::u32add(span, Checked, Some(*v)),
             Instruction::U32OverflowingAdd => u32_ops::u32add(span, Overflowing, None),
             Instruction::U32OverflowingAddImm(v) => u32_ops::u32add(span, Overflowing, Some(*v)),
+            Instruction::U32SaturatingAdd => u32_ops::u32add(span, Saturating, None),
+            Instruction::U32SaturatingAddImm(v) => u32_ops::u32add(span, Saturating, Some(*v)),
             Instruction::U32WrappingAdd => u32_ops::u32add(span, Wrapping, None),
             Instruction::U32WrappingAddImm(v) => u32_ops::u32add(span, Wrapping, Some(*v)),
             Instruction::U32OverflowingAdd3 => span.add_op(U32add3),
@@ -125,13 +129,17 @@ impl Assembler {
             Instruction::U32CheckedSubImm(v) => u32_ops::u32sub(span, Checked, Some(*v)),
             Instruction::U32OverflowingSub => u32_ops::u32sub(span, Overflowing, None),
             Instruction::U32OverflowingSubImm(v) => u32_ops::u32sub(span, Overflowing, Some(*v)),
+            Instruction::U32WrappingNeg => u32_ops::u32wrapping_neg(span),
             Instruction::U32WrappingSub => u32_ops::u32sub(span, Wrapping, None),
             Instruction::U32WrappingSubImm(v) => u32_ops::u32sub(span, Wrapping, Some(*v)),
+            Instruction::U32SaturatingSub => u32_ops::u32sub(span, Saturating, None),
+            Instruction::U32SaturatingSubImm(v) => u32_ops::u32sub(span, Saturating, Some(*v)),
 
             Instruction::U32CheckedMul => u32_ops::u32mul(span, Checked, None),
             Instruction::U32CheckedMulImm(v) => u32_ops::u32mul(span, Checked, Some(*v)),
             Instruction::U32OverflowingMul => u32_ops::u32mul(span, Overflowing, None),
             Instruction::U32OverflowingMulImm(v) => u32_ops::u32mul(span, Overflowing, Some(*v)),
+            Instruction::U32MulHi => u32_ops::u32mulhi(span),
             Instruction::U32WrappingMul => u32_ops::u32mul(span, Wrapping, None),
             Instruction::U32WrappingMulImm(v) => u32_ops::u32mul(span, Wrapping, Some(*v)),
             Instruction::U32OverflowingMadd => span.add_op(U32madd),
@@ -149,11 +157,23 @@ impl Assembler {
             Instruction::U32CheckedDivModImm(v) => u32_ops::u32divmod(span, Checked, Some(*v)),
             Instruction::U32UncheckedDivMod => u32_ops::u32divmod(span, Unchecked, None),
             Instruction::U32UncheckedDivModImm(v) => u32_ops::u32divmod(span, Unchecked, Some(*v)),
+            Instruction::U32ExactDiv => u32_ops::u32exactdiv(span, None),
+            Instruction::U32ExactDivImm(v) => u32_ops::u32exactdiv(span, Some(*v)),
+            Instruction::U32DivRound => u32_ops::u32div_round(span, None),
+            Instruction::U32DivRoundImm(v) => u32_ops::u32div_round(span, Some(*v)),
+            Instruction::U32Pow => u32_ops::u32pow(span),
+            Instruction::U32Sqrt => u32_ops::u32sqrt(span),
+            Instruction::U32Log2Ceil => u32_ops::u32log2_ceil(span),
+            Instruction::U32NextPow2 => u32_ops::u32nextpow2(span),
+            Instruction::U32Gcd => u32_ops::u32gcd(span),
+            Instruction::U32Midpoint => u32_ops::u32midpoint(span),
 
             Instruction::U32CheckedAnd => span.add_op(U32and),
             Instruction::U32CheckedOr => span.add_ops([Dup1, Dup1, U32and, Neg, Add, Add]),
             Instruction::U32CheckedXor => span.add_op(U32xor),
             Instruction::U32CheckedNot => u32_ops::u32not(span),
+            Instruction::U32Nand => u32_ops::u32nand(span),
+            Instruction::U32Nor => u32_ops::u32nor(span),
             Instruction::U32CheckedShl => u32_ops::u32shl(span, Checked, None),
             Instruction::U32CheckedShlImm(v) => u32_ops::u32shl(span, Checked, Some(*v)),
             Instruction::U32UncheckedShl => u32_ops::u32shl(span, Unchecked, None),
@@ -170,8 +190,32 @@ impl Assembler {
             Instruction::U32CheckedRotrImm(v) => u32_ops::u32rotr(span, Checked, Some(*v)),
             Instruction::U32UncheckedRotr => u32_ops::u32rotr(span, Unchecked, None),
             Instruction::U32UncheckedRotrImm(v) => u32_ops::u32rotr(span, Unchecked, Some(*v)),
+            Instruction::U32Rcl => u32_ops::u32rcl(span),
+            Instruction::U32Rcr => u32_ops::u32rcr(span),
             Instruction::U32CheckedPopcnt => u32_ops::u32popcnt(span, Checked),
             Instruction::U32UncheckedPopcnt => u32_ops::u32popcnt(span, Unchecked),
+            Instruction::U32Hamming => u32_ops::u32hamming(span),
+            Instruction::U32PopEq => u32_ops::u32popeq(span),
+            Instruction::U32DistinctBytes => u32_ops::u32distinctbytes(span),
+            Instruction::U32BitFill => u32_ops::u32bitfill(span),
+            Instruction::U32Rev => u32_ops::u32rev(span),
+            Instruction::U32Byteswap => u32_ops::u32byteswap(span),
+            Instruction::U32MortonEncode => u32_ops::u32morton_encode(span),
+            Instruction::U32MortonDecode => u32_ops::u32morton_decode(span),
+            Instruction::U32TestBit => u32_ops::u32testbit(span, None),
+            Instruction::U32TestBitImm(v) => u32_ops::u32testbit(span, Some(*v)),
+            Instruction::U32SetBit => u32_ops::u32setbit(span, None),
+            Instruction::U32SetBitImm(v) => u32_ops::u32setbit(span, Some(*v)),
+            Instruction::U32ClearBit => u32_ops::u32clearbit(span, None),
+            Instruction::U32ClearBitImm(v) => u32_ops::u32clearbit(span, Some(*v)),
+            Instruction::U32Bfe => u32_ops::u32bfe(span, None),
+            Instruction::U32BfeImm(offset, width) => u32_ops::u32bfe(span, Some((*offset, *width))),
+            Instruction::U32Bfi(offset, width) => u32_ops::u32bfi(span, *offset, *width),
+            Instruction::U32To16 => u32_ops::u32to16(span),
+            Instruction::U32To8 => u32_ops::u32to8(span),
+            Instruction::U32Maj => u32_ops::u32maj(span),
+            Instruction::U32Ch => u32_ops::u32ch(span),
+            Instruction::U32Sigma(a, b, c) => u32_ops::u32sigma(span, *a, *b, *c),
 
             Instruction::U32CheckedEq => u32_ops::u32eq(span, None),
             Instruction::U32CheckedEqImm(v) => u32_ops::u32eq(span, Some(*v)),
@@ -185,10 +229,20 @@ impl Assembler {
             Instruction::U32UncheckedGt => u32_ops::u32gt(span, Unchecked),
             Instruction::U32CheckedGte => u32_ops::u32gte(span, Checked),
             Instruction::U32UncheckedGte => u32_ops::u32gte(span, Unchecked),
+            Instruction::U32Cmp => u32_ops::u32cmp(span),
             Instruction::U32CheckedMin => u32_ops::u32min(span, Checked),
             Instruction::U32UncheckedMin => u32_ops::u32min(span, Unchecked),
             Instruction::U32CheckedMax => u32_ops::u32max(span, Checked),
             Instruction::U32UncheckedMax => u32_ops::u32max(span, Unchecked),
+            Instruction::U32AbsDiff => u32_ops::u32abs_diff(span),
+            Instruction::U32Clamp => u32_ops::u32clamp(span),
+            Instruction::I32Lt => u32_ops::i32lt(span),
+            Instruction::I32Lte => u32_ops::i32lte(span),
+            Instruction::I32Gt => u32_ops::i32gt(span),
+            Instruction::I32Gte => u32_ops::i32gte(span),
+            Instruction::I32Min => u32_ops::i32min(span),
+            Instruction::I32Max => u32_ops::i32max(span),
+            Instruction::I32Cls => u32_ops::i32cls(span),
 
             // ----- stack manipulation -----------------------------------------------------------
             Instruction::Drop => span.add_op(Drop),
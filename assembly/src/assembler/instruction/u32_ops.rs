@@ -1,26 +1,47 @@
 use super::{
     field_ops::append_pow2_op,
-    push_u32_value, validate_param, AssemblyError, CodeBlock, Felt,
+    push_u32_value, AssemblyError, CodeBlock, Felt, FieldElement,
     Operation::{self, *},
     SpanBuilder, ZERO,
 };
-use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
+use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE, U32_EXACT_DIV_ERR_CODE};
+use vm_core::AdviceInjector::{ILog2Ceil, U32Gcd, U32Sqrt};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in a u32 word; used to bound the immediate values of shift and rotation
+/// instructions.
+const U32_WORD_BITS: u8 = 32;
 
 // ENUMS
 // ================================================================================================
 
 /// This enum is intended to determine the mode of operation passed to the parsing function
-#[derive(PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum U32OpMode {
     Checked,
     Unchecked,
     Wrapping,
     Overflowing,
+    Saturating,
 }
 
 // CONVERSIONS AND TESTS
 // ================================================================================================
 
+/// Translates u32test assembly instruction to VM operations.
+///
+/// Pushes `1` onto the stack if the top element is a valid u32 value and `0` otherwise. The
+/// tested element is left on the stack and no assertion is made, which allows programs to branch
+/// on malformed inputs instead of aborting as `u32assert` does.
+///
+/// Implemented by executing DUP U32SPLIT SWAP DROP EQZ: the element is a valid u32 value if and
+/// only if the high 32 bits of its split are zero (total of 5 VM cycles).
+pub fn u32test(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([Dup0, U32split, Swap, Drop, Eqz])
+}
+
 /// Translates u32testw assembly instruction to VM operations.
 ///
 /// Implemented by executing DUP U32SPLIT SWAP DROP EQZ on each element in the word
@@ -68,6 +89,62 @@ pub fn u32assertw(
     span.add_ops(ops)
 }
 
+/// Translates u32frombytes assembly instruction to VM operations.
+///
+/// Packs the four bytes at the top of the stack into a single u32 value, with the byte at the
+/// top of the stack becoming the most significant byte: [b3, b2, b1, b0, ...] -> [a, ...] where
+/// a = b3 * 2^24 + b2 * 2^16 + b1 * 2^8 + b0.
+///
+/// Each byte is asserted to be smaller than 256 by masking out its low 8 bits with `U32AND` (see
+/// [append_byte_assert]); the `U32AND` operation also fails if the byte is not a u32 value. Since
+/// all of the bytes are range-checked before they are combined, the packed value is computed
+/// using field arithmetic.
+///
+/// This takes 32 VM cycles.
+pub fn u32frombytes(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    // [b3, b2, b1, b0, ...] -> [b3 * 2^8, b2, b1, b0, ...]
+    append_byte_assert(span);
+    span.push_ops([Push(Felt::new(256)), Mul]);
+
+    // add the remaining bytes to the accumulator, shifting it by 8 bits after each byte except
+    // the last one
+    for i in 0..3 {
+        span.push_op(Swap);
+        append_byte_assert(span);
+        span.push_op(Add);
+        if i != 2 {
+            span.push_ops([Push(Felt::new(256)), Mul]);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Translates u32tobytes assembly instruction to VM operations.
+///
+/// Unpacks the u32 value at the top of the stack into its four bytes, with the most significant
+/// byte ending up at the top of the stack: [a, ...] -> [b3, b2, b1, b0, ...]. This is the inverse
+/// of u32frombytes.
+///
+/// The value is asserted to be a u32 value, and the bytes are then extracted one by one, starting
+/// from the least significant byte, via `U32DIV` by 256.
+///
+/// This takes 12 VM cycles.
+pub fn u32tobytes(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // make sure the value is a u32 value
+        Pad, U32assert2(ZERO), Drop,
+        // [a, ...] -> [a >> 8, b0, ...]
+        Push(Felt::new(256)), U32div, Swap,
+        // [a >> 8, b0, ...] -> [a >> 16, b1, b0, ...]
+        Push(Felt::new(256)), U32div, Swap,
+        // [a >> 16, b1, b0, ...] -> [b3, b2, b1, b0, ...]
+        Push(Felt::new(256)), U32div, Swap,
+    ];
+    span.add_ops(ops)
+}
+
 // ARITHMETIC OPERATIONS
 // ================================================================================================
 
@@ -85,6 +162,8 @@ pub fn u32assertw(
 /// - u32wrapping_add.b: 3 cycles
 /// - u32overflowing_add: 1 cycles
 /// - u32overflowing_add.b: 2 cycles
+/// - u32saturating_add: 5 cycles
+/// - u32saturating_add.b: 6 cycles
 pub fn u32add(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
@@ -107,6 +186,8 @@ pub fn u32add(
 /// - u32wrapping_sub.b: 3 cycles
 /// - u32overflowing_sub: 1 cycles
 /// - u32overflowing_sub.b: 2 cycles
+/// - u32saturating_sub: 5 cycles
+/// - u32saturating_sub.b: 6 cycles
 pub fn u32sub(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
@@ -115,6 +196,18 @@ pub fn u32sub(
     handle_arithmetic_operation(span, U32sub, op_mode, imm)
 }
 
+/// Translates u32wrapping_neg assembly instruction to VM operations.
+///
+/// Computes the two's-complement negation of the value at the top of the stack, i.e.,
+/// `(2^32 - a) mod 2^32`, by subtracting the value from zero with `U32SUB` and dropping the
+/// underflow flag. This is equivalent to `u32checked_not` followed by `u32wrapping_add.1`, but
+/// does not check that the input is a valid u32 value.
+///
+/// This takes 4 VM cycles, compared to 8 VM cycles for the two-instruction composition.
+pub fn u32wrapping_neg(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([Pad, Swap, U32sub, Drop])
+}
+
 /// Translates u32mul assembly instructions to VM operations.
 ///
 /// The base operation is `U32MUL`, but depending on the mode, additional operations may be
@@ -137,6 +230,17 @@ pub fn u32mul(
     handle_arithmetic_operation(span, U32mul, op_mode, imm)
 }
 
+/// Translates u32mulhi assembly instruction to VM operations.
+///
+/// Computes the product of the two values at the top of the stack using `U32MUL`, and then
+/// discards the low 32 bits of the product, leaving only the high 32 bits on the stack. The
+/// inputs are not checked to be u32 values.
+///
+/// This takes 3 VM cycles.
+pub fn u32mulhi(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([U32mul, Swap, Drop])
+}
+
 /// Translates u32div assembly instructions to VM operations.
 ///
 /// VM cycles per mode:
@@ -196,6 +300,253 @@ pub fn u32divmod(
     handle_division(span, op_mode, imm)
 }
 
+/// Translates u32exactdiv assembly instructions to VM operations.
+///
+/// Both inputs are asserted to be u32 values, and the remainder of the division is asserted to
+/// be zero. If the remainder is not zero, execution fails with [U32_EXACT_DIV_ERR_CODE] error code.
+///
+/// VM cycles per mode:
+/// - u32exactdiv: 4 cycles
+/// - u32exactdiv.b:
+///    - 6 cycles if b is 1
+///    - 5 cycles if b is not 1
+pub fn u32exactdiv(
+    span: &mut SpanBuilder,
+    imm: Option<u32>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    handle_division(span, U32OpMode::Checked, imm)?;
+    span.add_ops([Eqz, Assert(Felt::from(U32_EXACT_DIV_ERR_CODE))])
+}
+
+/// Translates u32div_round assembly instructions to VM operations.
+///
+/// Computes the quotient of `a` and `b` rounded half up, i.e., `(a + b / 2) / b` where `/` is the
+/// truncating division. Both inputs are asserted to be u32 values. Since `a + b / 2` may not fit
+/// into 32 bits, it is not computed directly: instead, the truncated quotient `q` and the
+/// remainder `r` are computed via `U32DIV`, and `q` is incremented if `r >= b - r`, which holds
+/// exactly when `r + b / 2 >= b`. The rounded quotient always fits into 32 bits.
+///
+/// VM cycles per mode:
+/// - u32div_round: 13 cycles
+/// - u32div_round.b:
+///    - 10 cycles if b is 1
+///    - 9 cycles if b is not 1
+pub fn u32div_round(
+    span: &mut SpanBuilder,
+    imm: Option<u32>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    match imm {
+        Some(b) => {
+            // [b, a, ...] -> [r, q, ...]
+            handle_division(span, U32OpMode::Checked, imm)?;
+            // r >= b - r is the same as r >= ceil(b / 2): [r, q, ...] -> [borrow, q, ...]
+            span.push_ops([Push(Felt::from(b - b / 2)), U32sub, Swap, Drop]);
+        }
+        None => {
+            // keep a copy of b: [b, a, ...] -> [r, q, b, ...]
+            span.push_ops([Dup0, MovDn2]);
+            handle_division(span, U32OpMode::Checked, imm)?;
+            // compute b - r, which is a u32 value since r < b: [r, q, b, ...] -> [b - r, r, q, ...]
+            span.push_ops([MovUp2, Dup1, Neg, Add]);
+            // check whether r < b - r: [b - r, r, q, ...] -> [borrow, q, ...]
+            span.push_ops([U32sub, Swap, Drop]);
+        }
+    }
+
+    // increment the quotient if no borrow occurred: [borrow, q, ...] -> [q', ...]
+    span.add_ops([Not, Add])
+}
+
+/// Translates u32pow assembly instruction to VM operations.
+///
+/// The base and the exponent are asserted to be u32 values, and b^e mod 2^32 is then computed
+/// using square-and-multiply where each step is performed via a single EXPACC operation. Since
+/// both the base and the accumulator are u32 values, the products computed by EXPACC never
+/// exceed the field modulus, and so after each step they are reduced modulo 2^32 by splitting
+/// them into 32-bit limbs and dropping the high limb. The base is not reduced after the last step
+/// as it is no longer needed.
+///
+/// Since the exponent is a u32 value, all 32 steps are always executed, and thus the number of
+/// cycles does not depend on the value of the exponent.
+///
+/// This takes 293 VM cycles.
+pub fn u32pow(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    // arrange the top of the stack for EXPACC operation: [e, b, ...] -> [0, b, 1, e, ...]
+    span.push_ops([U32assert2(ZERO), Pad, Incr, MovUp2, Pad]);
+
+    for step in 0..32 {
+        // [bit, b, acc, e, ...] -> [bit, b^2, acc', e >> 1, ...]
+        span.push_op(Expacc);
+        // reduce the accumulator modulo 2^32
+        span.push_ops([MovUp2, U32split, Drop, MovDn2]);
+        // reduce the base modulo 2^32
+        if step != 31 {
+            span.push_ops([Swap, U32split, Drop, Swap]);
+        }
+    }
+
+    // drop everything but the accumulator: [bit, b, acc, 0, ...] -> [acc, ...]
+    span.add_ops([Drop, Drop, Swap, Drop])
+}
+
+/// Translates u32sqrt assembly instruction to VM operations.
+///
+/// The integer square root r of the value n at the top of the stack is provided via the advice
+/// stack, and the following is then verified:
+/// - both r and n are u32 values;
+/// - r * r is a u32 value (which implies that r < 2^16), and n - r * r is a u32 value (i.e.,
+///   r * r <= n);
+/// - r * r + 2 * r - n is a u32 value (i.e., n < (r + 1) * (r + 1)).
+///
+/// Since all of the intermediate values are much smaller than the field modulus, the last two
+/// differences are computed using field arithmetic.
+///
+/// This takes 22 VM cycles.
+pub fn u32sqrt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_advice_injector(U32Sqrt);
+
+    #[rustfmt::skip]
+    let ops = [
+        // read the root from the advice stack and make sure both r and n are u32 values
+        AdvPop, U32assert2(ZERO),
+        // compute r * r and make sure it is a u32 value: [r, n, ...] -> [r*r, r, n, ...]
+        Dup0, Dup0, U32mul, Eqz, Assert(ZERO),
+        // compute n - r * r
+        Dup2, Dup1, Neg, Add,
+        // compute r * r + 2 * r - n
+        Swap, Dup2, Dup0, Add, Add, MovUp3, Neg, Add,
+        // make sure both differences are u32 values and drop them
+        U32assert2(ZERO), Drop, Drop,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32log2_ceil assembly instruction to VM operations.
+///
+/// The value r = ceil(log2(n)) for the value n at the top of the stack is provided via the advice
+/// stack, and p = 2^r is computed using the same operations as the pow2 instruction. Since r is
+/// the only integer such that n <= 2^r < 2 * n, the result is then verified by asserting that
+/// both p - n and 2 * n - p - 1 are u32 values. Both r and n are asserted to be u32 values as
+/// well.
+///
+/// This takes 35 VM cycles.
+pub fn u32log2_ceil(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_advice_injector(ILog2Ceil);
+
+    // read r from the advice stack and compute p = 2^r: [n, ...] -> [p, r, n, ...]
+    span.push_ops([AdvPop, U32assert2(ZERO), Dup0]);
+    append_pow2_op(span);
+
+    #[rustfmt::skip]
+    let ops = [
+        // compute p - n
+        Dup0, Dup3, Neg, Add,
+        // compute 2 * n - p - 1
+        Swap, Incr, Neg, Dup3, Dup0, Add, Add,
+        // make sure both differences are u32 values and drop them along with n
+        U32assert2(ZERO), Drop, Drop, Swap, Drop,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32nextpow2 assembly instruction to VM operations.
+///
+/// Computes the smallest power of two p which is greater than or equal to the value n at the top
+/// of the stack. The two edge cases are handled as follows:
+/// - n = 0 is replaced with n = 1 before anything else (by adding the result of `EQZ` to n), since
+///   ceil(log2(0)) is undefined, and the smallest power of two is 1 in both cases.
+/// - n = 1 needs no special treatment: ceil(log2(1)) = 0, and thus p = 1.
+///
+/// Similarly to u32log2_ceil, r = ceil(log2(n)) is provided via the advice stack, and p = 2^r is
+/// computed using the same operations as the pow2 instruction (so p is a power of two by
+/// construction). The result is then verified by asserting that both p - n and 2 * n - p - 1 are
+/// u32 values, i.e., that p >= n and p / 2 < n. Both r and n are asserted to be u32 values as
+/// well. Note that for n > 2^31 the result is 2^32, which is not a u32 value.
+///
+/// This takes 37 VM cycles.
+pub fn u32nextpow2(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    // replace n = 0 with n = 1: [n, ...] -> [n', ...]
+    span.push_ops([Dup0, Eqz, Add]);
+    span.push_advice_injector(ILog2Ceil);
+
+    // read r from the advice stack and compute p = 2^r: [n', ...] -> [p, n', ...]
+    span.push_ops([AdvPop, U32assert2(ZERO)]);
+    append_pow2_op(span);
+
+    #[rustfmt::skip]
+    let ops = [
+        // compute p - n'
+        Dup0, Dup2, Neg, Add,
+        // compute 2 * n' - p - 1
+        Dup1, Incr, Neg, Dup3, Dup0, Add, Add,
+        // make sure both differences are u32 values and drop them along with n'
+        U32assert2(ZERO), Drop, Drop, Swap, Drop,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32gcd assembly instruction to VM operations.
+///
+/// The greatest common divisor g of the values a and b at the top of the stack is provided via
+/// the advice stack, together with the cofactors qa and qb, and the magnitudes x and y of the
+/// Bézout coefficients. The following is then verified:
+/// - a, b, qa, qb, x, and y are all u32 values;
+/// - g * qa = a and g * qb = b (i.e., g is a common divisor of a and b);
+/// - (qa * x - qb * y)^2 = 1 (i.e., qa and qb are coprime, and thus g is the greatest common
+///   divisor).
+///
+/// Since all of the products are smaller than the field modulus, these relations are checked
+/// using field arithmetic, and they imply that the same relations hold over the integers. In
+/// particular, this also covers the cases where a or b is zero: gcd(0, n) = n and gcd(0, 0) = 0.
+///
+/// This takes 33 VM cycles.
+pub fn u32gcd(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_advice_injector(U32Gcd);
+
+    #[rustfmt::skip]
+    let ops = [
+        // make sure a and b are u32 values, and read the advice:
+        // [b, a, ...] -> [y, x, qb, qa, g, b, a, ...]
+        U32assert2(ZERO), AdvPop, AdvPop, AdvPop, AdvPop, AdvPop,
+        // make sure x and y are u32 values
+        U32assert2(ZERO),
+        // compute d = qa * x - qb * y: [y, x, qb, qa, ...] -> [d, qb, qa, ...]
+        Dup2, Mul, Neg, Swap, Dup3, Mul, Add,
+        // make sure d^2 = 1: [d, qb, qa, ...] -> [qb, qa, ...]
+        Dup0, Mul, Push(Felt::ONE), Eq, Assert(ZERO),
+        // make sure qa and qb are u32 values
+        U32assert2(ZERO),
+        // make sure g * qb = b: [qb, qa, g, b, a, ...] -> [qa, g, b, a, ...]
+        Dup2, Mul, Dup3, Eq, Assert(ZERO),
+        // make sure g * qa = a: [qa, g, b, a, ...] -> [g, b, a, ...]
+        Dup1, Mul, Dup3, Eq, Assert(ZERO),
+        // drop a and b: [g, b, a, ...] -> [g, ...]
+        MovDn2, Drop, Drop,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32midpoint assembly instruction to VM operations.
+///
+/// Computes c = floor((a + b) / 2) without overflowing 32 bits: the values are added via
+/// `U32ADD`, which leaves the carry of the addition in a separate limb, and the result is then
+/// computed as floor(sum / 2) + carry * 2^31. Since the carry is taken into account, there is no
+/// precondition on the order of the operands, and the result is the same for a > b as for a <= b.
+///
+/// This takes 10 VM cycles.
+pub fn u32midpoint(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // make sure a and b are u32 values: [b, a, ...] -> [carry, sum, ...]
+        U32assert2(ZERO), U32add,
+        // [carry, sum, ...] -> [floor(sum / 2), carry, ...]
+        Swap, Push(Felt::new(2)), U32div, Drop,
+        // [floor(sum / 2), carry, ...] -> [c, ...]
+        Swap, Push(Felt::new(1 << 31)), Mul, Add,
+    ];
+    span.add_ops(ops)
+}
+
 // BITWISE OPERATIONS
 // ================================================================================================
 
@@ -220,6 +571,39 @@ pub fn u32not(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError
     span.add_ops(ops)
 }
 
+/// Translates u32nand assembly instruction to VM operations.
+///
+/// The bitwise AND of the inputs is computed using the `U32AND` operation, which also verifies
+/// that both inputs are u32 values. Since the result is guaranteed to be a u32 value, it is then
+/// negated by subtracting it from 2^32 - 1 using field arithmetic.
+///
+/// This takes 4 VM cycles.
+pub fn u32nand(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([U32and, Neg, Push(Felt::from(u32::MAX)), Add])
+}
+
+/// Translates u32nor assembly instruction to VM operations.
+///
+/// The bitwise OR of the inputs is equal to `a + b - (a AND b)`, and thus its negation can be
+/// computed as `2^32 - 1 - a - b + (a AND b)` using field arithmetic. The `U32AND` operation
+/// also verifies that both inputs are u32 values.
+///
+/// This takes 9 VM cycles.
+pub fn u32nor(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // compute a AND b and move it out of the way
+        Dup1, Dup1, U32and, MovDn2,
+
+        // compute (a AND b) - a - b
+        Add, Neg, Add,
+
+        // add 2^32 - 1 to get the final result
+        Push(Felt::from(u32::MAX)), Add,
+    ];
+    span.add_ops(ops)
+}
+
 /// Translates u32shl assembly instructions to VM operations.
 ///
 /// The operation is implemented by putting a power of 2 on the stack, then multiplying it with
@@ -236,7 +620,10 @@ pub fn u32shl(
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, [U32mul, Drop])
+    let instruction = bitwise_instruction_name(&op_mode, "u32checked_shl", "u32unchecked_shl");
+    prepare_bitwise::<U32_WORD_BITS>(span, imm, op_mode, [U32mul, Drop], |imm| {
+        AssemblyError::invalid_u32_shift_amount(instruction, imm)
+    })
 }
 
 /// Translates u32shr assembly instructions to VM operations.
@@ -255,7 +642,10 @@ pub fn u32shr(
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm, op_mode, [U32div, Drop])
+    let instruction = bitwise_instruction_name(&op_mode, "u32checked_shr", "u32unchecked_shr");
+    prepare_bitwise::<U32_WORD_BITS>(span, imm, op_mode, [U32div, Drop], |imm| {
+        AssemblyError::invalid_u32_shift_amount(instruction, imm)
+    })
 }
 
 /// Translates u32rotl assembly instructions to VM operations.
@@ -275,7 +665,10 @@ pub fn u32rotl(
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_ROTATE_VALUE>(span, imm, op_mode, [U32mul, Add])
+    let instruction = bitwise_instruction_name(&op_mode, "u32checked_rotl", "u32unchecked_rotl");
+    prepare_bitwise::<U32_WORD_BITS>(span, imm, op_mode, [U32mul, Add], |imm| {
+        AssemblyError::invalid_u32_rotation_amount(instruction, imm)
+    })
 }
 
 /// Translates u32rotr assembly instructions to VM operations.
@@ -285,16 +678,20 @@ pub fn u32rotl(
 /// variants, the shift value is asserted to be between 0-31 and the value to be shifted is
 /// asserted to be a 32-bit value.
 ///
+/// When the shift amount is provided via the stack, 2^(32-b) is computed directly as
+/// 2^32 * (2^-1)^b (see [append_rotr_pow2_op]), which also verifies that b is at most 31.
+///
 /// VM cycles per mode:
-/// - u32checked_rotr: 31 cycles
+/// - u32checked_rotr: 16 cycles
 /// - u32checked_rotr.b: 6 cycles
-/// - u32unchecked_rotr: 22 cycles
+/// - u32unchecked_rotr: 15 cycles
 /// - u32unchecked_rotr.b: 3 cycles
 pub fn u32rotr(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
+    let instruction = bitwise_instruction_name(&op_mode, "u32checked_rotr", "u32unchecked_rotr");
     match (imm, op_mode) {
         (Some(0), U32OpMode::Checked) => {
             // if rotation is performed by 0, just verify that stack top is u32
@@ -302,7 +699,7 @@ pub fn u32rotr(
             return Ok(None);
         }
         (Some(imm), U32OpMode::Checked) => {
-            validate_param(imm, 1..=MAX_U32_ROTATE_VALUE)?;
+            validate_rotation_amount(instruction, imm)?;
             span.push_ops([Push(Felt::new(1 << (32 - imm))), U32assert2(ZERO)]);
         }
         (Some(0), U32OpMode::Unchecked) => {
@@ -311,38 +708,76 @@ pub fn u32rotr(
             return Ok(None);
         }
         (Some(imm), U32OpMode::Unchecked) => {
-            validate_param(imm, 1..=MAX_U32_ROTATE_VALUE)?;
+            validate_rotation_amount(instruction, imm)?;
             span.push_op(Push(Felt::new(1 << (32 - imm))));
         }
         (None, U32OpMode::Checked) => {
-            #[rustfmt::skip]
-            span.push_ops([
-                // Verify both b and a are u32.
-                U32assert2(ZERO),
-
-                // Calculate 32 - b and assert that the shift value b <= 31.
-                Push(Felt::from(MAX_U32_ROTATE_VALUE)), Dup1, U32sub, Not, Assert(ZERO), Incr, Dup1,
-
-                // If 32-b = 32, replace it with 0.
-                Eqz, Not, CSwap, Drop,
-            ]);
-            append_pow2_op(span);
-            span.push_op(Swap);
-        }
-        (None, U32OpMode::Unchecked) => {
-            span.push_ops([Push(Felt::new(32)), Swap, U32sub, Drop]);
-            append_pow2_op(span);
+            // Verify both b and a are u32.
+            span.push_op(U32assert2(ZERO));
+            append_rotr_pow2_op(span);
         }
+        (None, U32OpMode::Unchecked) => append_rotr_pow2_op(span),
         _ => unreachable!("unsupported operation mode"),
     }
     span.add_ops([U32mul, Add])
 }
 
+/// Translates u32rcl assembly instruction to VM operations.
+///
+/// Rotates the 33-bit value formed by the 32-bit value `a` and the carry bit `c` to the left by
+/// one bit: [c, a, ...] -> [c', b, ...], where b = (a * 2 + c) mod 2^32 and c' is the most
+/// significant bit of `a`.
+///
+/// Both values are asserted to be u32 values via `U32ASSERT2`, and the carry is asserted to be a
+/// binary value via `NOT`. Since a * 2 + c is smaller than 2^33, `U32SPLIT` then leaves the new
+/// carry bit in the upper limb and the rotated value in the lower limb.
+///
+/// This takes 9 VM cycles.
+pub fn u32rcl(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // make sure a and c are u32 values, and c is a binary value
+        U32assert2(ZERO), Dup0, Not, Drop,
+        // [c, a, ...] -> [a * 2 + c, ...]
+        Swap, Dup0, Add, Add,
+        // [a * 2 + c, ...] -> [c', b, ...]
+        U32split,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32rcr assembly instruction to VM operations.
+///
+/// Rotates the 33-bit value formed by the 32-bit value `a` and the carry bit `c` to the right by
+/// one bit: [c, a, ...] -> [c', b, ...], where b = floor(a / 2) + c * 2^31 and c' is the least
+/// significant bit of `a`.
+///
+/// Both values are asserted to be u32 values via `U32ASSERT2`, and the carry is asserted to be a
+/// binary value via `NOT`. The value is then split into its least significant bit and the
+/// remaining bits via `U32DIV` by 2.
+///
+/// This takes 13 VM cycles.
+pub fn u32rcr(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // make sure a and c are u32 values, and c is a binary value
+        U32assert2(ZERO), Dup0, Not, Drop,
+        // [c, a, ...] -> [c', a >> 1, c, ...]
+        Swap, Push(Felt::new(2)), U32div,
+        // [c', a >> 1, c, ...] -> [c', b, ...]
+        MovUp2, Push(Felt::new(1 << 31)), Mul, MovUp2, Add, Swap,
+    ];
+    span.add_ops(ops)
+}
+
 /// Translates u32popcnt assembly instructions to VM operations.
 ///
 /// VM cycles per mode:
-/// - u32checked_popcnt: 36 cycles
-/// - u32unchecked_popcnt: 33 cycles
+/// - u32checked_popcnt: 34 cycles
+/// - u32unchecked_popcnt: 31 cycles
+///
+/// The intermediate sums never exceed 2^32, so the additions and subtractions after the first
+/// step are performed as field operations rather than u32 operations.
 pub fn u32popcnt(
     span: &mut SpanBuilder,
     op_mode: U32OpMode,
@@ -360,19 +795,18 @@ pub fn u32popcnt(
         Push(Felt::new(0x55555555)),
         U32and,
         U32sub, Drop,
-        // i = (i & 0x33333333) + ((i >> 2) & 0x33333333);
+        // i = (i & 0x33333333) + ((i >> 2) & 0x33333333), computed as
+        // i = i - 3 * ((i >> 2) & 0x33333333);
         Dup0,
         Push(Felt::new(1 << 2)), U32div, Drop,
         Push(Felt::new(0x33333333)),
         U32and,
-        Swap,
-        Push(Felt::new(0x33333333)),
-        U32and,
-        U32add, Drop,
+        Push(Felt::new(3)), Mul,
+        Neg, Add,
         // i = (i + (i >> 4)) & 0x0F0F0F0F;
         Dup0,
         Push(Felt::new(1 << 4)), U32div, Drop,
-        U32add, Drop,
+        Add,
         Push(Felt::new(0x0F0F0F0F)),
         U32and,
         // return (i * 0x01010101) >> 24;
@@ -383,15 +817,471 @@ pub fn u32popcnt(
     span.add_ops(ops)
 }
 
-/// Handles U32ADD, U32SUB, and U32MUL operations in checked, wrapping, and overflowing modes,
-/// including handling of immediate parameters.
+/// Translates u32hamming assembly instruction to VM operations.
+///
+/// The hamming distance between `a` and `b` is computed as the number of set bits in `a ^ b`,
+/// i.e., via `U32XOR` followed by the same sequence of operations as in `u32unchecked_popcnt`.
+/// Since the `U32XOR` operation fails if either of the inputs is not a u32 value, the result of
+/// the XOR is guaranteed to be a u32 value, and no further checks are needed.
+///
+/// This takes 32 VM cycles.
+pub fn u32hamming(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_op(U32xor);
+    u32popcnt(span, U32OpMode::Unchecked)
+}
+
+/// Translates u32popeq assembly instruction to VM operations.
+///
+/// Pushes 1 onto the stack if `a` and `b` have the same number of set bits, and 0 otherwise. Both
+/// values are asserted to be u32 values via a single `U32ASSERT2` operation, after which their
+/// popcounts are computed via the same sequence of operations as in `u32unchecked_popcnt` and
+/// compared via `EQ`.
+///
+/// This takes 65 VM cycles.
+pub fn u32popeq(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_op(U32assert2(ZERO));
+
+    // compute the popcounts of both values: [b, a, ...] -> [popcnt(a), popcnt(b), ...]
+    u32popcnt(span, U32OpMode::Unchecked)?;
+    span.push_op(Swap);
+    u32popcnt(span, U32OpMode::Unchecked)?;
+
+    span.add_op(Eq)
+}
+
+/// Translates u32distinctbytes assembly instruction to VM operations.
+///
+/// The value is unpacked into its four bytes (see [u32tobytes]), and each byte is then compared
+/// against all of the bytes below it via `EQ`. A byte which is equal to any of the bytes below it
+/// is a duplicate, and the number of distinct bytes is computed as 4 minus the number of
+/// duplicates. Thus, the result is always in the range [1, 4].
+///
+/// The value is asserted to be a u32 value by the conversion into bytes.
+///
+/// This takes 39 VM cycles.
+pub fn u32distinctbytes(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    // [a, ...] -> [b3, b2, b1, b0, ...]
+    u32tobytes(span)?;
+
+    #[rustfmt::skip]
+    let ops = [
+        // [b3, b2, b1, b0, ...] -> [b2, b1, b0, d3, ...], where d3 = 1 if b3 is a duplicate
+        Dup0, Dup2, Eq,
+        Dup1, Dup4, Eq, Or,
+        Swap, Dup4, Eq, Or,
+        MovDn3,
+        // [b2, b1, b0, d3, ...] -> [d2, b1, b0, d3, ...], where d2 = 1 if b2 is a duplicate
+        Dup0, Dup2, Eq,
+        Swap, Dup3, Eq, Or,
+        // [d2, b1, b0, d3, ...] -> [d1 + d2 + d3, ...], where d1 = 1 if b1 is a duplicate
+        MovUp2, MovUp2, Eq,
+        Add, Add,
+        // [d, ...] -> [4 - d, ...]
+        Neg, Push(Felt::new(4)), Add,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32bitfill assembly instruction to VM operations.
+///
+/// Computes a mask with all bits up to (and including) the most significant set bit of `a` set,
+/// i.e., `2^(floor(log2(a)) + 1) - 1` for `a > 0`, and `0` for `a = 0`. The mask is computed by the
+/// standard shift-or cascade `a |= a >> k` for k = 1, 2, 4, 8, 16, where the shifts are performed
+/// via `U32DIV` by `2^k` and `a | b` is computed as `a + b - (a & b)` in the same way as in
+/// u32checked_or. The first `U32AND` operation fails if the input is not a u32 value.
+///
+/// This takes 50 VM cycles.
+pub fn u32bitfill(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    for shift in [1, 2, 4, 8, 16] {
+        #[rustfmt::skip]
+        span.push_ops([
+            // [a, ...] -> [a >> k, a, ...]
+            Dup0, Push(Felt::new(1 << shift)), U32div, Drop,
+            // [a >> k, a, ...] -> [a | (a >> k), ...]
+            Dup1, Dup1, U32and, Neg, Add, Add,
+        ]);
+    }
+
+    Ok(None)
+}
+
+/// Translates u32rev assembly instruction to VM operations.
+///
+/// The bits of the value are reversed by swapping adjacent groups of 1, 2, 4, and 8 bits, and then
+/// swapping the two 16-bit halves (see [swap_bit_groups] and [swap_halves]).
+///
+/// Unlike u32clz and similar instructions, the result is not provided via the advice stack. Bit
+/// reversal has no arithmetic relation to the input which could be checked cheaply: verifying a
+/// non-deterministic result would require decomposing both values into bits, and this takes more
+/// cycles than computing the result directly.
+///
+/// The first `U32AND` operation fails if the input is not a u32 value.
+///
+/// This takes 40 VM cycles.
+pub fn u32rev(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    swap_bit_groups(span, 0xAAAAAAAA, 1);
+    swap_bit_groups(span, 0xCCCCCCCC, 2);
+    swap_bit_groups(span, 0xF0F0F0F0, 4);
+    swap_bit_groups(span, 0xFF00FF00, 8);
+    swap_halves(span)
+}
+
+/// Translates u32byteswap assembly instruction to VM operations.
+///
+/// The bytes of the value are reversed by swapping adjacent bytes, and then swapping the two
+/// 16-bit halves (see [swap_bit_groups] and [swap_halves]).
+///
+/// The `U32AND` operation fails if the input is not a u32 value.
+///
+/// This takes 13 VM cycles.
+pub fn u32byteswap(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    swap_bit_groups(span, 0xFF00FF00, 8);
+    swap_halves(span)
+}
+
+/// Translates u32morton_encode assembly instruction to VM operations.
+///
+/// Interleaves the bits of the 16-bit values `a` and `b` (with `b` at the top of the stack) into
+/// the u32 value `c`, such that bit i of `a` becomes bit 2i of `c`, and bit i of `b` becomes bit
+/// 2i + 1 of `c`. The bits of each value are spread apart via the standard masking cascade (see
+/// [spread_bits]), and since the spread values have no set bits in common, they are combined as
+/// `2 * spread(b) + spread(a)`.
+///
+/// Both values are asserted to be u32 values, and then to be smaller than 2^16 by asserting that
+/// their products with 2^16 are u32 values as well.
+///
+/// This takes 62 VM cycles.
+pub fn u32morton_encode(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    let pow2_16 = Felt::new(1 << 16);
+    #[rustfmt::skip]
+    span.push_ops([
+        // make sure both values are smaller than 2^16
+        U32assert2(ZERO),
+        Dup0, Push(pow2_16), Mul,
+        Dup2, Push(pow2_16), Mul,
+        U32assert2(ZERO), Drop, Drop,
+    ]);
+
+    // [b, a, ...] -> [2 * spread(b), a, ...]
+    spread_bits(span);
+    span.push_ops([Dup0, Add, Swap]);
+
+    // [a, 2 * spread(b), ...] -> [c, ...]
+    spread_bits(span);
+    span.add_op(Add)
+}
+
+/// Translates u32morton_decode assembly instruction to VM operations.
+///
+/// This is the inverse of u32morton_encode: the u32 value `c` is split into the 16-bit values `a`
+/// and `b`, such that `a` consists of the bits of `c` at even positions and `b` consists of the
+/// bits of `c` at odd positions. The bits of each value are gathered via the standard masking
+/// cascade (see [compact_bits]), where the odd bits are first moved to even positions by shifting
+/// `c` right by one bit via `U32DIV`.
+///
+/// The first `U32AND` operation fails if the input is not a u32 value.
+///
+/// This takes 65 VM cycles.
+pub fn u32morton_decode(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    // [c, ...] -> [a, c, ...]
+    span.push_op(Dup0);
+    compact_bits(span);
+
+    // [a, c, ...] -> [b, a, ...]
+    span.push_ops([Swap, Push(Felt::new(2)), U32div, Drop]);
+    compact_bits(span);
+
+    Ok(None)
+}
+
+/// Translates u32testbit assembly instruction to VM operations.
+///
+/// Leaves `1` on the stack if bit `i` of the value `a` is set and `0` otherwise. The bit index
+/// is either provided as an immediate value or taken from the top of the stack, in which case
+/// the stack is expected to be arranged as `[i, a, ...]`. The value is shifted right by `i` bits
+/// via `U32DIV` by `2^i`, and the lowest bit of the result is then extracted via `U32AND`.
+///
+/// This takes:
+/// - 5 VM cycles when the bit index is provided as an immediate value.
+/// - 20 VM cycles when the bit index is taken from the stack.
+///
+/// # Errors
+/// Returns an error if the immediate bit index is greater than 31.
+pub fn u32testbit(
+    span: &mut SpanBuilder,
+    imm: Option<u8>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    match imm {
+        Some(imm) => {
+            validate_bit_index("u32testbit", imm)?;
+            span.push_op(Push(Felt::new(1 << imm)));
+        }
+        None => append_pow2_op(span),
+    }
+    span.add_ops([U32div, Drop, Push(Felt::ONE), U32and])
+}
+
+/// Translates u32setbit assembly instruction to VM operations.
+///
+/// Sets bit `i` of the value `a` by computing `a | 2^i`. The bit index is either provided as an
+/// immediate value or taken from the top of the stack, in which case the stack is expected to be
+/// arranged as `[i, a, ...]`. Setting a bit which is already set leaves the value unchanged.
+///
+/// This takes:
+/// - 7 VM cycles when the bit index is provided as an immediate value.
+/// - 22 VM cycles when the bit index is taken from the stack.
+///
+/// # Errors
+/// Returns an error if the immediate bit index is greater than 31.
+pub fn u32setbit(
+    span: &mut SpanBuilder,
+    imm: Option<u8>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    match imm {
+        Some(imm) => {
+            validate_bit_index("u32setbit", imm)?;
+            span.push_op(Push(Felt::new(1 << imm)));
+        }
+        None => append_pow2_op(span),
+    }
+    // a | 2^i is computed as a + 2^i - (a & 2^i), in the same way as in u32checked_or
+    span.add_ops([Dup1, Dup1, U32and, Neg, Add, Add])
+}
+
+/// Translates u32clearbit assembly instruction to VM operations.
+///
+/// Clears bit `i` of the value `a` by computing `a & !(2^i)`. The bit index is either provided as
+/// an immediate value or taken from the top of the stack, in which case the stack is expected to
+/// be arranged as `[i, a, ...]`. For a stack-provided index, the mask is computed in the same way
+/// as in `u32checked_not`, i.e., by subtracting `2^i` from `2^32 - 1`.
+///
+/// This takes:
+/// - 2 VM cycles when the bit index is provided as an immediate value.
+/// - 20 VM cycles when the bit index is taken from the stack.
+///
+/// # Errors
+/// Returns an error if the immediate bit index is greater than 31.
+pub fn u32clearbit(
+    span: &mut SpanBuilder,
+    imm: Option<u8>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    match imm {
+        Some(imm) => {
+            validate_bit_index("u32clearbit", imm)?;
+            span.push_op(Push(Felt::from(!(1_u32 << imm))));
+        }
+        None => {
+            append_pow2_op(span);
+            span.push_ops([Neg, Push(Felt::from(u32::MAX)), Add]);
+        }
+    }
+    span.add_op(U32and)
+}
+
+/// Translates u32bfe assembly instruction to VM operations.
+///
+/// Extracts the bit field of `width` bits starting at bit `offset` of the value `a`, i.e., shifts
+/// `a` right by `offset` bits and keeps the lowest `width` bits of the result. The offset and the
+/// width are either provided as immediate values or taken from the stack, in which case the stack
+/// is expected to be arranged as `[width, offset, a, ...]`. In both cases, the sum of the offset
+/// and the width must not exceed 32.
+///
+/// This takes:
+/// - 5 VM cycles when the offset and the width are provided as immediate values (2 VM cycles if
+///   the offset is 0).
+/// - 50 VM cycles when the offset and the width are taken from the stack.
+///
+/// # Errors
+/// Returns an error if the sum of the immediate offset and width is greater than 32.
+pub fn u32bfe(
+    span: &mut SpanBuilder,
+    imm: Option<(u8, u8)>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    match imm {
+        Some((offset, width)) => {
+            validate_bit_field("u32bfe", offset, width)?;
+            if offset > 0 {
+                span.push_ops([Push(Felt::new(1 << offset)), U32div, Drop]);
+            }
+            span.push_op(Push(Felt::new((1 << width) - 1)));
+        }
+        None => {
+            // assert that offset + width <= 32 by checking that 32 - (offset + width) does not
+            // underflow
+            #[rustfmt::skip]
+            span.push_ops([
+                Dup1, Dup1, Add, Push(Felt::new(32)), Swap, U32sub, Eqz, Assert(ZERO), Drop,
+            ]);
+            // shift a right by the offset: [width, offset, a, ...] -> [a >> offset, width, ...]
+            span.push_op(Swap);
+            append_pow2_op(span);
+            span.push_ops([MovUp2, Swap, U32div, Drop]);
+            // compute the mask 2^width - 1: [a >> offset, width, ...] -> [mask, a >> offset, ...]
+            span.push_op(Swap);
+            append_pow2_op(span);
+            span.push_ops([Push(-Felt::ONE), Add]);
+        }
+    }
+    span.add_op(U32and)
+}
+
+/// Translates u32bfi assembly instruction to VM operations.
+///
+/// Inserts the lowest `width` bits of the value `b` into the value `a` starting at bit `offset`,
+/// leaving all other bits of `a` unchanged. The stack is expected to be arranged as
+/// `[b, a, ...]`. The bits of `a` covered by the bit field are cleared with a mask first, and
+/// the masked and shifted `b` is then OR-ed into the result. Since the two operands of the OR
+/// have no set bits in common, the OR is computed as a simple addition.
+///
+/// This takes 8 VM cycles (6 VM cycles if the offset is 0).
+///
+/// # Errors
+/// Returns an error if the sum of the offset and the width is greater than 32.
+pub fn u32bfi(
+    span: &mut SpanBuilder,
+    offset: u8,
+    width: u8,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    validate_bit_field("u32bfi", offset, width)?;
+    let mask = (1_u64 << width) - 1;
+
+    // keep the lowest width bits of b and shift them into place: [b, a, ...] -> [b', a, ...]
+    span.push_ops([Push(Felt::new(mask)), U32and]);
+    if offset > 0 {
+        span.push_ops([Push(Felt::new(1 << offset)), Mul]);
+    }
+
+    // clear the bit field in a: [b', a, ...] -> [a', b', ...]
+    span.push_ops([Swap, Push(Felt::from(!(mask << offset) as u32)), U32and]);
+
+    // a' | b' is computed as a' + b' since the bit field of a' is cleared
+    span.add_op(Add)
+}
+
+/// Translates u32to16 assembly instruction to VM operations.
+///
+/// Truncates the value at the top of the stack to its 16 least significant bits by computing a
+/// bitwise AND with 2^16 - 1. The operation fails if the value is not a u32 value.
+///
+/// This takes 2 VM cycles.
+pub fn u32to16(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([Push(Felt::from(u16::MAX)), U32and])
+}
+
+/// Translates u32to8 assembly instruction to VM operations.
+///
+/// Truncates the value at the top of the stack to its 8 least significant bits by computing a
+/// bitwise AND with 2^8 - 1. The operation fails if the value is not a u32 value.
+///
+/// This takes 2 VM cycles.
+pub fn u32to8(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([Push(Felt::from(u8::MAX)), U32and])
+}
+
+/// Translates u32maj assembly instruction to VM operations.
+///
+/// Computes the bitwise majority (a & b) ^ (a & c) ^ (b & c) of the values a, b, and c at the top
+/// of the stack, as used in SHA-256. This is done via the equivalent expression
+/// (a & b) ^ (c & (a ^ b)), which requires only two `U32AND` and two `U32XOR` operations. The
+/// operation fails if any of the values is not a u32 value.
+///
+/// This takes 7 VM cycles.
+pub fn u32maj(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // compute c & (a ^ b): [c, b, a, ...] -> [b, a, c & (a ^ b), ...]
+        Dup2, Dup2, U32xor, U32and, MovDn2,
+        // compute (a & b) ^ (c & (a ^ b))
+        U32and, U32xor,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32ch assembly instruction to VM operations.
+///
+/// Computes the bitwise choice (a & b) ^ (!a & c) of the values a, b, and c at the top of the
+/// stack, as used in SHA-256, i.e., each bit of the result is taken from b if the corresponding bit
+/// of a is set, and from c otherwise. This is done via the equivalent expression
+/// c ^ (a & (b ^ c)), which requires no bitwise negation. The operation fails if any of the values
+/// is not a u32 value.
+///
+/// This takes 6 VM cycles.
+pub fn u32ch(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // compute b ^ c: [c, b, a, ...] -> [b ^ c, c, a, ...]
+        Swap, Dup1, U32xor,
+        // compute c ^ (a & (b ^ c))
+        MovUp2, U32and, U32xor,
+    ];
+    span.add_ops(ops)
+}
+
+/// Translates u32sigma.a.b.c assembly instruction to VM operations.
+///
+/// Computes rotr(x, a) ^ rotr(x, b) ^ shr(x, c) for the value x at the top of the stack, as used
+/// in the sigma functions of SHA-256. The rotations and the shift are performed in the same way
+/// as in the immediate forms of u32checked_rotr and u32unchecked_shr, except that x is asserted
+/// to be a u32 value only once, by the first rotation; the results are then combined via two
+/// `U32XOR` operations.
+///
+/// This takes 15 VM cycles; 1 cycle fewer if a = 0, and 2 cycles fewer for each of b and c which
+/// is 0.
+///
+/// # Errors
+/// Returns an error if either of the rotation amounts a and b or the shift amount c is greater
+/// than 31.
+pub fn u32sigma(
+    span: &mut SpanBuilder,
+    a: u8,
+    b: u8,
+    c: u8,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    validate_rotation_amount("u32sigma", a)?;
+    validate_rotation_amount("u32sigma", b)?;
+    if c > MAX_U32_SHIFT_VALUE {
+        return Err(AssemblyError::invalid_u32_shift_amount("u32sigma", c));
+    }
+
+    // compute rotr(x, a), making sure x is a u32 value: [x, ...] -> [rotr(x, a), x, ...]
+    span.push_op(Dup0);
+    u32rotr(span, U32OpMode::Checked, Some(a))?;
+
+    // compute rotr(x, a) ^ rotr(x, b): [rotr(x, a), x, ...] -> [x, t, ...]
+    span.push_op(Dup1);
+    u32rotr(span, U32OpMode::Unchecked, Some(b))?;
+    span.push_ops([U32xor, Swap]);
+
+    // compute t ^ shr(x, c)
+    u32shr(span, U32OpMode::Unchecked, Some(c))?;
+    span.add_op(U32xor)
+}
+
+/// Appends operations to the span block which assert that the value at the top of the stack is a
+/// byte (i.e., a value smaller than 256), leaving the stack unchanged.
+///
+/// This is done by asserting that all bits except the 8 least significant ones are zero, and
+/// takes 5 VM cycles.
+fn append_byte_assert(span: &mut SpanBuilder) {
+    span.push_ops([Dup0, Push(Felt::new(0xffff_ff00)), U32and, Eqz, Assert(ZERO)]);
+}
+
+/// Handles U32ADD, U32SUB, and U32MUL operations in checked, wrapping, overflowing, and saturating
+/// modes, including handling of immediate parameters.
 ///
 /// Specifically handles these specific inputs per the spec.
-/// - Checked: fails if either of the inputs or the output is not a u32 value.
+/// - Checked: fails if either of the inputs or the output is not a u32 value. The inputs are
+///   checked via a single U32ASSERT2 operation before the base operation, and the output is
+///   checked by asserting that the overflow or underflow bits are zero; this costs 3 extra VM
+///   cycles compared to the overflowing mode.
 /// - Wrapping: does not check if the inputs are u32 values; overflow or underflow bits are
 ///   discarded.
 /// - Overflowing: does not check if the inputs are u32 values; overflow or underflow bits are
 ///   pushed onto the stack.
+/// - Saturating: does not check if the inputs are u32 values; on overflow the result is clamped
+///   to 2^32 - 1, and on underflow to 0. The bound is selected with CSWAP using the overflow or
+///   underflow bit, which costs 4 extra VM cycles compared to the overflowing mode. Only U32ADD
+///   and U32SUB are supported in this mode.
 fn handle_arithmetic_operation(
     span: &mut SpanBuilder,
     op: Operation,
@@ -400,6 +1290,7 @@ fn handle_arithmetic_operation(
 ) -> Result<Option<CodeBlock>, AssemblyError> {
     let mut drop_high_bits = false;
     let mut assert_u32_res = false;
+    let mut saturate = false;
 
     if let Some(imm) = imm {
         push_u32_value(span, imm);
@@ -414,6 +1305,9 @@ fn handle_arithmetic_operation(
             drop_high_bits = true;
         }
         U32OpMode::Overflowing => {}
+        U32OpMode::Saturating => {
+            saturate = true;
+        }
         _ => unreachable!("unsupported operation mode"),
     }
 
@@ -421,6 +1315,15 @@ fn handle_arithmetic_operation(
 
     if assert_u32_res {
         span.add_ops([Eqz, Assert(ZERO)])
+    } else if saturate {
+        // the stack is now [flag, result, ...]; push the bound beneath the flag and, if the flag
+        // is set, swap it with the result before dropping the value which is not selected
+        let bound = match op {
+            U32add => Push(Felt::from(u32::MAX)),
+            U32sub => Pad,
+            _ => unreachable!("unsupported operation for saturating mode"),
+        };
+        span.add_ops([bound, Swap, CSwap, Drop])
     } else if drop_high_bits {
         span.add_op(Drop)
     } else {
@@ -460,18 +1363,27 @@ fn handle_division(
 /// either as a provided immediate value, or as an element that already exists in the stack.
 ///
 /// If the used mode is `checked`, the function will assert that both `[b, a]` are valid `u32`.
-/// This function is equivalent to a bit shift operation, so the exponent shouldn't cause a number
-/// to be greater than `u32::MAX`; therefore, the maximum valid value must be `31`, as defined in
-/// the helper constants.
+/// This function is equivalent to a bit shift operation over words of `WORD_BITS` bits, so the
+/// exponent shouldn't cause a number to be greater than the largest word value; therefore, the
+/// maximum valid immediate value is `WORD_BITS - 1` (i.e., `31` for 32-bit words and `15` for
+/// 16-bit words). If an immediate value exceeds this bound, the error returned by `out_of_range`
+/// is propagated.
 ///
 /// This function supports only checked and unchecked modes; if some other mode is provided, it
 /// will panic.
-fn prepare_bitwise<const MAX_VALUE: u8>(
+fn prepare_bitwise<const WORD_BITS: u8>(
     span: &mut SpanBuilder,
     imm: Option<u8>,
     op_mode: U32OpMode,
     final_ops: [Operation; 2],
+    out_of_range: impl FnOnce(u8) -> AssemblyError,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
+    if let Some(imm) = imm {
+        if imm >= WORD_BITS {
+            return Err(out_of_range(imm));
+        }
+    }
+
     match (imm, op_mode) {
         (Some(0), U32OpMode::Checked) => {
             // if shift/rotation is performed by 0, just verify that stack top is u32
@@ -479,7 +1391,6 @@ fn prepare_bitwise<const MAX_VALUE: u8>(
             return Ok(None);
         }
         (Some(imm), U32OpMode::Checked) => {
-            validate_param(imm, 1..=MAX_VALUE)?;
             span.push_ops([Push(Felt::new(1 << imm)), U32assert2(ZERO)]);
         }
         (Some(0), U32OpMode::Unchecked) => {
@@ -501,6 +1412,132 @@ fn prepare_bitwise<const MAX_VALUE: u8>(
     span.add_ops(final_ops)
 }
 
+/// Returns the name of a checked or unchecked bitwise instruction depending on the mode.
+fn bitwise_instruction_name(
+    op_mode: &U32OpMode,
+    checked: &'static str,
+    unchecked: &'static str,
+) -> &'static str {
+    if *op_mode == U32OpMode::Checked {
+        checked
+    } else {
+        unchecked
+    }
+}
+
+/// Returns an error if the immediate bit index for the specified instruction is greater than 31.
+fn validate_bit_index(instruction: &str, imm: u8) -> Result<(), AssemblyError> {
+    if imm > MAX_U32_SHIFT_VALUE {
+        return Err(AssemblyError::invalid_u32_bit_index(instruction, imm));
+    }
+    Ok(())
+}
+
+/// Returns an error if the immediate bit field for the specified instruction does not fit into 32
+/// bits.
+fn validate_bit_field(instruction: &str, offset: u8, width: u8) -> Result<(), AssemblyError> {
+    if offset.saturating_add(width) > U32_WORD_BITS {
+        return Err(AssemblyError::invalid_u32_bit_field(instruction, offset, width));
+    }
+    Ok(())
+}
+
+/// Returns an error if the immediate rotation amount for the specified instruction is greater
+/// than 31.
+fn validate_rotation_amount(instruction: &str, imm: u8) -> Result<(), AssemblyError> {
+    if imm > MAX_U32_ROTATE_VALUE {
+        return Err(AssemblyError::invalid_u32_rotation_amount(instruction, imm));
+    }
+    Ok(())
+}
+
+/// Appends operations which replace the shift value b at the top of the stack with 2^(32-b).
+///
+/// The value is computed as 2^32 * (2^-1)^b using the EXPACC operation in the same way as in
+/// [append_pow2_op], but with the accumulator initialized to 2^32 and the base set to 2^-1. Since
+/// the shift value must be at most 31, only 5 EXPACC operations are needed, and asserting that
+/// the remaining exponent is zero verifies that b <= 31.
+///
+/// This takes 13 VM cycles.
+fn append_rotr_pow2_op(span: &mut SpanBuilder) {
+    // arrange the top of the stack for EXPACC operation: [b, ...] -> [0, 2^-1, 2^32, b, ...]
+    span.push_ops([Push(Felt::new(1 << 32)), Push(Felt::new(2).inv()), Pad]);
+    // calling expacc instruction 5 times
+    span.push_op_many(Expacc, 5);
+    // drop the top two elements bit and exp value of the latest bit.
+    span.push_ops([Drop, Drop]);
+    // taking `b` to the top and asserting if it's equal to ZERO after all the right shifts.
+    span.push_ops([Swap, Eqz, Assert(ZERO)]);
+}
+
+/// Swaps adjacent groups of `shift` bits in the u32 value at the top of the stack, where `mask`
+/// selects the upper group of each pair.
+///
+/// The value x is split into the bits in the upper groups h = x & mask and the bits in the lower
+/// groups l = x - h. The swapped value h / 2^k + l * 2^k is then computed as
+/// x * 2^k + h * (2^-k - 2^k) using field arithmetic; this is exact since h is divisible by 2^k
+/// and the result is always a u32 value.
+///
+/// This takes 9 VM cycles.
+fn swap_bit_groups(span: &mut SpanBuilder, mask: u32, shift: u32) {
+    let pow2 = Felt::new(1 << shift);
+    #[rustfmt::skip]
+    let ops = [
+        Dup0,
+        Push(Felt::from(mask)), U32and,
+        Push(pow2.inv() - pow2), Mul,
+        Swap,
+        Push(pow2), Mul,
+        Add,
+    ];
+    span.push_ops(ops);
+}
+
+/// Spreads the bits of the 16-bit value at the top of the stack apart, such that bit i of the value
+/// becomes bit 2i of the result.
+///
+/// This uses the standard cascade x = (x ^ (x << k)) & mask for k = 8, 4, 2, 1, where the left
+/// shifts are computed as field multiplications by 2^k; these never overflow, since the value
+/// being shifted is smaller than 2^(32 - k) at every step.
+///
+/// This takes 24 VM cycles.
+fn spread_bits(span: &mut SpanBuilder) {
+    for (shift, mask) in [(8, 0x00FF00FF), (4, 0x0F0F0F0F), (2, 0x33333333), (1, 0x55555555)] {
+        #[rustfmt::skip]
+        span.push_ops([
+            Dup0, Push(Felt::new(1 << shift)), Mul,
+            U32xor, Push(Felt::new(mask)), U32and,
+        ]);
+    }
+}
+
+/// Gathers the bits at even positions of the u32 value at the top of the stack into a 16-bit
+/// value, such that bit 2i of the value becomes bit i of the result. This is the inverse of
+/// [spread_bits].
+///
+/// The bits at odd positions are cleared first, and then the cascade x = (x ^ (x >> k)) & mask is
+/// applied for k = 1, 2, 4, 8, where the right shifts are computed via `U32DIV` by 2^k.
+///
+/// This takes 30 VM cycles.
+fn compact_bits(span: &mut SpanBuilder) {
+    span.push_ops([Push(Felt::new(0x55555555)), U32and]);
+    for (shift, mask) in [(1, 0x33333333), (2, 0x0F0F0F0F), (4, 0x00FF00FF), (8, 0x0000FFFF)] {
+        #[rustfmt::skip]
+        span.push_ops([
+            Dup0, Push(Felt::new(1 << shift)), U32div, Drop,
+            U32xor, Push(Felt::new(mask)), U32and,
+        ]);
+    }
+}
+
+/// Swaps the two 16-bit halves of the u32 value at the top of the stack by splitting x * 2^16
+/// into its high and low 32-bit limbs and adding them together.
+///
+/// This takes 4 VM cycles.
+fn swap_halves(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([Push(Felt::new(1 << 16)), Mul, U32split, Add])
+}
+
 // COMPARISON OPERATIONS
 // ================================================================================================
 
@@ -620,6 +1657,29 @@ pub fn u32gte(
     span.add_op(Not)
 }
 
+/// Translates u32cmp assembly instruction to VM operations.
+///
+/// Specifically we test the first two numbers to be u32 (U32ASSERT2), subtract the top value from
+/// the second to the top value (U32SUB), and use the underflow flag lt together with the equality
+/// flag eq of the difference and zero to compute the result as 1 - eq - 2 * lt. Thus, the result
+/// is -1 (i.e., p - 1) if a < b, 0 if a = b, and 1 if a > b.
+///
+/// This takes 9 VM cycles.
+pub fn u32cmp(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        U32assert2(ZERO),
+        U32sub,
+        // compute 2 * lt
+        Dup0, Add,
+        // compute eq
+        Swap, Eqz,
+        // compute 1 - (eq + 2 * lt)
+        Add, Neg, Incr,
+    ];
+    span.add_ops(ops)
+}
+
 /// Translates u32min assembly instructions to VM operations.
 ///
 /// Specifically, we test the first two numbers to be u32 (U32SPLIT NOT ASSERT), subtract the top
@@ -660,6 +1720,169 @@ pub fn u32max(
     span.add_ops([Swap, Drop])
 }
 
+/// Translates u32abs_diff assembly instruction to VM operations.
+///
+/// Specifically, we test the first two values to be u32 (U32ASSERT2), determine the max and the
+/// min of the two values in the same way as in u32max and u32min, and then subtract the min from
+/// the max. Since max >= min, the subtraction can be performed using field arithmetic.
+///
+/// This takes 11 VM cycles.
+pub fn u32abs_diff(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    compute_max_and_min(span, U32OpMode::Checked);
+
+    // Subtract the min from the max
+    span.add_ops([Swap, Neg, Add])
+}
+
+/// Translates u32clamp assembly instruction to VM operations.
+///
+/// The input stack is expected to be arranged as [max, min, value, ...], and the output is
+/// min(max(value, min), max). Specifically, we move the upper bound out of the way (MOVDN2),
+/// determine the max of the value and the lower bound in the same way as in u32max, and then
+/// determine the min of the result and the upper bound in the same way as in u32min. All three
+/// inputs are asserted to be u32 values.
+///
+/// The bounds are expected to satisfy min <= max; if this is not the case, max is returned.
+///
+/// This takes 20 VM cycles.
+pub fn u32clamp(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_op(MovDn2);
+
+    // Keep the max of the value and the lower bound
+    compute_max_and_min(span, U32OpMode::Checked);
+    span.push_ops([Swap, Drop]);
+
+    // Keep the min of the result and the upper bound
+    compute_max_and_min(span, U32OpMode::Checked);
+    span.add_op(Drop)
+}
+
+/// Translates i32lt assembly instruction to VM operations.
+///
+/// The values are interpreted as two's-complement 32-bit integers. Flipping the sign bits of both
+/// values (see [flip_sign_bits]) maps them onto u32 values with the same ordering, which are then
+/// compared via an unsigned lt check.
+///
+/// This takes 11 VM cycles, i.e., 5 more than u32checked_lt.
+pub fn i32lt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    flip_sign_bits(span);
+
+    // Restore the order of the values to get a lt check.
+    span.push_op(Swap);
+    compute_lt(span);
+
+    Ok(None)
+}
+
+/// Translates i32lte assembly instruction to VM operations.
+///
+/// The values are interpreted as two's-complement 32-bit integers and compared in the same way
+/// as in i32gt, after which the result is flipped.
+///
+/// This takes 11 VM cycles, i.e., 3 more than u32checked_lte.
+pub fn i32lte(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    flip_sign_bits(span);
+    compute_lt(span);
+
+    // Flip the final results to get the lte results.
+    span.add_op(Not)
+}
+
+/// Translates i32gt assembly instruction to VM operations.
+///
+/// The values are interpreted as two's-complement 32-bit integers. Flipping the sign bits of both
+/// values (see [flip_sign_bits]) maps them onto u32 values with the same ordering and also
+/// reverses their order on the stack, which makes an unsigned lt check compute the gt result.
+///
+/// This takes 10 VM cycles, i.e., 3 more than u32checked_gt.
+pub fn i32gt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    flip_sign_bits(span);
+    compute_lt(span);
+
+    Ok(None)
+}
+
+/// Translates i32gte assembly instruction to VM operations.
+///
+/// The values are interpreted as two's-complement 32-bit integers and compared in the same way
+/// as in i32lt, after which the result is flipped.
+///
+/// This takes 12 VM cycles, i.e., 5 more than u32checked_gte.
+pub fn i32gte(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    flip_sign_bits(span);
+
+    // Restore the order of the values and compute the lt check.
+    span.push_op(Swap);
+    compute_lt(span);
+
+    // Flip the final results to get the gte results.
+    span.add_op(Not)
+}
+
+/// Translates i32min assembly instruction to VM operations.
+///
+/// The values are interpreted as two's-complement 32-bit integers. The sign bits of both values
+/// are flipped (see [flip_sign_bits]), the min of the resulting u32 values is determined in the
+/// same way as in u32min, and the sign bit of the min is then flipped back.
+///
+/// This takes 15 VM cycles.
+pub fn i32min(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    flip_sign_bits(span);
+    compute_max_and_min(span, U32OpMode::Unchecked);
+
+    // Drop the max and keep the min
+    span.push_op(Drop);
+
+    // Restore the sign bit of the min
+    span.add_ops([Push(Felt::new(1 << 31)), U32xor])
+}
+
+/// Translates i32max assembly instruction to VM operations.
+///
+/// The values are interpreted as two's-complement 32-bit integers. The sign bits of both values
+/// are flipped (see [flip_sign_bits]), the max of the resulting u32 values is determined in the
+/// same way as in u32max, and the sign bit of the max is then flipped back.
+///
+/// This takes 16 VM cycles.
+pub fn i32max(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    flip_sign_bits(span);
+    compute_max_and_min(span, U32OpMode::Unchecked);
+
+    // Drop the min and keep the max
+    span.push_ops([Swap, Drop]);
+
+    // Restore the sign bit of the max
+    span.add_ops([Push(Felt::new(1 << 31)), U32xor])
+}
+
+/// Translates i32cls assembly instruction to VM operations.
+///
+/// The value a at the top of the stack is interpreted as a two's-complement 32-bit integer, and
+/// the number of leading bits equal to its sign bit, excluding the sign bit itself, is computed.
+/// If a is negative, it is first replaced with x = 2^32 - 1 - a (i.e., its bitwise negation), so
+/// that x < 2^31 in both cases and the result is the number of leading zeros of x minus one. This
+/// is then computed as 31 - ceil(log2(x + 1)), where ceil(log2(x + 1)) is provided via the advice
+/// stack and verified in the same way as in u32log2_ceil. The value a is asserted to be a u32
+/// value.
+///
+/// This takes 51 VM cycles.
+pub fn i32cls(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    #[rustfmt::skip]
+    let ops = [
+        // extract the sign bit s of a: [a, ...] -> [s, a, ...]
+        Dup0, Push(Felt::new(1 << 31)), U32and, Eqz, Not,
+        // compute 2^32 - 1 - a: [s, a, ...] -> [s, 2^32 - 1 - a, a, ...]
+        Dup1, Neg, Push(Felt::from(u32::MAX)), Add, Swap,
+        // keep a if s = 0, and 2^32 - 1 - a otherwise, then add one: [s, ...] -> [x + 1, ...]
+        CSwap, Drop, Incr,
+    ];
+    span.push_ops(ops);
+
+    // compute r = ceil(log2(x + 1)) and subtract it from 31
+    u32log2_ceil(span)?;
+    span.add_ops([Neg, Push(Felt::new(31)), Add])
+}
+
 // COMPARISON OPERATIONS - HELPERS
 // ================================================================================================
 
@@ -679,6 +1902,22 @@ fn compute_lt(span: &mut SpanBuilder) {
     ])
 }
 
+/// Flips the sign bits of the two values at the top of the stack by XOR-ing them with 2^31, and
+/// leaves them in reverse order: [b, a, ...] -> [a', b', ...].
+///
+/// For two's-complement 32-bit integers, this maps i32::MIN..=i32::MAX onto 0..=u32::MAX while
+/// preserving their ordering. The `U32XOR` operations also fail if either value is not a u32
+/// value. This takes 5 cycles.
+fn flip_sign_bits(span: &mut SpanBuilder) {
+    #[rustfmt::skip]
+    span.push_ops([
+        // flip the sign bit of b: [b, a, ...] -> [a, b', ...]
+        Push(Felt::new(1 << 31)), U32xor, Swap,
+        // flip the sign bit of a: [a, b', ...] -> [a', b', ...]
+        Push(Felt::new(1 << 31)), U32xor,
+    ]);
+}
+
 /// Duplicate the top two elements in the stack and check both are u32, and determine the min
 /// and max between them.
 ///
@@ -699,3 +1938,85 @@ fn compute_max_and_min(span: &mut SpanBuilder, op_mode: U32OpMode) {
         Eqz, CSwap,
     ]);
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        prepare_bitwise, u32pow, AssemblyError, CodeBlock, Felt, Operation::*, SpanBuilder,
+        U32OpMode,
+    };
+
+    #[test]
+    fn prepare_bitwise_16bit_words() {
+        let out_of_range = |imm: u8| AssemblyError::param_out_of_bounds(imm.into(), 0, 15);
+
+        // an immediate equal to the word width is rejected
+        let mut span = SpanBuilder::default();
+        let err = prepare_bitwise::<16>(
+            &mut span,
+            Some(16),
+            U32OpMode::Unchecked,
+            [U32mul, Drop],
+            out_of_range,
+        )
+        .unwrap_err();
+        assert_eq!(err, AssemblyError::param_out_of_bounds(16, 0, 15));
+
+        let err = prepare_bitwise::<16>(
+            &mut span,
+            Some(31),
+            U32OpMode::Checked,
+            [U32mul, Drop],
+            out_of_range,
+        )
+        .unwrap_err();
+        assert_eq!(err, AssemblyError::param_out_of_bounds(31, 0, 15));
+
+        // the largest valid immediate is accepted
+        let mut span = SpanBuilder::default();
+        prepare_bitwise::<16>(
+            &mut span,
+            Some(15),
+            U32OpMode::Unchecked,
+            [U32mul, Drop],
+            out_of_range,
+        )
+        .unwrap();
+        let mut blocks = Vec::new();
+        span.extract_final_span_into(&mut blocks);
+        let expected = CodeBlock::new_span(vec![Push(Felt::new(1 << 15)), U32mul, Drop]);
+        assert_eq!(blocks[0].hash(), expected.hash());
+    }
+
+    #[test]
+    fn u32pow_cycle_count() {
+        let mut span = SpanBuilder::default();
+        u32pow(&mut span).unwrap();
+        assert_eq!(span.op_count(), 293);
+    }
+
+    #[test]
+    fn u32_op_mode_hash_set() {
+        use std::collections::HashSet;
+
+        let modes = [
+            U32OpMode::Checked,
+            U32OpMode::Unchecked,
+            U32OpMode::Wrapping,
+            U32OpMode::Overflowing,
+            U32OpMode::Saturating,
+        ];
+
+        // modes are copied into the set, and duplicates are collapsed
+        let mut set = HashSet::new();
+        for mode in modes.iter().chain(modes.iter()) {
+            set.insert(*mode);
+        }
+        assert_eq!(set.len(), modes.len());
+        assert!(modes.iter().all(|mode| set.contains(mode)));
+        assert_eq!(format!("{:?}", U32OpMode::Wrapping), "Wrapping");
+    }
+}
@@ -2,7 +2,8 @@ use super::{
     AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList,
     Instruction, Operation, ToString, Vec,
 };
-use vm_core::{AdviceInjector, AssemblyOp};
+use crate::MAX_SPAN_OPS;
+use vm_core::{AdviceInjector, AssemblyOp, DebugOptions};
 
 // SPAN BUILDER
 // ================================================================================================
@@ -42,6 +43,18 @@ impl SpanBuilder {
         }
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of operations in the current span.
+    ///
+    /// This includes the operations of the wrapper's prologue (if the current span is the first
+    /// span of the body), but not the operations of the wrapper's epilogue. The count is reset to
+    /// zero whenever a span is extracted from the builder.
+    pub fn op_count(&self) -> usize {
+        self.ops.len()
+    }
+
     // OPERATIONS
     // --------------------------------------------------------------------------------------------
 
@@ -61,6 +74,16 @@ impl SpanBuilder {
         Ok(None)
     }
 
+    /// Adds the specified operation n times to the list of span operations and returns Ok(None).
+    pub fn add_op_many(
+        &mut self,
+        op: Operation,
+        n: usize,
+    ) -> Result<Option<CodeBlock>, AssemblyError> {
+        self.push_op_many(op, n);
+        Ok(None)
+    }
+
     /// Adds the specified operation to the list of span operations.
     pub fn push_op(&mut self, op: Operation) {
         self.ops.push(op);
@@ -75,12 +98,49 @@ impl SpanBuilder {
         self.ops.extend(ops.into_iter().map(|o| *o.borrow()));
     }
 
+    /// Adds the specified sequence of operations to the list of span operations, making sure that
+    /// the current span does not grow beyond [MAX_SPAN_OPS] operations.
+    ///
+    /// As with [SpanBuilder::op_count()], operations of the wrapper's epilogue are not counted.
+    ///
+    /// # Errors
+    /// Returns an error if the number of operations in the current span would exceed
+    /// [MAX_SPAN_OPS]; in this case, none of the operations are added to the span.
+    #[allow(dead_code)]
+    pub fn try_push_ops(&mut self, ops: &[Operation]) -> Result<(), AssemblyError> {
+        if self.ops.len() + ops.len() > MAX_SPAN_OPS {
+            return Err(AssemblyError::span_ops_limit_exceeded(
+                self.ops.len(),
+                ops.len(),
+                MAX_SPAN_OPS,
+            ));
+        }
+        self.ops.extend_from_slice(ops);
+        Ok(())
+    }
+
     /// Adds the specified operation n times to the list of span operations.
     pub fn push_op_many(&mut self, op: Operation, n: usize) {
         let new_len = self.ops.len() + n;
         self.ops.resize(new_len, op);
     }
 
+    /// Appends NOOP operations to the list of span operations until the number of operations in
+    /// the current span is a multiple of `multiple`.
+    ///
+    /// If the number of operations is already a multiple of `multiple`, the span is not changed.
+    ///
+    /// # Panics
+    /// Panics if `multiple` is zero.
+    #[allow(dead_code)]
+    pub fn pad_to(&mut self, multiple: usize) {
+        assert!(multiple > 0, "cannot pad a span to a multiple of zero");
+        let remainder = self.op_count() % multiple;
+        if remainder != 0 {
+            self.push_op_many(Operation::Noop, multiple - remainder);
+        }
+    }
+
     // DECORATORS
     // --------------------------------------------------------------------------------------------
 
@@ -94,6 +154,23 @@ impl SpanBuilder {
         self.push_decorator(Decorator::Advice(injector));
     }
 
+    /// Adds a debug decorator which prints out the top `n` items of the operand stack to the list
+    /// of span decorators.
+    ///
+    /// This is intended to help with inspecting intermediate stack states of multi-operation
+    /// instruction translations. The decorator is added only in debug builds of the assembler, and
+    /// is executed by the processor only when it runs in debug mode.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero or greater than [u16::MAX].
+    #[allow(dead_code)]
+    pub fn push_debug_stack(&mut self, n: usize) {
+        let n = u16::try_from(n).ok().filter(|&n| n > 0).expect("invalid number of stack items");
+        if cfg!(debug_assertions) {
+            self.push_decorator(Decorator::Debug(DebugOptions::StackTop(n)));
+        }
+    }
+
     /// Adds an AsmOp decorator to the list of span decorators.
     ///
     /// This indicates that the provided instruction should be tracked and the cycle count for
@@ -115,13 +192,15 @@ impl SpanBuilder {
     /// for instructions which do not contribute any operations to the span block - e.g., exec,
     /// call, and syscall.
     pub fn set_instruction_cycle_count(&mut self) {
+        let op_count = self.op_count();
+
         // get the last asmop decorator and the cycle at which it was added
         let (op_start, assembly_op) =
             self.decorators.get_mut(self.last_asmop_pos).expect("no asmop decorator");
         assert!(matches!(assembly_op, Decorator::AsmOp(_)));
 
         // compute the cycle count for the instruction
-        let cycle_count = self.ops.len() - *op_start;
+        let cycle_count = op_count - *op_start;
 
         // if the cycle count is 0, remove the decorator; otherwise update its cycle count
         if cycle_count == 0 {
@@ -164,3 +243,158 @@ impl SpanBuilder {
         self.extract_span_into(target);
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceInjector, AssemblyError, BodyWrapper, DebugOptions, Decorator, Operation,
+        SpanBuilder, Vec, MAX_SPAN_OPS,
+    };
+    use vm_core::{Felt, FieldElement};
+
+    #[test]
+    fn push_op_many() {
+        let mut span = SpanBuilder::default();
+        span.push_op(Operation::Pad);
+        span.push_op_many(Operation::Dup0, 3);
+        span.push_op(Operation::Drop);
+        assert_eq!(
+            span.ops,
+            [
+                Operation::Pad,
+                Operation::Dup0,
+                Operation::Dup0,
+                Operation::Dup0,
+                Operation::Drop
+            ]
+        );
+
+        // pushing an operation zero times should not change the list of operations
+        span.push_op_many(Operation::Swap, 0);
+        assert_eq!(span.ops.len(), 5);
+    }
+
+    #[test]
+    fn add_op_many() {
+        let mut span = SpanBuilder::default();
+        assert!(span.add_op_many(Operation::Swap, 2).unwrap().is_none());
+        assert!(span.add_op_many(Operation::Incr, 0).unwrap().is_none());
+        assert!(span.add_op_many(Operation::Drop, 1).unwrap().is_none());
+        assert_eq!(span.ops, [Operation::Swap, Operation::Swap, Operation::Drop]);
+    }
+
+    #[test]
+    fn try_push_ops() {
+        let mut span = SpanBuilder::default();
+        span.try_push_ops(&[Operation::Pad, Operation::Incr]).unwrap();
+        span.push_op_many(Operation::Dup0, MAX_SPAN_OPS - 3);
+        span.try_push_ops(&[]).unwrap();
+
+        // pushing past the limit fails and leaves the span unchanged
+        let err = span.try_push_ops(&[Operation::Add, Operation::Drop]).unwrap_err();
+        assert_eq!(err, AssemblyError::span_ops_limit_exceeded(MAX_SPAN_OPS - 1, 2, MAX_SPAN_OPS));
+        assert!(err.to_string().contains(&format!("at most {MAX_SPAN_OPS} operations")));
+        assert_eq!(span.op_count(), MAX_SPAN_OPS - 1);
+
+        // the span can still be filled up to the limit
+        span.try_push_ops(&[Operation::Add]).unwrap();
+        assert_eq!(span.op_count(), MAX_SPAN_OPS);
+        assert!(span.try_push_ops(&[Operation::Drop]).is_err());
+    }
+
+    #[test]
+    fn op_count() {
+        let mut span = SpanBuilder::default();
+        assert_eq!(span.op_count(), 0);
+
+        span.push_op(Operation::Pad);
+        span.push_ops([Operation::Incr, Operation::Dup0]);
+        span.push_op_many(Operation::Add, 2);
+        span.push_decorator(Decorator::Advice(AdviceInjector::U32Sqrt));
+        assert_eq!(span.op_count(), 5);
+
+        // extracting the span resets the count
+        let mut blocks = Vec::new();
+        span.extract_span_into(&mut blocks);
+        assert_eq!(span.op_count(), 0);
+
+        // operations in the epilogue of the wrapper are not counted
+        let wrapper = BodyWrapper {
+            prologue: vec![Operation::Push(Felt::ONE)],
+            epilogue: vec![Operation::Drop, Operation::Drop],
+        };
+        let mut span = SpanBuilder::new(Some(wrapper));
+        span.push_op(Operation::Dup0);
+        assert_eq!(span.op_count(), 2);
+    }
+
+    #[test]
+    fn pad_to() {
+        for (num_ops, multiple, expected) in
+            [(0, 4, 0), (1, 4, 4), (3, 4, 4), (5, 8, 8), (9, 8, 16), (7, 1, 7), (3, 3, 3)]
+        {
+            let mut span = SpanBuilder::default();
+            span.push_op_many(Operation::Incr, num_ops);
+            span.pad_to(multiple);
+            assert_eq!(span.op_count(), expected);
+
+            // the original operations are untouched and the span is padded with NOOPs
+            assert!(span.ops[..num_ops].iter().all(|&op| op == Operation::Incr));
+            assert!(span.ops[num_ops..].iter().all(|&op| op == Operation::Noop));
+        }
+
+        // an already aligned span is not changed
+        let mut span = SpanBuilder::default();
+        span.push_ops([Operation::Pad, Operation::Incr, Operation::Dup0, Operation::Add]);
+        span.pad_to(4);
+        span.pad_to(2);
+        assert_eq!(span.ops, [Operation::Pad, Operation::Incr, Operation::Dup0, Operation::Add]);
+    }
+
+    #[test]
+    fn push_debug_stack() {
+        let mut span = SpanBuilder::default();
+        span.push_ops([Operation::Pad, Operation::Incr]);
+        span.push_debug_stack(3);
+        span.push_op(Operation::Dup0);
+        span.push_debug_stack(u16::MAX as usize);
+
+        // the decorators do not add any operations
+        assert_eq!(span.op_count(), 3);
+
+        // the decorators are added only in debug builds, at the position of the next operation
+        let expected = if cfg!(debug_assertions) {
+            vec![
+                (2, Decorator::Debug(DebugOptions::StackTop(3))),
+                (3, Decorator::Debug(DebugOptions::StackTop(u16::MAX))),
+            ]
+        } else {
+            vec![]
+        };
+        assert_eq!(span.decorators, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_debug_stack_zero() {
+        let mut span = SpanBuilder::default();
+        span.push_debug_stack(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_debug_stack_too_many() {
+        let mut span = SpanBuilder::default();
+        span.push_debug_stack(u16::MAX as usize + 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pad_to_zero() {
+        let mut span = SpanBuilder::default();
+        span.pad_to(0);
+    }
+}
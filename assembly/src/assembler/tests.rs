@@ -1,5 +1,8 @@
 use super::{combine_blocks, Assembler, CodeBlock, Library, Module, Operation};
-use crate::{ast::ModuleAst, LibraryNamespace, LibraryPath, Version};
+use crate::{
+    ast::{Instruction, ModuleAst, Node, ProgramAst},
+    LibraryNamespace, LibraryPath, Version,
+};
 use core::slice::Iter;
 
 // TESTS
@@ -139,3 +142,87 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn u32_bitwise_imm_out_of_range() {
+    let cases = [
+        (
+            Instruction::U32CheckedShlImm(32),
+            "invalid shift amount 32 for instruction 'u32checked_shl'",
+        ),
+        (
+            Instruction::U32UncheckedShlImm(32),
+            "invalid shift amount 32 for instruction 'u32unchecked_shl'",
+        ),
+        (
+            Instruction::U32CheckedShrImm(40),
+            "invalid shift amount 40 for instruction 'u32checked_shr'",
+        ),
+        (
+            Instruction::U32UncheckedShrImm(32),
+            "invalid shift amount 32 for instruction 'u32unchecked_shr'",
+        ),
+        (
+            Instruction::U32CheckedRotlImm(32),
+            "invalid rotation amount 32 for instruction 'u32checked_rotl'",
+        ),
+        (
+            Instruction::U32UncheckedRotlImm(32),
+            "invalid rotation amount 32 for instruction 'u32unchecked_rotl'",
+        ),
+        (
+            Instruction::U32CheckedRotrImm(32),
+            "invalid rotation amount 32 for instruction 'u32checked_rotr'",
+        ),
+        (
+            Instruction::U32UncheckedRotrImm(255),
+            "invalid rotation amount 255 for instruction 'u32unchecked_rotr'",
+        ),
+        (
+            Instruction::U32TestBitImm(32),
+            "invalid bit index 32 for instruction 'u32testbit'",
+        ),
+        (
+            Instruction::U32SetBitImm(32),
+            "invalid bit index 32 for instruction 'u32setbit'",
+        ),
+        (
+            Instruction::U32ClearBitImm(32),
+            "invalid bit index 32 for instruction 'u32clearbit'",
+        ),
+        (
+            Instruction::U32Sigma(7, 32, 3),
+            "invalid rotation amount 32 for instruction 'u32sigma'",
+        ),
+        (
+            Instruction::U32Sigma(7, 18, 32),
+            "invalid shift amount 32 for instruction 'u32sigma'",
+        ),
+    ];
+
+    let assembler = Assembler::default();
+    for (instruction, expected) in cases {
+        let ast = ProgramAst::new(vec![Node::Instruction(instruction)], vec![]).unwrap();
+        let err = assembler.compile_ast(&ast).unwrap_err().to_string();
+        assert!(err.starts_with(expected), "unexpected error: {err}");
+        assert!(err.ends_with("must be in the range 0..=31"), "unexpected error: {err}");
+    }
+
+    // bit fields which do not fit into 32 bits are rejected as well
+    let ast =
+        ProgramAst::new(vec![Node::Instruction(Instruction::U32BfeImm(16, 17))], vec![]).unwrap();
+    let err = assembler.compile_ast(&ast).unwrap_err().to_string();
+    assert!(
+        err.starts_with("invalid bit field with offset 16 and width 17 for instruction 'u32bfe'")
+    );
+    let ast = ProgramAst::new(vec![Node::Instruction(Instruction::U32Bfi(33, 0))], vec![]).unwrap();
+    let err = assembler.compile_ast(&ast).unwrap_err().to_string();
+    assert!(
+        err.starts_with("invalid bit field with offset 33 and width 0 for instruction 'u32bfi'")
+    );
+
+    // the largest valid amount is still accepted
+    let ast = ProgramAst::new(vec![Node::Instruction(Instruction::U32CheckedShlImm(31))], vec![])
+        .unwrap();
+    assert!(assembler.compile_ast(&ast).is_ok());
+}
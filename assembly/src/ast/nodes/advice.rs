@@ -19,6 +19,12 @@ use vm_core::{AdviceInjector, Felt, SignatureKind, ZERO};
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AdviceInjectorNode {
     PushU64div,
+    PushU64mul,
+    PushFieldInv,
+    PushSortWords { count: u32 },
+    PushBinarySearch,
+    PushRandom { count: u32 },
+    PushExt2sqrt,
     PushExt2intt,
     PushSmtGet,
     PushSmtSet,
@@ -27,12 +33,27 @@ pub enum AdviceInjectorNode {
     PushMapValImm { offset: u8 },
     PushMapValN,
     PushMapValNImm { offset: u8 },
+    PopMapVal,
+    PopMapValImm { offset: u8 },
+    PopMapValN,
+    PopMapValNImm { offset: u8 },
     PushMtNode,
+    PushMtPath,
+    PushRpoPreimage,
     InsertMem,
+    InsertMemN,
     InsertHdword,
     InsertHdwordImm { domain: u8 },
     InsertHperm,
     PushSignature { kind: SignatureKind },
+    PushU64mod { modulus: u64 },
+    PushFixedRecip { frac_bits: u8 },
+    PushCrc32 { poly: u32 },
+    PushInversePermutation { count: u32 },
+    PushFactor,
+    PushBarrettMu { modulus: u64, k: u8 },
+    PushFieldOrder,
+    PushMapKeys,
 }
 
 impl From<&AdviceInjectorNode> for AdviceInjector {
@@ -40,6 +61,12 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         use AdviceInjectorNode::*;
         match value {
             PushU64div => Self::DivU64,
+            PushU64mul => Self::MulU64,
+            PushFieldInv => Self::FieldInv,
+            PushSortWords { count } => Self::SortWords { count: *count },
+            PushBinarySearch => Self::BinarySearch,
+            PushRandom { count } => Self::DrawRandom { count: *count },
+            PushExt2sqrt => Self::Ext2Sqrt,
             PushExt2intt => Self::Ext2Intt,
             PushSmtGet => Self::SmtGet,
             PushSmtSet => Self::SmtSet,
@@ -47,27 +74,67 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
             PushMapVal => Self::MapValueToStack {
                 include_len: false,
                 key_offset: 0,
+                consume: false,
             },
             PushMapValImm { offset } => Self::MapValueToStack {
                 include_len: false,
                 key_offset: (*offset) as usize,
+                consume: false,
             },
             PushMapValN => Self::MapValueToStack {
                 include_len: true,
                 key_offset: 0,
+                consume: false,
             },
             PushMapValNImm { offset } => Self::MapValueToStack {
                 include_len: true,
                 key_offset: (*offset) as usize,
+                consume: false,
+            },
+            PopMapVal => Self::MapValueToStack {
+                include_len: false,
+                key_offset: 0,
+                consume: true,
+            },
+            PopMapValImm { offset } => Self::MapValueToStack {
+                include_len: false,
+                key_offset: (*offset) as usize,
+                consume: true,
+            },
+            PopMapValN => Self::MapValueToStack {
+                include_len: true,
+                key_offset: 0,
+                consume: true,
+            },
+            PopMapValNImm { offset } => Self::MapValueToStack {
+                include_len: true,
+                key_offset: (*offset) as usize,
+                consume: true,
             },
             PushMtNode => Self::MerkleNodeToStack,
-            InsertMem => Self::MemToMap,
+            PushMtPath => Self::MerklePathToStack,
+            PushRpoPreimage => Self::RpoPreimageToStack,
+            InsertMem => Self::MemToMap { include_len: false },
+            InsertMemN => Self::MemToMap { include_len: true },
             InsertHdword => Self::HdwordToMap { domain: ZERO },
             InsertHdwordImm { domain } => Self::HdwordToMap {
                 domain: Felt::from(*domain),
             },
             InsertHperm => Self::HpermToMap,
             PushSignature { kind } => Self::SigToStack { kind: *kind },
+            PushU64mod { modulus } => Self::ModReduce { modulus: *modulus },
+            PushFixedRecip { frac_bits } => Self::FixedRecip {
+                frac_bits: *frac_bits,
+            },
+            PushCrc32 { poly } => Self::Crc32 { poly: *poly },
+            PushInversePermutation { count } => Self::InversePermutation { count: *count },
+            PushFactor => Self::FactorHint,
+            PushBarrettMu { modulus, k } => Self::BarrettMu {
+                modulus: *modulus,
+                k: *k,
+            },
+            PushFieldOrder => Self::FieldOrder,
+            PushMapKeys => Self::MapKeysSorted,
         }
     }
 }
@@ -77,6 +144,12 @@ impl fmt::Display for AdviceInjectorNode {
         use AdviceInjectorNode::*;
         match self {
             PushU64div => write!(f, "push_u64div"),
+            PushU64mul => write!(f, "push_u64mul"),
+            PushFieldInv => write!(f, "push_inv"),
+            PushSortWords { count } => write!(f, "push_sortw.{count}"),
+            PushBinarySearch => write!(f, "push_bsearchw"),
+            PushRandom { count } => write!(f, "push_rand.{count}"),
+            PushExt2sqrt => write!(f, "push_ext2sqrt"),
             PushExt2intt => write!(f, "push_ext2intt"),
             PushSmtGet => write!(f, "push_smtget"),
             PushSmtSet => write!(f, "push_smtset"),
@@ -85,12 +158,27 @@ impl fmt::Display for AdviceInjectorNode {
             PushMapValImm { offset } => write!(f, "push_mapval.{offset}"),
             PushMapValN => write!(f, "push_mapvaln"),
             PushMapValNImm { offset } => write!(f, "push_mapvaln.{offset}"),
+            PopMapVal => write!(f, "pop_mapval"),
+            PopMapValImm { offset } => write!(f, "pop_mapval.{offset}"),
+            PopMapValN => write!(f, "pop_mapvaln"),
+            PopMapValNImm { offset } => write!(f, "pop_mapvaln.{offset}"),
             PushMtNode => write!(f, "push_mtnode"),
+            PushMtPath => write!(f, "push_mtpath"),
+            PushRpoPreimage => write!(f, "push_rpo_preimage"),
             InsertMem => write!(f, "insert_mem"),
+            InsertMemN => write!(f, "insert_memn"),
             InsertHdword => write!(f, "insert_hdword"),
             InsertHdwordImm { domain } => write!(f, "insert_hdword.{domain}"),
             InsertHperm => writeln!(f, "insert_hperm"),
             PushSignature { kind } => write!(f, "push_sig.{kind}"),
+            PushU64mod { modulus } => write!(f, "push_u64mod.{modulus}"),
+            PushFixedRecip { frac_bits } => write!(f, "push_recip.{frac_bits}"),
+            PushCrc32 { poly } => write!(f, "push_crc32.{poly}"),
+            PushInversePermutation { count } => write!(f, "push_invperm.{count}"),
+            PushFactor => write!(f, "push_factor"),
+            PushBarrettMu { modulus, k } => write!(f, "push_barrettmu.{modulus}.{k}"),
+            PushFieldOrder => write!(f, "push_order"),
+            PushMapKeys => write!(f, "push_mapkeys"),
         }
     }
 }
@@ -113,12 +201,45 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_FIELD_INV: u8 = 15;
+const PUSH_SORTW: u8 = 16;
+const PUSH_U64MUL: u8 = 17;
+const POP_MAPVAL: u8 = 18;
+const POP_MAPVAL_IMM: u8 = 19;
+const POP_MAPVALN: u8 = 20;
+const POP_MAPVALN_IMM: u8 = 21;
+const PUSH_EXT2SQRT: u8 = 22;
+const PUSH_BSEARCHW: u8 = 23;
+const INSERT_MEMN: u8 = 24;
+const PUSH_RAND: u8 = 25;
+const PUSH_MTPATH: u8 = 26;
+const PUSH_RPO_PREIMAGE: u8 = 27;
+const PUSH_U64MOD: u8 = 28;
+const PUSH_RECIP: u8 = 29;
+const PUSH_CRC32: u8 = 30;
+const PUSH_INVPERM: u8 = 31;
+const PUSH_FACTOR: u8 = 32;
+const PUSH_BARRETTMU: u8 = 33;
+const PUSH_ORDER: u8 = 34;
+const PUSH_MAPKEYS: u8 = 35;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         use AdviceInjectorNode::*;
         match self {
             PushU64div => target.write_u8(PUSH_U64DIV),
+            PushU64mul => target.write_u8(PUSH_U64MUL),
+            PushFieldInv => target.write_u8(PUSH_FIELD_INV),
+            PushSortWords { count } => {
+                target.write_u8(PUSH_SORTW);
+                target.write_u32(*count);
+            }
+            PushBinarySearch => target.write_u8(PUSH_BSEARCHW),
+            PushRandom { count } => {
+                target.write_u8(PUSH_RAND);
+                target.write_u32(*count);
+            }
+            PushExt2sqrt => target.write_u8(PUSH_EXT2SQRT),
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushSmtSet => target.write_u8(PUSH_SMTSET),
@@ -133,8 +254,21 @@ impl Serializable for AdviceInjectorNode {
                 target.write_u8(PUSH_MAPVALN_IMM);
                 target.write_u8(*offset);
             }
+            PopMapVal => target.write_u8(POP_MAPVAL),
+            PopMapValImm { offset } => {
+                target.write_u8(POP_MAPVAL_IMM);
+                target.write_u8(*offset);
+            }
+            PopMapValN => target.write_u8(POP_MAPVALN),
+            PopMapValNImm { offset } => {
+                target.write_u8(POP_MAPVALN_IMM);
+                target.write_u8(*offset);
+            }
             PushMtNode => target.write_u8(PUSH_MTNODE),
+            PushMtPath => target.write_u8(PUSH_MTPATH),
+            PushRpoPreimage => target.write_u8(PUSH_RPO_PREIMAGE),
             InsertMem => target.write_u8(INSERT_MEM),
+            InsertMemN => target.write_u8(INSERT_MEMN),
             InsertHdword => target.write_u8(INSERT_HDWORD),
             InsertHdwordImm { domain } => {
                 target.write_u8(INSERT_HDWORD_IMM);
@@ -145,6 +279,30 @@ impl Serializable for AdviceInjectorNode {
                 target.write_u8(PUSH_SIG);
                 signatures::write_options_into(target, kind)
             }
+            PushU64mod { modulus } => {
+                target.write_u8(PUSH_U64MOD);
+                target.write_u64(*modulus);
+            }
+            PushFixedRecip { frac_bits } => {
+                target.write_u8(PUSH_RECIP);
+                target.write_u8(*frac_bits);
+            }
+            PushCrc32 { poly } => {
+                target.write_u8(PUSH_CRC32);
+                target.write_u32(*poly);
+            }
+            PushInversePermutation { count } => {
+                target.write_u8(PUSH_INVPERM);
+                target.write_u32(*count);
+            }
+            PushFactor => target.write_u8(PUSH_FACTOR),
+            PushBarrettMu { modulus, k } => {
+                target.write_u8(PUSH_BARRETTMU);
+                target.write_u64(*modulus);
+                target.write_u8(*k);
+            }
+            PushFieldOrder => target.write_u8(PUSH_ORDER),
+            PushMapKeys => target.write_u8(PUSH_MAPKEYS),
         }
     }
 }
@@ -153,6 +311,18 @@ impl Deserializable for AdviceInjectorNode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64div),
+            PUSH_U64MUL => Ok(AdviceInjectorNode::PushU64mul),
+            PUSH_FIELD_INV => Ok(AdviceInjectorNode::PushFieldInv),
+            PUSH_SORTW => {
+                let count = source.read_u32()?;
+                Ok(AdviceInjectorNode::PushSortWords { count })
+            }
+            PUSH_BSEARCHW => Ok(AdviceInjectorNode::PushBinarySearch),
+            PUSH_RAND => {
+                let count = source.read_u32()?;
+                Ok(AdviceInjectorNode::PushRandom { count })
+            }
+            PUSH_EXT2SQRT => Ok(AdviceInjectorNode::PushExt2sqrt),
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_SMTSET => Ok(AdviceInjectorNode::PushSmtSet),
@@ -173,8 +343,27 @@ impl Deserializable for AdviceInjectorNode {
                 }
                 Ok(AdviceInjectorNode::PushMapValNImm { offset })
             }
+            POP_MAPVAL => Ok(AdviceInjectorNode::PopMapVal),
+            POP_MAPVAL_IMM => {
+                let offset = source.read_u8()?;
+                if offset > MAX_STACK_WORD_OFFSET {
+                    return Err(DeserializationError::InvalidValue("invalid offset".to_string()));
+                }
+                Ok(AdviceInjectorNode::PopMapValImm { offset })
+            }
+            POP_MAPVALN => Ok(AdviceInjectorNode::PopMapValN),
+            POP_MAPVALN_IMM => {
+                let offset = source.read_u8()?;
+                if offset > MAX_STACK_WORD_OFFSET {
+                    return Err(DeserializationError::InvalidValue("invalid offset".to_string()));
+                }
+                Ok(AdviceInjectorNode::PopMapValNImm { offset })
+            }
             PUSH_MTNODE => Ok(AdviceInjectorNode::PushMtNode),
+            PUSH_MTPATH => Ok(AdviceInjectorNode::PushMtPath),
+            PUSH_RPO_PREIMAGE => Ok(AdviceInjectorNode::PushRpoPreimage),
             INSERT_MEM => Ok(AdviceInjectorNode::InsertMem),
+            INSERT_MEMN => Ok(AdviceInjectorNode::InsertMemN),
             INSERT_HDWORD => Ok(AdviceInjectorNode::InsertHdword),
             INSERT_HDWORD_IMM => {
                 let domain = source.read_u8()?;
@@ -184,6 +373,44 @@ impl Deserializable for AdviceInjectorNode {
             PUSH_SIG => Ok(AdviceInjectorNode::PushSignature {
                 kind: signatures::read_options_from(source)?,
             }),
+            PUSH_U64MOD => {
+                let modulus = source.read_u64()?;
+                if modulus == 0 {
+                    return Err(DeserializationError::InvalidValue("invalid modulus".to_string()));
+                }
+                Ok(AdviceInjectorNode::PushU64mod { modulus })
+            }
+            PUSH_RECIP => {
+                let frac_bits = source.read_u8()?;
+                if frac_bits > AdviceInjector::MAX_FIXED_RECIP_FRAC_BITS {
+                    return Err(DeserializationError::InvalidValue(
+                        "invalid number of fractional bits".to_string(),
+                    ));
+                }
+                Ok(AdviceInjectorNode::PushFixedRecip { frac_bits })
+            }
+            PUSH_CRC32 => {
+                let poly = source.read_u32()?;
+                Ok(AdviceInjectorNode::PushCrc32 { poly })
+            }
+            PUSH_INVPERM => {
+                let count = source.read_u32()?;
+                Ok(AdviceInjectorNode::PushInversePermutation { count })
+            }
+            PUSH_FACTOR => Ok(AdviceInjectorNode::PushFactor),
+            PUSH_BARRETTMU => {
+                let modulus = source.read_u64()?;
+                if modulus == 0 {
+                    return Err(DeserializationError::InvalidValue("invalid modulus".to_string()));
+                }
+                let k = source.read_u8()?;
+                if k > AdviceInjector::MAX_BARRETT_K {
+                    return Err(DeserializationError::InvalidValue("invalid exponent".to_string()));
+                }
+                Ok(AdviceInjectorNode::PushBarrettMu { modulus, k })
+            }
+            PUSH_ORDER => Ok(AdviceInjectorNode::PushFieldOrder),
+            PUSH_MAPKEYS => Ok(AdviceInjectorNode::PushMapKeys),
             val => Err(DeserializationError::InvalidValue(val.to_string())),
         }
     }
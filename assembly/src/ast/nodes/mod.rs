@@ -98,26 +98,34 @@ pub enum Instruction {
     U32AssertWWithError(ErrorCode),
     U32Split,
     U32Cast,
+    U32FromBytes,
+    U32ToBytes,
     U32CheckedAdd,
     U32CheckedAddImm(u32),
     U32WrappingAdd,
     U32WrappingAddImm(u32),
     U32OverflowingAdd,
     U32OverflowingAddImm(u32),
+    U32SaturatingAdd,
+    U32SaturatingAddImm(u32),
     U32OverflowingAdd3,
     U32WrappingAdd3,
     U32CheckedSub,
     U32CheckedSubImm(u32),
     U32WrappingSub,
     U32WrappingSubImm(u32),
+    U32SaturatingSub,
+    U32SaturatingSubImm(u32),
     U32OverflowingSub,
     U32OverflowingSubImm(u32),
+    U32WrappingNeg,
     U32CheckedMul,
     U32CheckedMulImm(u32),
     U32WrappingMul,
     U32WrappingMulImm(u32),
     U32OverflowingMul,
     U32OverflowingMulImm(u32),
+    U32MulHi,
     U32OverflowingMadd,
     U32WrappingMadd,
     U32CheckedDiv,
@@ -132,10 +140,22 @@ pub enum Instruction {
     U32CheckedDivModImm(u32),
     U32UncheckedDivMod,
     U32UncheckedDivModImm(u32),
+    U32ExactDiv,
+    U32ExactDivImm(u32),
+    U32DivRound,
+    U32DivRoundImm(u32),
+    U32Pow,
+    U32Sqrt,
+    U32Log2Ceil,
+    U32NextPow2,
+    U32Gcd,
+    U32Midpoint,
     U32CheckedAnd,
     U32CheckedOr,
     U32CheckedXor,
     U32CheckedNot,
+    U32Nand,
+    U32Nor,
     U32CheckedShr,
     U32CheckedShrImm(u8),
     U32UncheckedShr,
@@ -152,8 +172,32 @@ pub enum Instruction {
     U32CheckedRotlImm(u8),
     U32UncheckedRotl,
     U32UncheckedRotlImm(u8),
+    U32Rcl,
+    U32Rcr,
     U32CheckedPopcnt,
     U32UncheckedPopcnt,
+    U32Hamming,
+    U32PopEq,
+    U32DistinctBytes,
+    U32BitFill,
+    U32Rev,
+    U32Byteswap,
+    U32MortonEncode,
+    U32MortonDecode,
+    U32TestBit,
+    U32TestBitImm(u8),
+    U32SetBit,
+    U32SetBitImm(u8),
+    U32ClearBit,
+    U32ClearBitImm(u8),
+    U32Bfe,
+    U32BfeImm(u8, u8),
+    U32Bfi(u8, u8),
+    U32To16,
+    U32To8,
+    U32Maj,
+    U32Ch,
+    U32Sigma(u8, u8, u8),
     U32CheckedEq,
     U32CheckedEqImm(u32),
     U32CheckedNeq,
@@ -166,10 +210,20 @@ pub enum Instruction {
     U32UncheckedGt,
     U32CheckedGte,
     U32UncheckedGte,
+    U32Cmp,
     U32CheckedMin,
     U32UncheckedMin,
     U32CheckedMax,
     U32UncheckedMax,
+    U32AbsDiff,
+    U32Clamp,
+    I32Lt,
+    I32Lte,
+    I32Gt,
+    I32Gte,
+    I32Min,
+    I32Max,
+    I32Cls,
 
     // ----- stack manipulation -------------------------------------------------------------------
     Drop,
@@ -382,26 +436,34 @@ impl fmt::Display for Instruction {
             Self::U32AssertWWithError(err_code) => write!(f, "u32assertw.err={err_code}"),
             Self::U32Split => write!(f, "u32split"),
             Self::U32Cast => write!(f, "u32cast"),
+            Self::U32FromBytes => write!(f, "u32frombytes"),
+            Self::U32ToBytes => write!(f, "u32tobytes"),
             Self::U32CheckedAdd => write!(f, "u32checked_add"),
             Self::U32CheckedAddImm(value) => write!(f, "u32checked_add.{value}"),
             Self::U32WrappingAdd => write!(f, "u32wrapping_add"),
             Self::U32WrappingAddImm(value) => write!(f, "u32wrapping_add.{value}"),
             Self::U32OverflowingAdd => write!(f, "u32overflowing_add"),
             Self::U32OverflowingAddImm(value) => write!(f, "u32overflowing_add.{value}"),
+            Self::U32SaturatingAdd => write!(f, "u32saturating_add"),
+            Self::U32SaturatingAddImm(value) => write!(f, "u32saturating_add.{value}"),
             Self::U32OverflowingAdd3 => write!(f, "u32overflowing_add3"),
             Self::U32WrappingAdd3 => write!(f, "u32wrapping_add3"),
             Self::U32CheckedSub => write!(f, "u32checked_sub"),
             Self::U32CheckedSubImm(value) => write!(f, "u32checked_sub.{value}"),
             Self::U32WrappingSub => write!(f, "u32wrapping_sub"),
             Self::U32WrappingSubImm(value) => write!(f, "u32wrapping_sub.{value}"),
+            Self::U32SaturatingSub => write!(f, "u32saturating_sub"),
+            Self::U32SaturatingSubImm(value) => write!(f, "u32saturating_sub.{value}"),
             Self::U32OverflowingSub => write!(f, "u32overflowing_sub"),
             Self::U32OverflowingSubImm(value) => write!(f, "u32overflowing_sub.{value}"),
+            Self::U32WrappingNeg => write!(f, "u32wrapping_neg"),
             Self::U32CheckedMul => write!(f, "u32checked_mul"),
             Self::U32CheckedMulImm(value) => write!(f, "u32checked_mul.{value}"),
             Self::U32WrappingMul => write!(f, "u32wrapping_mul"),
             Self::U32WrappingMulImm(value) => write!(f, "u32wrapping_mul.{value}"),
             Self::U32OverflowingMul => write!(f, "u32overflowing_mul"),
             Self::U32OverflowingMulImm(value) => write!(f, "u32overflowing_mul.{value}"),
+            Self::U32MulHi => write!(f, "u32mulhi"),
             Self::U32OverflowingMadd => write!(f, "u32overflowing_madd"),
             Self::U32WrappingMadd => write!(f, "u32wrapping_madd"),
             Self::U32CheckedDiv => write!(f, "u32checked_div"),
@@ -416,10 +478,22 @@ impl fmt::Display for Instruction {
             Self::U32CheckedDivModImm(value) => write!(f, "u32checked_divmod.{value}"),
             Self::U32UncheckedDivMod => write!(f, "u32unchecked_divmod"),
             Self::U32UncheckedDivModImm(value) => write!(f, "u32unchecked_divmod.{value}"),
+            Self::U32ExactDiv => write!(f, "u32exactdiv"),
+            Self::U32ExactDivImm(value) => write!(f, "u32exactdiv.{value}"),
+            Self::U32DivRound => write!(f, "u32div_round"),
+            Self::U32DivRoundImm(value) => write!(f, "u32div_round.{value}"),
+            Self::U32Pow => write!(f, "u32pow"),
+            Self::U32Sqrt => write!(f, "u32sqrt"),
+            Self::U32Log2Ceil => write!(f, "u32log2_ceil"),
+            Self::U32NextPow2 => write!(f, "u32nextpow2"),
+            Self::U32Gcd => write!(f, "u32gcd"),
+            Self::U32Midpoint => write!(f, "u32midpoint"),
             Self::U32CheckedAnd => write!(f, "u32checked_and"),
             Self::U32CheckedOr => write!(f, "u32checked_or"),
             Self::U32CheckedXor => write!(f, "u32checked_xor"),
             Self::U32CheckedNot => write!(f, "u32checked_not"),
+            Self::U32Nand => write!(f, "u32nand"),
+            Self::U32Nor => write!(f, "u32nor"),
             Self::U32CheckedShr => write!(f, "u32checked_shr"),
             Self::U32CheckedShrImm(value) => write!(f, "u32checked_shr.{value}"),
             Self::U32UncheckedShr => write!(f, "u32unchecked_shr"),
@@ -436,8 +510,32 @@ impl fmt::Display for Instruction {
             Self::U32CheckedRotlImm(value) => write!(f, "u32checked_rotl.{value}"),
             Self::U32UncheckedRotl => write!(f, "u32unchecked_rotl"),
             Self::U32UncheckedRotlImm(value) => write!(f, "u32unchecked_rotl.{value}"),
+            Self::U32Rcl => write!(f, "u32rcl"),
+            Self::U32Rcr => write!(f, "u32rcr"),
             Self::U32CheckedPopcnt => write!(f, "u32checked_popcnt"),
             Self::U32UncheckedPopcnt => write!(f, "u32unchecked_popcnt"),
+            Self::U32Hamming => write!(f, "u32hamming"),
+            Self::U32PopEq => write!(f, "u32popeq"),
+            Self::U32DistinctBytes => write!(f, "u32distinctbytes"),
+            Self::U32BitFill => write!(f, "u32bitfill"),
+            Self::U32Rev => write!(f, "u32rev"),
+            Self::U32Byteswap => write!(f, "u32byteswap"),
+            Self::U32MortonEncode => write!(f, "u32morton_encode"),
+            Self::U32MortonDecode => write!(f, "u32morton_decode"),
+            Self::U32TestBit => write!(f, "u32testbit"),
+            Self::U32TestBitImm(value) => write!(f, "u32testbit.{value}"),
+            Self::U32SetBit => write!(f, "u32setbit"),
+            Self::U32SetBitImm(value) => write!(f, "u32setbit.{value}"),
+            Self::U32ClearBit => write!(f, "u32clearbit"),
+            Self::U32ClearBitImm(value) => write!(f, "u32clearbit.{value}"),
+            Self::U32Bfe => write!(f, "u32bfe"),
+            Self::U32BfeImm(offset, width) => write!(f, "u32bfe.{offset}.{width}"),
+            Self::U32Bfi(offset, width) => write!(f, "u32bfi.{offset}.{width}"),
+            Self::U32To16 => write!(f, "u32to16"),
+            Self::U32To8 => write!(f, "u32to8"),
+            Self::U32Maj => write!(f, "u32maj"),
+            Self::U32Ch => write!(f, "u32ch"),
+            Self::U32Sigma(a, b, c) => write!(f, "u32sigma.{a}.{b}.{c}"),
             Self::U32CheckedEq => write!(f, "u32checked_eq"),
             Self::U32CheckedEqImm(value) => write!(f, "u32checked_eq.{value}"),
             Self::U32CheckedNeq => write!(f, "u32checked_neq"),
@@ -450,10 +548,20 @@ impl fmt::Display for Instruction {
             Self::U32UncheckedGt => write!(f, "u32unchecked_gt"),
             Self::U32CheckedGte => write!(f, "u32checked_gte"),
             Self::U32UncheckedGte => write!(f, "u32unchecked_gte"),
+            Self::U32Cmp => write!(f, "u32cmp"),
             Self::U32CheckedMin => write!(f, "u32checked_min"),
             Self::U32UncheckedMin => write!(f, "u32unchecked_min"),
             Self::U32CheckedMax => write!(f, "u32checked_max"),
             Self::U32UncheckedMax => write!(f, "u32unchecked_max"),
+            Self::U32AbsDiff => write!(f, "u32abs_diff"),
+            Self::U32Clamp => write!(f, "u32clamp"),
+            Self::I32Lt => write!(f, "i32lt"),
+            Self::I32Lte => write!(f, "i32lte"),
+            Self::I32Gt => write!(f, "i32gt"),
+            Self::I32Gte => write!(f, "i32gte"),
+            Self::I32Min => write!(f, "i32min"),
+            Self::I32Max => write!(f, "i32max"),
+            Self::I32Cls => write!(f, "i32cls"),
 
             // ----- stack manipulation ---------------------------------------------------------------
             Self::Drop => write!(f, "drop"),
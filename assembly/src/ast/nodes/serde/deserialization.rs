@@ -8,44 +8,40 @@ use super::{
 
 impl Deserializable for Node {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let first_byte = source.peek_u8()?;
-
-        if first_byte == OpCode::IfElse as u8 {
-            source.read_u8()?;
-
-            let if_block_len = source.read_u16()? as usize;
-            let nodes = Deserializable::read_batch_from(source, if_block_len)?;
-            let true_case = CodeBody::new(nodes);
-
-            let else_block_len = source.read_u16()? as usize;
-            let nodes = Deserializable::read_batch_from(source, else_block_len)?;
-            let false_case = CodeBody::new(nodes);
+        let opcode = OpCode::read_from(source)?;
 
-            Ok(Node::IfElse {
-                true_case,
-                false_case,
-            })
-        } else if first_byte == OpCode::Repeat as u8 {
-            source.read_u8()?;
+        match opcode {
+            OpCode::IfElse => {
+                let if_block_len = source.read_u16()? as usize;
+                let nodes = Deserializable::read_batch_from(source, if_block_len)?;
+                let true_case = CodeBody::new(nodes);
 
-            let times = source.read_u32()?;
+                let else_block_len = source.read_u16()? as usize;
+                let nodes = Deserializable::read_batch_from(source, else_block_len)?;
+                let false_case = CodeBody::new(nodes);
 
-            let nodes_len = source.read_u16()? as usize;
-            let nodes = Deserializable::read_batch_from(source, nodes_len)?;
-            let body = CodeBody::new(nodes);
+                Ok(Node::IfElse {
+                    true_case,
+                    false_case,
+                })
+            }
+            OpCode::Repeat => {
+                let times = source.read_u32()?;
 
-            Ok(Node::Repeat { times, body })
-        } else if first_byte == OpCode::While as u8 {
-            source.read_u8()?;
+                let nodes_len = source.read_u16()? as usize;
+                let nodes = Deserializable::read_batch_from(source, nodes_len)?;
+                let body = CodeBody::new(nodes);
 
-            let nodes_len = source.read_u16()? as usize;
-            let nodes = Deserializable::read_batch_from(source, nodes_len)?;
-            let body = CodeBody::new(nodes);
+                Ok(Node::Repeat { times, body })
+            }
+            OpCode::While => {
+                let nodes_len = source.read_u16()? as usize;
+                let nodes = Deserializable::read_batch_from(source, nodes_len)?;
+                let body = CodeBody::new(nodes);
 
-            Ok(Node::While { body })
-        } else {
-            let inner = Deserializable::read_from(source)?;
-            Ok(Node::Instruction(inner))
+                Ok(Node::While { body })
+            }
+            _ => Ok(Node::Instruction(Instruction::read_with_opcode(opcode, source)?)),
         }
     }
 }
@@ -56,7 +52,16 @@ impl Deserializable for Node {
 impl Deserializable for Instruction {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let opcode = OpCode::read_from(source)?;
+        Self::read_with_opcode(opcode, source)
+    }
+}
 
+impl Instruction {
+    /// Reads the body of an instruction whose opcode has already been read from the source.
+    fn read_with_opcode<R: ByteReader>(
+        opcode: OpCode,
+        source: &mut R,
+    ) -> Result<Self, DeserializationError> {
         match opcode {
             OpCode::Assert => Ok(Instruction::Assert),
             OpCode::AssertWithError => Ok(Instruction::AssertWithError(source.read_u32()?)),
@@ -115,6 +120,8 @@ impl Deserializable for Instruction {
             OpCode::U32AssertWWithError => Ok(Instruction::U32AssertWWithError(source.read_u32()?)),
             OpCode::U32Split => Ok(Instruction::U32Split),
             OpCode::U32Cast => Ok(Instruction::U32Cast),
+            OpCode::U32FromBytes => Ok(Instruction::U32FromBytes),
+            OpCode::U32ToBytes => Ok(Instruction::U32ToBytes),
             OpCode::U32CheckedAdd => Ok(Instruction::U32CheckedAdd),
             OpCode::U32CheckedAddImm => Ok(Instruction::U32CheckedAddImm(source.read_u32()?)),
             OpCode::U32WrappingAdd => Ok(Instruction::U32WrappingAdd),
@@ -123,16 +130,21 @@ impl Deserializable for Instruction {
             OpCode::U32OverflowingAddImm => {
                 Ok(Instruction::U32OverflowingAddImm(source.read_u32()?))
             }
+            OpCode::U32SaturatingAdd => Ok(Instruction::U32SaturatingAdd),
+            OpCode::U32SaturatingAddImm => Ok(Instruction::U32SaturatingAddImm(source.read_u32()?)),
             OpCode::U32OverflowingAdd3 => Ok(Instruction::U32OverflowingAdd3),
             OpCode::U32WrappingAdd3 => Ok(Instruction::U32WrappingAdd3),
             OpCode::U32CheckedSub => Ok(Instruction::U32CheckedSub),
             OpCode::U32CheckedSubImm => Ok(Instruction::U32CheckedSubImm(source.read_u32()?)),
             OpCode::U32WrappingSub => Ok(Instruction::U32WrappingSub),
             OpCode::U32WrappingSubImm => Ok(Instruction::U32WrappingSubImm(source.read_u32()?)),
+            OpCode::U32SaturatingSub => Ok(Instruction::U32SaturatingSub),
+            OpCode::U32SaturatingSubImm => Ok(Instruction::U32SaturatingSubImm(source.read_u32()?)),
             OpCode::U32OverflowingSub => Ok(Instruction::U32OverflowingSub),
             OpCode::U32OverflowingSubImm => {
                 Ok(Instruction::U32OverflowingSubImm(source.read_u32()?))
             }
+            OpCode::U32WrappingNeg => Ok(Instruction::U32WrappingNeg),
             OpCode::U32CheckedMul => Ok(Instruction::U32CheckedMul),
             OpCode::U32CheckedMulImm => Ok(Instruction::U32CheckedMulImm(source.read_u32()?)),
             OpCode::U32WrappingMul => Ok(Instruction::U32WrappingMul),
@@ -141,6 +153,7 @@ impl Deserializable for Instruction {
             OpCode::U32OverflowingMulImm => {
                 Ok(Instruction::U32OverflowingMulImm(source.read_u32()?))
             }
+            OpCode::U32MulHi => Ok(Instruction::U32MulHi),
             OpCode::U32OverflowingMadd => Ok(Instruction::U32OverflowingMadd),
             OpCode::U32WrappingMadd => Ok(Instruction::U32WrappingMadd),
             OpCode::U32CheckedDiv => Ok(Instruction::U32CheckedDiv),
@@ -157,10 +170,22 @@ impl Deserializable for Instruction {
             OpCode::U32UncheckedDivModImm => {
                 Ok(Instruction::U32UncheckedDivModImm(source.read_u32()?))
             }
+            OpCode::U32ExactDiv => Ok(Instruction::U32ExactDiv),
+            OpCode::U32ExactDivImm => Ok(Instruction::U32ExactDivImm(source.read_u32()?)),
+            OpCode::U32DivRound => Ok(Instruction::U32DivRound),
+            OpCode::U32DivRoundImm => Ok(Instruction::U32DivRoundImm(source.read_u32()?)),
+            OpCode::U32Pow => Ok(Instruction::U32Pow),
+            OpCode::U32Sqrt => Ok(Instruction::U32Sqrt),
+            OpCode::U32Log2Ceil => Ok(Instruction::U32Log2Ceil),
+            OpCode::U32NextPow2 => Ok(Instruction::U32NextPow2),
+            OpCode::U32Gcd => Ok(Instruction::U32Gcd),
+            OpCode::U32Midpoint => Ok(Instruction::U32Midpoint),
             OpCode::U32CheckedAnd => Ok(Instruction::U32CheckedAnd),
             OpCode::U32CheckedOr => Ok(Instruction::U32CheckedOr),
             OpCode::U32CheckedXor => Ok(Instruction::U32CheckedXor),
             OpCode::U32CheckedNot => Ok(Instruction::U32CheckedNot),
+            OpCode::U32Nand => Ok(Instruction::U32Nand),
+            OpCode::U32Nor => Ok(Instruction::U32Nor),
             OpCode::U32CheckedShr => Ok(Instruction::U32CheckedShr),
             OpCode::U32CheckedShrImm => Ok(Instruction::U32CheckedShrImm(source.read_u8()?)),
             OpCode::U32UncheckedShr => Ok(Instruction::U32UncheckedShr),
@@ -177,8 +202,45 @@ impl Deserializable for Instruction {
             OpCode::U32CheckedRotlImm => Ok(Instruction::U32CheckedRotlImm(source.read_u8()?)),
             OpCode::U32UncheckedRotl => Ok(Instruction::U32UncheckedRotl),
             OpCode::U32UncheckedRotlImm => Ok(Instruction::U32UncheckedRotlImm(source.read_u8()?)),
+            OpCode::U32Rcl => Ok(Instruction::U32Rcl),
+            OpCode::U32Rcr => Ok(Instruction::U32Rcr),
             OpCode::U32CheckedPopcnt => Ok(Instruction::U32CheckedPopcnt),
             OpCode::U32UncheckedPopcnt => Ok(Instruction::U32UncheckedPopcnt),
+            OpCode::U32Hamming => Ok(Instruction::U32Hamming),
+            OpCode::U32PopEq => Ok(Instruction::U32PopEq),
+            OpCode::U32DistinctBytes => Ok(Instruction::U32DistinctBytes),
+            OpCode::U32BitFill => Ok(Instruction::U32BitFill),
+            OpCode::U32Rev => Ok(Instruction::U32Rev),
+            OpCode::U32Byteswap => Ok(Instruction::U32Byteswap),
+            OpCode::U32MortonEncode => Ok(Instruction::U32MortonEncode),
+            OpCode::U32MortonDecode => Ok(Instruction::U32MortonDecode),
+            OpCode::U32TestBit => Ok(Instruction::U32TestBit),
+            OpCode::U32TestBitImm => Ok(Instruction::U32TestBitImm(source.read_u8()?)),
+            OpCode::U32SetBit => Ok(Instruction::U32SetBit),
+            OpCode::U32SetBitImm => Ok(Instruction::U32SetBitImm(source.read_u8()?)),
+            OpCode::U32ClearBit => Ok(Instruction::U32ClearBit),
+            OpCode::U32ClearBitImm => Ok(Instruction::U32ClearBitImm(source.read_u8()?)),
+            OpCode::U32Bfe => Ok(Instruction::U32Bfe),
+            OpCode::U32BfeImm => {
+                let offset = source.read_u8()?;
+                let width = source.read_u8()?;
+                Ok(Instruction::U32BfeImm(offset, width))
+            }
+            OpCode::U32Bfi => {
+                let offset = source.read_u8()?;
+                let width = source.read_u8()?;
+                Ok(Instruction::U32Bfi(offset, width))
+            }
+            OpCode::U32To16 => Ok(Instruction::U32To16),
+            OpCode::U32To8 => Ok(Instruction::U32To8),
+            OpCode::U32Maj => Ok(Instruction::U32Maj),
+            OpCode::U32Ch => Ok(Instruction::U32Ch),
+            OpCode::U32Sigma => {
+                let a = source.read_u8()?;
+                let b = source.read_u8()?;
+                let c = source.read_u8()?;
+                Ok(Instruction::U32Sigma(a, b, c))
+            }
             OpCode::U32CheckedEq => Ok(Instruction::U32CheckedEq),
             OpCode::U32CheckedEqImm => Ok(Instruction::U32CheckedEqImm(source.read_u32()?)),
             OpCode::U32CheckedNeq => Ok(Instruction::U32CheckedNeq),
@@ -191,10 +253,20 @@ impl Deserializable for Instruction {
             OpCode::U32UncheckedGt => Ok(Instruction::U32UncheckedGt),
             OpCode::U32CheckedGte => Ok(Instruction::U32CheckedGte),
             OpCode::U32UncheckedGte => Ok(Instruction::U32UncheckedGte),
+            OpCode::U32Cmp => Ok(Instruction::U32Cmp),
             OpCode::U32CheckedMin => Ok(Instruction::U32CheckedMin),
             OpCode::U32UncheckedMin => Ok(Instruction::U32UncheckedMin),
             OpCode::U32CheckedMax => Ok(Instruction::U32CheckedMax),
             OpCode::U32UncheckedMax => Ok(Instruction::U32UncheckedMax),
+            OpCode::U32AbsDiff => Ok(Instruction::U32AbsDiff),
+            OpCode::U32Clamp => Ok(Instruction::U32Clamp),
+            OpCode::I32Lt => Ok(Instruction::I32Lt),
+            OpCode::I32Lte => Ok(Instruction::I32Lte),
+            OpCode::I32Gt => Ok(Instruction::I32Gt),
+            OpCode::I32Gte => Ok(Instruction::I32Gte),
+            OpCode::I32Min => Ok(Instruction::I32Min),
+            OpCode::I32Max => Ok(Instruction::I32Max),
+            OpCode::I32Cls => Ok(Instruction::I32Cls),
 
             // ----- stack manipulation -----------------------------------------------------------
             OpCode::Drop => Ok(Instruction::Drop),
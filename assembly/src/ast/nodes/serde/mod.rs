@@ -11,7 +11,7 @@ pub mod signatures;
 // OPERATION CODES ENUM
 // ================================================================================================
 
-#[repr(u8)]
+#[repr(u16)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, TryFromPrimitive)]
 pub enum OpCode {
     Assert = 0,
@@ -143,6 +143,60 @@ pub enum OpCode {
     U32UncheckedMin = 122,
     U32CheckedMax = 123,
     U32UncheckedMax = 124,
+    U32Nand = 256,
+    U32Nor = 257,
+    U32Rev = 258,
+    U32Byteswap = 259,
+    U32AbsDiff = 260,
+    U32Cmp = 261,
+    U32Pow = 262,
+    U32Sqrt = 263,
+    U32Log2Ceil = 264,
+    U32ExactDiv = 265,
+    U32ExactDivImm = 266,
+    U32Clamp = 267,
+    U32MulHi = 268,
+    U32TestBit = 269,
+    U32TestBitImm = 270,
+    U32SetBit = 271,
+    U32SetBitImm = 272,
+    U32ClearBit = 273,
+    U32ClearBitImm = 274,
+    U32WrappingNeg = 275,
+    U32SaturatingAdd = 276,
+    U32SaturatingAddImm = 277,
+    U32SaturatingSub = 278,
+    U32SaturatingSubImm = 279,
+    U32Gcd = 280,
+    U32FromBytes = 281,
+    U32ToBytes = 282,
+    U32Rcl = 283,
+    U32Rcr = 284,
+    U32Midpoint = 285,
+    I32Lt = 286,
+    I32Lte = 287,
+    I32Gt = 288,
+    I32Gte = 289,
+    I32Min = 290,
+    I32Max = 291,
+    U32Hamming = 292,
+    U32DistinctBytes = 293,
+    U32BitFill = 294,
+    U32NextPow2 = 295,
+    U32MortonEncode = 296,
+    U32MortonDecode = 297,
+    U32Bfe = 298,
+    U32BfeImm = 299,
+    U32Bfi = 300,
+    U32DivRound = 301,
+    U32DivRoundImm = 302,
+    I32Cls = 303,
+    U32To16 = 304,
+    U32To8 = 305,
+    U32Maj = 306,
+    U32Ch = 307,
+    U32Sigma = 308,
+    U32PopEq = 309,
 
     // ----- stack manipulation -------------------------------------------------------------------
     Drop = 125,
@@ -294,13 +348,13 @@ pub enum OpCode {
 
 impl Serializable for OpCode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        target.write_u8(*self as u8);
+        target.write_u16(*self as u16);
     }
 }
 
 impl Deserializable for OpCode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let value = source.read_u8()?;
+        let value = source.read_u16()?;
         Self::try_from(value).map_err(|_| {
             DeserializationError::InvalidValue("could not read a valid opcode".to_string())
         })
@@ -154,6 +154,8 @@ impl Serializable for Instruction {
             }
             Self::U32Split => OpCode::U32Split.write_into(target),
             Self::U32Cast => OpCode::U32Cast.write_into(target),
+            Self::U32FromBytes => OpCode::U32FromBytes.write_into(target),
+            Self::U32ToBytes => OpCode::U32ToBytes.write_into(target),
             Self::U32CheckedAdd => OpCode::U32CheckedAdd.write_into(target),
             Self::U32CheckedAddImm(v) => {
                 OpCode::U32CheckedAddImm.write_into(target);
@@ -169,6 +171,11 @@ impl Serializable for Instruction {
                 OpCode::U32OverflowingAddImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32SaturatingAdd => OpCode::U32SaturatingAdd.write_into(target),
+            Self::U32SaturatingAddImm(v) => {
+                OpCode::U32SaturatingAddImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::U32OverflowingAdd3 => OpCode::U32OverflowingAdd3.write_into(target),
             Self::U32WrappingAdd3 => OpCode::U32WrappingAdd3.write_into(target),
             Self::U32CheckedSub => OpCode::U32CheckedSub.write_into(target),
@@ -181,11 +188,17 @@ impl Serializable for Instruction {
                 OpCode::U32WrappingSubImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32SaturatingSub => OpCode::U32SaturatingSub.write_into(target),
+            Self::U32SaturatingSubImm(v) => {
+                OpCode::U32SaturatingSubImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::U32OverflowingSub => OpCode::U32OverflowingSub.write_into(target),
             Self::U32OverflowingSubImm(v) => {
                 OpCode::U32OverflowingSubImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32WrappingNeg => OpCode::U32WrappingNeg.write_into(target),
             Self::U32CheckedMul => OpCode::U32CheckedMul.write_into(target),
             Self::U32CheckedMulImm(v) => {
                 OpCode::U32CheckedMulImm.write_into(target);
@@ -201,6 +214,7 @@ impl Serializable for Instruction {
                 OpCode::U32OverflowingMulImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32MulHi => OpCode::U32MulHi.write_into(target),
             Self::U32OverflowingMadd => OpCode::U32OverflowingMadd.write_into(target),
             Self::U32WrappingMadd => OpCode::U32WrappingMadd.write_into(target),
             Self::U32CheckedDiv => OpCode::U32CheckedDiv.write_into(target),
@@ -233,10 +247,28 @@ impl Serializable for Instruction {
                 OpCode::U32UncheckedDivModImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::U32ExactDiv => OpCode::U32ExactDiv.write_into(target),
+            Self::U32ExactDivImm(v) => {
+                OpCode::U32ExactDivImm.write_into(target);
+                target.write_u32(*v);
+            }
+            Self::U32DivRound => OpCode::U32DivRound.write_into(target),
+            Self::U32DivRoundImm(v) => {
+                OpCode::U32DivRoundImm.write_into(target);
+                target.write_u32(*v);
+            }
+            Self::U32Pow => OpCode::U32Pow.write_into(target),
+            Self::U32Sqrt => OpCode::U32Sqrt.write_into(target),
+            Self::U32Log2Ceil => OpCode::U32Log2Ceil.write_into(target),
+            Self::U32NextPow2 => OpCode::U32NextPow2.write_into(target),
+            Self::U32Gcd => OpCode::U32Gcd.write_into(target),
+            Self::U32Midpoint => OpCode::U32Midpoint.write_into(target),
             Self::U32CheckedAnd => OpCode::U32CheckedAnd.write_into(target),
             Self::U32CheckedOr => OpCode::U32CheckedOr.write_into(target),
             Self::U32CheckedXor => OpCode::U32CheckedXor.write_into(target),
             Self::U32CheckedNot => OpCode::U32CheckedNot.write_into(target),
+            Self::U32Nand => OpCode::U32Nand.write_into(target),
+            Self::U32Nor => OpCode::U32Nor.write_into(target),
             Self::U32CheckedShr => OpCode::U32CheckedShr.write_into(target),
             Self::U32CheckedShrImm(v) => {
                 OpCode::U32CheckedShrImm.write_into(target);
@@ -277,8 +309,54 @@ impl Serializable for Instruction {
                 OpCode::U32UncheckedRotlImm.write_into(target);
                 target.write_u8(*v);
             }
+            Self::U32Rcl => OpCode::U32Rcl.write_into(target),
+            Self::U32Rcr => OpCode::U32Rcr.write_into(target),
             Self::U32CheckedPopcnt => OpCode::U32CheckedPopcnt.write_into(target),
             Self::U32UncheckedPopcnt => OpCode::U32UncheckedPopcnt.write_into(target),
+            Self::U32Hamming => OpCode::U32Hamming.write_into(target),
+            Self::U32PopEq => OpCode::U32PopEq.write_into(target),
+            Self::U32DistinctBytes => OpCode::U32DistinctBytes.write_into(target),
+            Self::U32BitFill => OpCode::U32BitFill.write_into(target),
+            Self::U32Rev => OpCode::U32Rev.write_into(target),
+            Self::U32Byteswap => OpCode::U32Byteswap.write_into(target),
+            Self::U32MortonEncode => OpCode::U32MortonEncode.write_into(target),
+            Self::U32MortonDecode => OpCode::U32MortonDecode.write_into(target),
+            Self::U32TestBit => OpCode::U32TestBit.write_into(target),
+            Self::U32TestBitImm(v) => {
+                OpCode::U32TestBitImm.write_into(target);
+                target.write_u8(*v);
+            }
+            Self::U32SetBit => OpCode::U32SetBit.write_into(target),
+            Self::U32SetBitImm(v) => {
+                OpCode::U32SetBitImm.write_into(target);
+                target.write_u8(*v);
+            }
+            Self::U32ClearBit => OpCode::U32ClearBit.write_into(target),
+            Self::U32ClearBitImm(v) => {
+                OpCode::U32ClearBitImm.write_into(target);
+                target.write_u8(*v);
+            }
+            Self::U32Bfe => OpCode::U32Bfe.write_into(target),
+            Self::U32BfeImm(offset, width) => {
+                OpCode::U32BfeImm.write_into(target);
+                target.write_u8(*offset);
+                target.write_u8(*width);
+            }
+            Self::U32Bfi(offset, width) => {
+                OpCode::U32Bfi.write_into(target);
+                target.write_u8(*offset);
+                target.write_u8(*width);
+            }
+            Self::U32To16 => OpCode::U32To16.write_into(target),
+            Self::U32To8 => OpCode::U32To8.write_into(target),
+            Self::U32Maj => OpCode::U32Maj.write_into(target),
+            Self::U32Ch => OpCode::U32Ch.write_into(target),
+            Self::U32Sigma(a, b, c) => {
+                OpCode::U32Sigma.write_into(target);
+                target.write_u8(*a);
+                target.write_u8(*b);
+                target.write_u8(*c);
+            }
             Self::U32CheckedEq => OpCode::U32CheckedEq.write_into(target),
             Self::U32CheckedEqImm(v) => {
                 OpCode::U32CheckedEqImm.write_into(target);
@@ -297,10 +375,20 @@ impl Serializable for Instruction {
             Self::U32UncheckedGt => OpCode::U32UncheckedGt.write_into(target),
             Self::U32CheckedGte => OpCode::U32CheckedGte.write_into(target),
             Self::U32UncheckedGte => OpCode::U32UncheckedGte.write_into(target),
+            Self::U32Cmp => OpCode::U32Cmp.write_into(target),
             Self::U32CheckedMin => OpCode::U32CheckedMin.write_into(target),
             Self::U32UncheckedMin => OpCode::U32UncheckedMin.write_into(target),
             Self::U32CheckedMax => OpCode::U32CheckedMax.write_into(target),
             Self::U32UncheckedMax => OpCode::U32UncheckedMax.write_into(target),
+            Self::U32AbsDiff => OpCode::U32AbsDiff.write_into(target),
+            Self::U32Clamp => OpCode::U32Clamp.write_into(target),
+            Self::I32Lt => OpCode::I32Lt.write_into(target),
+            Self::I32Lte => OpCode::I32Lte.write_into(target),
+            Self::I32Gt => OpCode::I32Gt.write_into(target),
+            Self::I32Gte => OpCode::I32Gte.write_into(target),
+            Self::I32Min => OpCode::I32Min.write_into(target),
+            Self::I32Max => OpCode::I32Max.write_into(target),
+            Self::I32Cls => OpCode::I32Cls.write_into(target),
 
             // ----- stack manipulation ---------------------------------------------------------------
             Self::Drop => OpCode::Drop.write_into(target),
@@ -5,7 +5,7 @@ use super::{
     Node::{self, Instruction},
     ParsingError, Token, MAX_STACK_WORD_OFFSET,
 };
-use vm_core::SignatureKind;
+use vm_core::{AdviceInjector, SignatureKind};
 
 // INSTRUCTION PARSERS
 // ================================================================================================
@@ -26,6 +26,38 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushU64div),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_u64mul" => match op.num_parts() {
+            2 => AdvInject(PushU64mul),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_inv" => match op.num_parts() {
+            2 => AdvInject(PushFieldInv),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_sortw" => match op.num_parts() {
+            0..=2 => return Err(ParsingError::missing_param(op, "adv.push_sortw.<count>")),
+            3 => {
+                let count = parse_checked_param::<u32, _>(op, 2, 1..=u32::MAX)?;
+                AdvInject(PushSortWords { count })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_bsearchw" => match op.num_parts() {
+            2 => AdvInject(PushBinarySearch),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_rand" => match op.num_parts() {
+            0..=2 => return Err(ParsingError::missing_param(op, "adv.push_rand.<count>")),
+            3 => {
+                let count = parse_checked_param::<u32, _>(op, 2, 1..=u32::MAX)?;
+                AdvInject(PushRandom { count })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_ext2sqrt" => match op.num_parts() {
+            2 => AdvInject(PushExt2sqrt),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_ext2intt" => match op.num_parts() {
             2 => AdvInject(PushExt2intt),
             _ => return Err(ParsingError::extra_param(op)),
@@ -66,14 +98,50 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             }
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "pop_mapval" => match op.num_parts() {
+            2 => AdvInject(PopMapVal),
+            3 => {
+                let offset = parse_checked_param::<u8, _>(op, 2, 0..=MAX_STACK_WORD_OFFSET)?;
+                if offset == 0 {
+                    AdvInject(PopMapVal)
+                } else {
+                    AdvInject(PopMapValImm { offset })
+                }
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "pop_mapvaln" => match op.num_parts() {
+            2 => AdvInject(PopMapValN),
+            3 => {
+                let offset = parse_checked_param::<u8, _>(op, 2, 0..=MAX_STACK_WORD_OFFSET)?;
+                if offset == 0 {
+                    AdvInject(PopMapValN)
+                } else {
+                    AdvInject(PopMapValNImm { offset })
+                }
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_mtnode" => match op.num_parts() {
             2 => AdvInject(PushMtNode),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_mtpath" => match op.num_parts() {
+            2 => AdvInject(PushMtPath),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_rpo_preimage" => match op.num_parts() {
+            2 => AdvInject(PushRpoPreimage),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "insert_mem" => match op.num_parts() {
             2 => AdvInject(InsertMem),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "insert_memn" => match op.num_parts() {
+            2 => AdvInject(InsertMemN),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "insert_hdword" => match op.num_parts() {
             2 => AdvInject(InsertHdword),
             3 => {
@@ -95,10 +163,72 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
                 "rpo_falcon512" => AdvInject(PushSignature {
                     kind: SignatureKind::RpoFalcon512,
                 }),
+                "ecdsa_secp256k1" => AdvInject(PushSignature {
+                    kind: SignatureKind::EcdsaSecp256k1,
+                }),
                 _ => return Err(ParsingError::invalid_param(op, 1)),
             },
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_u64mod" => match op.num_parts() {
+            0..=2 => return Err(ParsingError::missing_param(op, "adv.push_u64mod.<modulus>")),
+            3 => {
+                let modulus = parse_checked_param::<u64, _>(op, 2, 1..=u64::MAX)?;
+                AdvInject(PushU64mod { modulus })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_recip" => match op.num_parts() {
+            0..=2 => return Err(ParsingError::missing_param(op, "adv.push_recip.<frac_bits>")),
+            3 => {
+                let frac_bits = parse_checked_param::<u8, _>(
+                    op,
+                    2,
+                    0..=AdviceInjector::MAX_FIXED_RECIP_FRAC_BITS,
+                )?;
+                AdvInject(PushFixedRecip { frac_bits })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_crc32" => match op.num_parts() {
+            0..=2 => return Err(ParsingError::missing_param(op, "adv.push_crc32.<poly>")),
+            3 => {
+                let poly = parse_checked_param::<u32, _>(op, 2, 0..=u32::MAX)?;
+                AdvInject(PushCrc32 { poly })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_invperm" => match op.num_parts() {
+            0..=2 => return Err(ParsingError::missing_param(op, "adv.push_invperm.<count>")),
+            3 => {
+                let count = parse_checked_param::<u32, _>(op, 2, 1..=u32::MAX)?;
+                AdvInject(PushInversePermutation { count })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_factor" => match op.num_parts() {
+            2 => AdvInject(PushFactor),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_barrettmu" => match op.num_parts() {
+            0..=3 => {
+                return Err(ParsingError::missing_param(op, "adv.push_barrettmu.<modulus>.<k>"))
+            }
+            4 => {
+                let modulus = parse_checked_param::<u64, _>(op, 2, 1..=u64::MAX)?;
+                let k = parse_checked_param::<u8, _>(op, 3, 0..=AdviceInjector::MAX_BARRETT_K)?;
+                AdvInject(PushBarrettMu { modulus, k })
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_order" => match op.num_parts() {
+            2 => AdvInject(PushFieldOrder),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_mapkeys" => match op.num_parts() {
+            2 => AdvInject(PushMapKeys),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         _ => return Err(ParsingError::invalid_op(op)),
     };
 
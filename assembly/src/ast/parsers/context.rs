@@ -483,10 +483,13 @@ impl ParserContext<'_> {
             "u32assertw" => u32_ops::parse_u32assertw(op, &self.local_constants),
             "u32cast" => simple_instruction(op, U32Cast),
             "u32split" => simple_instruction(op, U32Split),
+            "u32frombytes" => simple_instruction(op, U32FromBytes),
+            "u32tobytes" => simple_instruction(op, U32ToBytes),
 
             "u32checked_add" => u32_ops::parse_u32checked_add(op),
             "u32wrapping_add" => u32_ops::parse_u32wrapping_add(op),
             "u32overflowing_add" => u32_ops::parse_u32overflowing_add(op),
+            "u32saturating_add" => u32_ops::parse_u32saturating_add(op),
 
             "u32overflowing_add3" => simple_instruction(op, U32OverflowingAdd3),
             "u32wrapping_add3" => simple_instruction(op, U32WrappingAdd3),
@@ -494,10 +497,13 @@ impl ParserContext<'_> {
             "u32checked_sub" => u32_ops::parse_u32checked_sub(op),
             "u32wrapping_sub" => u32_ops::parse_u32wrapping_sub(op),
             "u32overflowing_sub" => u32_ops::parse_u32overflowing_sub(op),
+            "u32saturating_sub" => u32_ops::parse_u32saturating_sub(op),
+            "u32wrapping_neg" => simple_instruction(op, U32WrappingNeg),
 
             "u32checked_mul" => u32_ops::parse_u32checked_mul(op),
             "u32wrapping_mul" => u32_ops::parse_u32wrapping_mul(op),
             "u32overflowing_mul" => u32_ops::parse_u32overflowing_mul(op),
+            "u32mulhi" => simple_instruction(op, U32MulHi),
 
             "u32overflowing_madd" => simple_instruction(op, U32OverflowingMadd),
             "u32wrapping_madd" => simple_instruction(op, U32WrappingMadd),
@@ -510,11 +516,21 @@ impl ParserContext<'_> {
 
             "u32checked_divmod" => u32_ops::parse_u32_divmod(op, true),
             "u32unchecked_divmod" => u32_ops::parse_u32_divmod(op, false),
+            "u32exactdiv" => u32_ops::parse_u32_exactdiv(op),
+            "u32div_round" => u32_ops::parse_u32_div_round(op),
+            "u32pow" => simple_instruction(op, U32Pow),
+            "u32sqrt" => simple_instruction(op, U32Sqrt),
+            "u32log2_ceil" => simple_instruction(op, U32Log2Ceil),
+            "u32nextpow2" => simple_instruction(op, U32NextPow2),
+            "u32gcd" => simple_instruction(op, U32Gcd),
+            "u32midpoint" => simple_instruction(op, U32Midpoint),
 
             "u32checked_and" => simple_instruction(op, U32CheckedAnd),
             "u32checked_or" => simple_instruction(op, U32CheckedOr),
             "u32checked_xor" => simple_instruction(op, U32CheckedXor),
             "u32checked_not" => simple_instruction(op, U32CheckedNot),
+            "u32nand" => simple_instruction(op, U32Nand),
+            "u32nor" => simple_instruction(op, U32Nor),
 
             "u32checked_shr" => u32_ops::parse_u32_shr(op, true),
             "u32unchecked_shr" => u32_ops::parse_u32_shr(op, false),
@@ -528,8 +544,29 @@ impl ParserContext<'_> {
             "u32checked_rotl" => u32_ops::parse_u32_rotl(op, true),
             "u32unchecked_rotl" => u32_ops::parse_u32_rotl(op, false),
 
+            "u32rcl" => simple_instruction(op, U32Rcl),
+            "u32rcr" => simple_instruction(op, U32Rcr),
+
             "u32checked_popcnt" => simple_instruction(op, U32CheckedPopcnt),
             "u32unchecked_popcnt" => simple_instruction(op, U32UncheckedPopcnt),
+            "u32hamming" => simple_instruction(op, U32Hamming),
+            "u32popeq" => simple_instruction(op, U32PopEq),
+            "u32distinctbytes" => simple_instruction(op, U32DistinctBytes),
+            "u32bitfill" => simple_instruction(op, U32BitFill),
+            "u32rev" => simple_instruction(op, U32Rev),
+            "u32byteswap" => simple_instruction(op, U32Byteswap),
+            "u32morton_encode" => simple_instruction(op, U32MortonEncode),
+            "u32morton_decode" => simple_instruction(op, U32MortonDecode),
+            "u32testbit" => u32_ops::parse_u32_testbit(op),
+            "u32setbit" => u32_ops::parse_u32_setbit(op),
+            "u32clearbit" => u32_ops::parse_u32_clearbit(op),
+            "u32bfe" => u32_ops::parse_u32_bfe(op),
+            "u32bfi" => u32_ops::parse_u32_bfi(op),
+            "u32to16" => simple_instruction(op, U32To16),
+            "u32to8" => simple_instruction(op, U32To8),
+            "u32maj" => simple_instruction(op, U32Maj),
+            "u32ch" => simple_instruction(op, U32Ch),
+            "u32sigma" => u32_ops::parse_u32_sigma(op),
 
             "u32checked_eq" => u32_ops::parse_u32checked_eq(op),
             "u32checked_neq" => u32_ops::parse_u32checked_neq(op),
@@ -545,12 +582,23 @@ impl ParserContext<'_> {
 
             "u32checked_gte" => simple_instruction(op, U32CheckedGte),
             "u32unchecked_gte" => simple_instruction(op, U32UncheckedGte),
+            "u32cmp" => simple_instruction(op, U32Cmp),
 
             "u32checked_min" => simple_instruction(op, U32CheckedMin),
             "u32unchecked_min" => simple_instruction(op, U32UncheckedMin),
 
             "u32checked_max" => simple_instruction(op, U32CheckedMax),
             "u32unchecked_max" => simple_instruction(op, U32UncheckedMax),
+            "u32abs_diff" => simple_instruction(op, U32AbsDiff),
+            "u32clamp" => simple_instruction(op, U32Clamp),
+
+            "i32lt" => simple_instruction(op, I32Lt),
+            "i32lte" => simple_instruction(op, I32Lte),
+            "i32gt" => simple_instruction(op, I32Gt),
+            "i32gte" => simple_instruction(op, I32Gte),
+            "i32min" => simple_instruction(op, I32Min),
+            "i32max" => simple_instruction(op, I32Max),
+            "i32cls" => simple_instruction(op, I32Cls),
 
             // ----- stack manipulation -----------------------------------------------------------
             "drop" => simple_instruction(op, Drop),
@@ -7,6 +7,12 @@ use super::{
 };
 use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bits in a u32 value; bounds the bit fields of bit-field instructions.
+const U32_BITS: u8 = 32;
+
 // INSTRUCTION PARSERS
 // ================================================================================================
 
@@ -136,6 +142,25 @@ pub fn parse_u32overflowing_add(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32SaturatingAdd` instruction node if no immediate value is provided or
+/// `U32SaturatingAddImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32saturating_add(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32saturating_add");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32SaturatingAdd)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32SaturatingAddImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `U32CheckedSub` instruction node if no immediate value is provided or
 /// `U32CheckedSubImm` instruction node otherwise.
 ///
@@ -192,6 +217,25 @@ pub fn parse_u32overflowing_sub(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32SaturatingSub` instruction node if no immediate value is provided or
+/// `U32SaturatingSubImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is not a u32 value.
+pub fn parse_u32saturating_sub(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32saturating_sub");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32SaturatingSub)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            Ok(Instruction(U32SaturatingSubImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `U32CheckedMul` instruction node if no immediate value is provided or
 /// `U32CheckedMulImm` instruction node otherwise.
 ///
@@ -346,6 +390,46 @@ pub fn parse_u32_divmod(op: &Token, checked: bool) -> Result<Node, ParsingError>
     }
 }
 
+/// Returns `U32ExactDiv` instruction node if no immediate value is provided or
+/// `U32ExactDivImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains more than one parameter, or if the
+/// parameter is not a non-zero u32 value.
+pub fn parse_u32_exactdiv(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32exactdiv");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32ExactDiv)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            check_div_by_zero(value.into(), op, 1)?;
+            Ok(Instruction(U32ExactDivImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32DivRound` instruction node if no immediate value is provided or
+/// `U32DivRoundImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains more than one parameter, or if the
+/// parameter is not a non-zero u32 value.
+pub fn parse_u32_div_round(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32div_round");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32DivRound)),
+        2 => {
+            let value = parse_param::<u32>(op, 1)?;
+            check_div_by_zero(value.into(), op, 1)?;
+            Ok(Instruction(U32DivRoundImm(value)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns one of four possible instructions:
 /// - checked without parameter: `U32CheckedShr`
 /// - unchecked without parameter: `U32UncheckedShr`
@@ -377,6 +461,123 @@ pub fn parse_u32_shr(op: &Token, checked: bool) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `U32TestBit` instruction node if no immediate value is provided or `U32TestBitImm`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains more than one parameter, or if the
+/// provided bit index is greater than 31.
+pub fn parse_u32_testbit(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32testbit");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32TestBit)),
+        2 => {
+            let n = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            Ok(Instruction(U32TestBitImm(n)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32SetBit` instruction node if no immediate value is provided or `U32SetBitImm`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains more than one parameter, or if the
+/// provided bit index is greater than 31.
+pub fn parse_u32_setbit(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32setbit");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32SetBit)),
+        2 => {
+            let n = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            Ok(Instruction(U32SetBitImm(n)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32ClearBit` instruction node if no immediate value is provided or `U32ClearBitImm`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains more than one parameter, or if the
+/// provided bit index is greater than 31.
+pub fn parse_u32_clearbit(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32clearbit");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32ClearBit)),
+        2 => {
+            let n = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            Ok(Instruction(U32ClearBitImm(n)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32Bfe` instruction node if no immediate values are provided or `U32BfeImm`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, or if the
+/// provided offset and width describe a bit field which does not fit into 32 bits.
+pub fn parse_u32_bfe(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32bfe");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(U32Bfe)),
+        2 => Err(ParsingError::missing_param(op, "u32bfe.<offset>.<width>")),
+        3 => {
+            let (offset, width) = parse_bit_field(op)?;
+            Ok(Instruction(U32BfeImm(offset, width)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32Bfi` instruction node with the offset and the width of the bit field provided as
+/// immediate values.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, or if the
+/// provided offset and width describe a bit field which does not fit into 32 bits.
+pub fn parse_u32_bfi(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32bfi");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 | 2 => Err(ParsingError::missing_param(op, "u32bfi.<offset>.<width>")),
+        3 => {
+            let (offset, width) = parse_bit_field(op)?;
+            Ok(Instruction(U32Bfi(offset, width)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32Sigma` instruction node with the two rotation amounts and the shift amount
+/// provided as immediate values.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, or if any of
+/// the provided amounts is greater than 31.
+pub fn parse_u32_sigma(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32sigma");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1..=3 => Err(ParsingError::missing_param(op, "u32sigma.<rotr>.<rotr>.<shr>")),
+        4 => {
+            let a = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_ROTATE_VALUE)?;
+            let b = parse_checked_param::<u8, _>(op, 2, 0..=MAX_U32_ROTATE_VALUE)?;
+            let c = parse_checked_param::<u8, _>(op, 3, 0..=MAX_U32_SHIFT_VALUE)?;
+            Ok(Instruction(U32Sigma(a, b, c)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns one of four possible instructions:
 /// - checked without parameter: `U32CheckedShl`
 /// - unchecked without parameter: `U32UncheckedShl`
@@ -507,3 +708,24 @@ pub fn parse_u32checked_neq(op: &Token) -> Result<Node, ParsingError> {
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Parses the offset and the width of a bit field from the first two parameters of the
+/// instruction token.
+///
+/// # Errors
+/// Returns an error if either of the parameters is greater than 32, or if their sum is.
+fn parse_bit_field(op: &Token) -> Result<(u8, u8), ParsingError> {
+    let offset = parse_checked_param::<u8, _>(op, 1, 0..=U32_BITS)?;
+    let width = parse_checked_param::<u8, _>(op, 2, 0..=U32_BITS)?;
+    if offset + width > U32_BITS {
+        return Err(ParsingError::invalid_param_with_reason(
+            op,
+            2,
+            &format!("the sum of the offset and the width must not exceed {U32_BITS}"),
+        ));
+    }
+    Ok((offset, width))
+}
@@ -3,7 +3,7 @@ use super::{
     ParsingError, ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, String,
     ToString, Token,
 };
-use vm_core::utils::SliceReader;
+use vm_core::{utils::SliceReader, SignatureKind};
 
 // UNIT TESTS
 // ================================================================================================
@@ -230,12 +230,35 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source =
+        "begin adv.push_u64div adv.push_inv adv.push_sortw.3 adv.push_rand.2 adv.push_mapval adv.push_smtget \
+        adv.push_mtpath adv.push_rpo_preimage adv.insert_mem adv.insert_memn adv.push_sig.ecdsa_secp256k1 \
+        adv.push_u64mod.4294967311 adv.push_recip.16 adv.push_crc32.79764919 adv.push_invperm.5 \
+        adv.push_factor adv.push_barrettmu.7.6 adv.push_order adv.push_mapkeys end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64div)),
+        Node::Instruction(AdvInject(PushFieldInv)),
+        Node::Instruction(AdvInject(PushSortWords { count: 3 })),
+        Node::Instruction(AdvInject(PushRandom { count: 2 })),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
+        Node::Instruction(AdvInject(PushMtPath)),
+        Node::Instruction(AdvInject(PushRpoPreimage)),
         Node::Instruction(AdvInject(InsertMem)),
+        Node::Instruction(AdvInject(InsertMemN)),
+        Node::Instruction(AdvInject(PushSignature {
+            kind: SignatureKind::EcdsaSecp256k1,
+        })),
+        Node::Instruction(AdvInject(PushU64mod {
+            modulus: 4294967311,
+        })),
+        Node::Instruction(AdvInject(PushFixedRecip { frac_bits: 16 })),
+        Node::Instruction(AdvInject(PushCrc32 { poly: 0x04c11db7 })),
+        Node::Instruction(AdvInject(PushInversePermutation { count: 5 })),
+        Node::Instruction(AdvInject(PushFactor)),
+        Node::Instruction(AdvInject(PushBarrettMu { modulus: 7, k: 6 })),
+        Node::Instruction(AdvInject(PushFieldOrder)),
+        Node::Instruction(AdvInject(PushMapKeys)),
     ];
 
     assert_program_output(source, BTreeMap::new(), nodes);
@@ -824,6 +847,23 @@ fn test_ast_program_serde_control_flow() {
     assert_correct_program_serialization(source, true);
 }
 
+#[test]
+fn test_ast_program_serde_adv_injectors() {
+    let source = "\
+    begin
+        adv.push_u64div
+        adv.push_u64mod.4294967311
+        adv.push_recip.31
+        adv.push_crc32.517762881
+        adv.push_invperm.5
+        adv.push_factor
+        adv.push_barrettmu.4294967311.63
+        adv.push_order
+        adv.push_mapkeys
+    end";
+    assert_correct_program_serialization(source, true);
+}
+
 #[test]
 fn assert_parsing_line_unmatched_begin() {
     let source = format!("\n\nbegin\npush.1.2\n\nadd mul");
@@ -1,6 +1,7 @@
 use super::{
     ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, LibraryNamespace,
-    ProcedureId, ProcedureName, String, ToString, Token, Vec,
+    ProcedureId, ProcedureName, String, ToString, Token, Vec, MAX_U32_ROTATE_VALUE,
+    MAX_U32_SHIFT_VALUE,
 };
 use core::fmt;
 
@@ -24,12 +25,17 @@ pub enum AssemblyError {
     ImportedProcNotFoundInModule(ProcedureId, String),
     InvalidProgramAssemblyContext,
     InvalidCacheLock,
+    InvalidU32BitIndex(String, u8),
+    InvalidU32BitField(String, u8, u8),
+    InvalidU32RotationAmount(String, u8),
+    InvalidU32ShiftAmount(String, u8),
     KernelProcNotFound(ProcedureId),
     LocalProcNotFound(u16, String),
     ParsingError(String),
     ParamOutOfBounds(u64, u64, u64),
     PhantomCallsNotAllowed(RpoDigest),
     ProcedureNameError(String),
+    SpanOpsLimitExceeded(usize, usize, usize),
     SysCallInKernel(String),
     LibraryError(String),
     Io(String),
@@ -98,6 +104,10 @@ impl AssemblyError {
         Self::ParamOutOfBounds(value, min, max)
     }
 
+    pub fn span_ops_limit_exceeded(current: usize, added: usize, max: usize) -> Self {
+        Self::SpanOpsLimitExceeded(current, added, max)
+    }
+
     pub fn phantom_calls_not_allowed(mast_root: RpoDigest) -> Self {
         Self::PhantomCallsNotAllowed(mast_root)
     }
@@ -109,6 +119,22 @@ impl AssemblyError {
     pub fn invalid_cache_lock() -> Self {
         Self::InvalidCacheLock
     }
+
+    pub fn invalid_u32_bit_index(instruction: &str, value: u8) -> Self {
+        Self::InvalidU32BitIndex(instruction.to_string(), value)
+    }
+
+    pub fn invalid_u32_bit_field(instruction: &str, offset: u8, width: u8) -> Self {
+        Self::InvalidU32BitField(instruction.to_string(), offset, width)
+    }
+
+    pub fn invalid_u32_rotation_amount(instruction: &str, value: u8) -> Self {
+        Self::InvalidU32RotationAmount(instruction.to_string(), value)
+    }
+
+    pub fn invalid_u32_shift_amount(instruction: &str, value: u8) -> Self {
+        Self::InvalidU32ShiftAmount(instruction.to_string(), value)
+    }
 }
 
 impl From<ParsingError> for AssemblyError {
@@ -147,12 +173,17 @@ impl fmt::Display for AssemblyError {
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
             InvalidProgramAssemblyContext => write!(f, "assembly context improperly initialized for program compilation"),
             InvalidCacheLock => write!(f, "an attempt was made to lock a borrowed procedures cache"),
+            InvalidU32BitIndex(instruction, value) => write!(f, "invalid bit index {value} for instruction '{instruction}': bit index must be in the range 0..={MAX_U32_SHIFT_VALUE}"),
+            InvalidU32BitField(instruction, offset, width) => write!(f, "invalid bit field with offset {offset} and width {width} for instruction '{instruction}': bit field must fit into 32 bits"),
+            InvalidU32RotationAmount(instruction, value) => write!(f, "invalid rotation amount {value} for instruction '{instruction}': rotation amount must be in the range 0..={MAX_U32_ROTATE_VALUE}"),
+            InvalidU32ShiftAmount(instruction, value) => write!(f, "invalid shift amount {value} for instruction '{instruction}': shift amount must be in the range 0..={MAX_U32_SHIFT_VALUE}"),
             Io(description) => write!(f, "I/O error: {description}"),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
             LibraryError(err) | ParsingError(err) | ProcedureNameError(err) => write!(f, "{err}"),
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
             ParamOutOfBounds(value, min, max) => write!(f, "parameter value must be greater than or equal to {min} and less than or equal to {max}, but was {value}"),
             PhantomCallsNotAllowed(mast_root) => write!(f, "cannot call phantom procedure with MAST root {mast_root}: phantom calls not allowed"),
+            SpanOpsLimitExceeded(current, added, max) => write!(f, "cannot add {added} operations to a span with {current} operations: a span can contain at most {max} operations"),
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
         }
     }
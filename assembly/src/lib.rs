@@ -58,9 +58,17 @@ const MAX_U32_SHIFT_VALUE: u8 = 31;
 /// The maximum number of bits by which a u32 value can be rotated in a bitwise operation.
 const MAX_U32_ROTATE_VALUE: u8 = 31;
 
+/// The error code with which the `u32exactdiv` instruction fails when the dividend is not evenly
+/// divisible by the divisor.
+pub const U32_EXACT_DIV_ERR_CODE: u32 = u32::MAX;
+
 /// The maximum number of bits allowed for the exponent parameter for exponentiation instructions.
 const MAX_EXP_BITS: u8 = 64;
 
+/// The maximum number of operations which can be added to a single SPAN block via
+/// `SpanBuilder::try_push_ops()`.
+const MAX_SPAN_OPS: usize = u16::MAX as usize;
+
 /// The maximum length (in bytes) of a constant, procedure, or library namespace labels.
 const MAX_LABEL_LEN: usize = 255;
 
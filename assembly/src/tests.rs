@@ -35,6 +35,117 @@ fn simple_instructions() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn u32_nand_nor() {
+    let assembler = Assembler::default();
+    let source = "begin u32nand u32nor end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                u32and neg push(4294967295) add \
+                dup1 dup1 u32and movdn2 add neg add push(4294967295) add \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn u32_popcnt() {
+    let assembler = Assembler::default();
+    let source = "begin u32unchecked_popcnt end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                dup0 push(2) u32div drop push(1431655765) u32and u32sub drop \
+                dup0 push(4) u32div drop push(858993459) u32and push(3) mul neg add \
+                dup0 push(16) u32div drop add push(252645135) u32and \
+                push(16843009) u32mul drop push(16777216) u32div drop \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn u32_rev() {
+    let assembler = Assembler::default();
+    let source = "begin u32rev end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                dup0 push(2863311530) u32and push(9223372034707292159) mul swap push(2) mul add \
+                dup0 push(3435973836) u32and push(13835058052060938237) mul swap push(4) mul add \
+                dup0 push(4042322160) u32and push(17293822565076172785) mul swap push(16) mul add \
+                dup0 push(4278255360) u32and push(18374686475393433345) mul swap push(256) mul add \
+                push(65536) mul u32split add \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn u32_byteswap() {
+    let assembler = Assembler::default();
+    let source = "begin u32byteswap end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                dup0 push(4278255360) u32and push(18374686475393433345) mul swap push(256) mul add \
+                push(65536) mul u32split add \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn u32_abs_diff() {
+    let assembler = Assembler::default();
+    let source = "begin u32abs_diff end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                dup1 dup1 u32assert2(0) u32sub swap drop eqz cswap swap neg add \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn u32_cmp() {
+    let assembler = Assembler::default();
+    let source = "begin u32cmp end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                u32assert2(0) u32sub dup0 add swap eqz add neg incr \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn u32_sqrt() {
+    let assembler = Assembler::default();
+    let source = "begin u32sqrt end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span \
+                advpop u32assert2(0) \
+                dup0 dup0 u32mul eqz assert(0) \
+                dup2 dup1 neg add \
+                swap dup2 dup0 add add movup3 neg add \
+                u32assert2(0) drop drop \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
 #[test]
 fn empty_program() {
     let assembler = Assembler::default();
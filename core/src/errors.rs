@@ -1,3 +1,4 @@
+use crate::utils::string::String;
 use core::fmt;
 
 // INPUT ERROR
@@ -7,6 +8,8 @@ use core::fmt;
 pub enum InputError {
     NotFieldElement(u64, &'static str),
     DuplicateAdviceRoot([u8; 32]),
+    InvalidStackInput(u64, usize),
+    TooManyInputs(usize, usize),
 }
 
 impl fmt::Display for InputError {
@@ -19,6 +22,12 @@ impl fmt::Display for InputError {
             DuplicateAdviceRoot(key) => {
                 write!(f, "{key:02x?} is a duplicate of the current merkle set")
             }
+            InvalidStackInput(value, pos) => {
+                write!(f, "stack input {value} at position {pos} is not a valid field element")
+            }
+            TooManyInputs(provided, max) => {
+                write!(f, "{provided} stack inputs were provided, but at most {max} are allowed")
+            }
         }
     }
 }
@@ -54,3 +63,29 @@ impl fmt::Display for OutputError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for OutputError {}
+
+// ADVICE INJECTOR PARSING ERROR
+// ================================================================================================
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AdviceInjectorParseError {
+    UnknownInjector(String),
+    InvalidParam(String),
+    UnknownSignatureKind(String),
+}
+
+impl fmt::Display for AdviceInjectorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use AdviceInjectorParseError::*;
+        match self {
+            UnknownInjector(injector) => write!(f, "unknown advice injector '{injector}'"),
+            InvalidParam(injector) => {
+                write!(f, "missing or invalid parameter in advice injector '{injector}'")
+            }
+            UnknownSignatureKind(kind) => write!(f, "unknown signature kind '{kind}'"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AdviceInjectorParseError {}
@@ -1,6 +1,6 @@
 use super::SignatureKind;
-use crate::Felt;
-use core::fmt;
+use crate::{errors::AdviceInjectorParseError, utils::string::ToString, Felt, StarkField};
+use core::{cmp::Ordering, fmt, str::FromStr};
 
 // ADVICE INJECTORS
 // ================================================================================================
@@ -11,8 +11,15 @@ use core::fmt;
 /// These actions can affect all 3 components of the advice provider: Merkle store, advice stack,
 /// and advice map.
 ///
-/// All actions, except for `MerkleNodeMerge`, `Ext2Inv` and `UpdateMerkleNode` can be invoked
-/// directly from Miden assembly via dedicated instructions.
+/// Most actions can be invoked directly from Miden assembly via dedicated instructions. The
+/// remaining ones (e.g., `MerkleNodeMerge`, `Ext2Inv` and `UpdateMerkleNode`) are used only
+/// internally; see [AdviceInjector::is_user_invokable()].
+///
+/// Injectors are totally ordered: injectors of different variants are ordered by the position of
+/// the variant in this enum, and injectors of the same variant are ordered lexicographically by
+/// their parameters in the order in which the parameters are declared (with `false < true`, field
+/// elements compared by their integer values, and signature kinds ordered by the position of
+/// the variant in [SignatureKind]).
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum AdviceInjector {
     // MERKLE STORE INJECTORS
@@ -48,6 +55,25 @@ pub enum AdviceInjector {
     ///   Merkle store: {TREE_ROOT<-NODE}
     MerkleNodeToStack,
 
+    /// Pushes the authentication path of the Merkle tree node specified by the values on the top
+    /// of the operand stack onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [...]
+    ///   Merkle store: {TREE_ROOT<-NODE}
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [S_0, S_1, ..., S_{depth-1}, ...]
+    ///   Merkle store: {TREE_ROOT<-NODE}
+    ///
+    /// Where S_0 is the sibling of the node, and each subsequent S_i is the sibling of the node's
+    /// ancestor at depth `depth - i`, so that S_{depth-1} is a child of the root. Thus, the path
+    /// is ordered from the node to the root, and each sibling word is laid out in the same way as
+    /// the word pushed by `MerkleNodeToStack`.
+    MerklePathToStack,
+
     /// Updates the node of a Merkle tree specified by the values at the top of the operand stack.
     /// Returns the path from the updated node to the new root of the tree to the caller.
     ///
@@ -65,7 +91,8 @@ pub enum AdviceInjector {
 
     /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
     /// map using the specified word from the operand stack as the key. If `include_len` is set to
-    /// true, the number of elements in the value is also pushed onto the advice stack.
+    /// true, the number of elements in the value is also pushed onto the advice stack. If `consume`
+    /// is set to true, the entry is removed from the advice map once its values have been pushed.
     ///
     /// Inputs:
     ///   Operand stack: [..., KEY, ...]
@@ -75,7 +102,7 @@ pub enum AdviceInjector {
     /// Outputs:
     ///   Operand stack: [..., KEY, ...]
     ///   Advice stack: [values_len?, values, ...]
-    ///   Advice map: {KEY: values}
+    ///   Advice map: {KEY: values} if `consume` is false, {} otherwise
     ///
     /// The `key_offset` value specifies the location of the `KEY` on the stack. For example,
     /// offset value of 0 indicates that the top word on the stack should be used as the key, the
@@ -83,11 +110,33 @@ pub enum AdviceInjector {
     /// etc.
     ///
     /// The valid values of `key_offset` are 0 through 12 (inclusive).
+    ///
+    /// A consumed entry cannot be replayed: subsequent lookups of the same key fail with an error
+    /// unless the entry is re-inserted into the advice map.
     MapValueToStack {
         include_len: bool,
         key_offset: usize,
+        consume: bool,
     },
 
+    /// Pushes the preimage of the RPO digest at the top of the operand stack onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [DIGEST, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {DIGEST: values}
+    ///
+    /// Outputs:
+    ///   Operand stack: [DIGEST, ...]
+    ///   Advice stack: [values, ...]
+    ///   Advice map: {DIGEST: values}
+    ///
+    /// The preimage must have been registered in the advice map under its digest beforehand. It
+    /// is not checked that the values actually hash to the digest; the program is expected to
+    /// re-hash the values and compare the result against the digest.
+    RpoPreimageToStack,
+
     /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
     /// stack.
     ///
@@ -105,6 +154,307 @@ pub enum AdviceInjector {
     /// the remainder respectively.
     DivU64,
 
+    /// Pushes the result of [u64] multiplication (all four 32-bit limbs of the 128-bit product)
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b1, b0, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b1, b0, a1, a0, ...]
+    ///   Advice stack: [c0, c1, c2, c3, ...]
+    ///
+    /// Where (a0, a1) and (b0, b1) are the 32-bit limbs of the multiplicands (with a0 representing
+    /// the 32 least significant bits and a1 representing the 32 most significant bits), and (c0,
+    /// c1, c2, c3) are the 32-bit limbs of the product c = a * b ordered from the least significant
+    /// to the most significant. Same as with `DivU64`, the least significant limb ends up at the
+    /// top of the advice stack. Execution fails if any of the limbs is not a valid u32 value.
+    MulU64,
+
+    /// Pushes the result of reducing a [u64] value modulo the specified modulus (both the
+    /// quotient and the remainder) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [q0, q1, r0, r1, ...]
+    ///
+    /// Where (a0, a1) are the 32-bit limbs of the value a (with a0 representing the 32 least
+    /// significant bits and a1 representing the 32 most significant bits), and (q0, q1) and
+    /// (r0, r1) are the limbs of the quotient q = a / modulus and the remainder r = a % modulus,
+    /// laid out the same way as with `DivU64`.
+    ///
+    /// `modulus` is an immediate non-zero [u64] value (e.g., an external prime); it is displayed
+    /// and parsed as a decimal integer. The results are not verified by the injector: the program
+    /// is expected to assert that a = q * modulus + r and r < modulus using u64 arithmetic.
+    /// Execution fails if either of the limbs of a is not a valid u32 value.
+    ModReduce { modulus: u64 },
+
+    /// Pushes the Barrett reduction constant for the specified modulus onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [mu, ...]
+    ///
+    /// Where mu = floor(2^k / modulus). `k` should be chosen such that all values x to be reduced
+    /// are smaller than 2^k (e.g., k = 2 * ceil(log2(modulus)) for reducing products of two
+    /// residues). In this case, q = floor(x * mu / 2^k) underestimates floor(x / modulus) by at
+    /// most one, and thus x - q * modulus can be reduced with at most one conditional
+    /// subtraction. The program is expected to verify mu by checking that
+    /// mu * modulus <= 2^k < (mu + 1) * modulus.
+    ///
+    /// `modulus` is an immediate non-zero [u64] value and `k` must be in the range [0, 63], which
+    /// guarantees that mu is a valid field element. Both are displayed and parsed as decimal
+    /// integers.
+    BarrettMu { modulus: u64, k: u8 },
+
+    /// Pushes the integer square root of the u32 value at the top of the operand stack onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [r, ...]
+    ///
+    /// Where r is the largest integer such that r * r <= n.
+    U32Sqrt,
+
+    /// Pushes the ceiling of the base 2 logarithm of the element at the top of the operand stack
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [ceil(log2(n)), ...]
+    ///
+    /// The result is the smallest integer r such that n <= 2^r; in particular, it is 0 when n is
+    /// 1. Execution fails if n is 0.
+    ILog2Ceil,
+
+    /// Pushes the greatest common divisor of the two u32 values at the top of the operand stack
+    /// onto the advice stack, together with the values needed to verify it.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b, a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b, a, ...]
+    ///   Advice stack: [g, qa, qb, x, y, ...]
+    ///
+    /// Where g = gcd(a, b), a = g * qa, b = g * qb, and x and y are the magnitudes of the Bézout
+    /// coefficients of qa and qb, such that qa * x - qb * y = ±1. All pushed values are u32
+    /// values.
+    ///
+    /// Execution fails if either a or b is not a u32 value.
+    U32Gcd,
+
+    /// Pushes a factorization of the u32 value at the top of the operand stack onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [p, q, ...]
+    ///
+    /// Where p * q = n. If n has a nontrivial factorization, p is the smallest prime factor of n
+    /// and q = n / p, and thus 1 < p <= q < n. Otherwise (i.e., if n is prime, or if n is 0 or 1),
+    /// p is set to 1 and q is set to n. Thus, p = 1 serves as a flag indicating that n is either
+    /// prime or smaller than 2, and n can be proven composite on the stack by checking that p > 1.
+    ///
+    /// Execution fails if n is not a u32 value.
+    FactorHint,
+
+    /// Pushes the multiplicative inverse of the element at the top of the operand stack onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [a^-1, ...]
+    ///
+    /// Execution fails if a is 0, since 0 has no inverse in the base field.
+    FieldInv,
+
+    /// Pushes the multiplicative order of the element at the top of the operand stack onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [n, ...]
+    ///
+    /// Where n is the smallest positive integer such that a^n = 1 (e.g., the order of 1 is 1).
+    /// Since n divides the order of the multiplicative group of the field (p - 1), the claimed
+    /// order can be verified on the stack by checking that a^n = 1 and that a^(n/q) != 1 for each
+    /// prime factor q of n.
+    ///
+    /// Execution fails if a is 0, since 0 has no multiplicative order.
+    FieldOrder,
+
+    /// Pushes a fixed-point approximation of the reciprocal of the u32 value at the top of the
+    /// operand stack onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [r, ...]
+    ///
+    /// Where r = floor(2^frac_bits / a), i.e., the reciprocal of a with `frac_bits` fractional
+    /// bits, rounded towards zero. Thus, the result is within one unit in the last place of the
+    /// exact reciprocal, which can be verified on the stack by checking that r * a <= 2^frac_bits
+    /// and (r + 1) * a > 2^frac_bits. `frac_bits` must be in the range [0, 31], which guarantees
+    /// that r is a u32 value.
+    ///
+    /// Execution fails if a is 0 or is not a u32 value.
+    FixedRecip { frac_bits: u8 },
+
+    /// Reads `count` words from memory starting at the address located at the top of the operand
+    /// stack, sorts them, and pushes the sorted words onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [W_0, W_1, ..., W_{count-1}, ...]
+    ///
+    /// Where W_0, ..., W_{count-1} are the words located in memory[ptr..ptr + count] in
+    /// non-decreasing order, with W_0 at the top of the advice stack. Words are compared
+    /// lexicographically by the integer values of their elements, starting with the first element
+    /// of the word as laid out in memory. Thus, the words can be read back in sorted order via
+    /// consecutive `adv_loadw` instructions.
+    SortWords { count: u32 },
+
+    /// Reads a permutation of `count` indices from memory starting at the address located at the
+    /// top of the operand stack, and pushes its inverse permutation onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [inv_0, inv_1, ..., inv_{count-1}, ...]
+    ///
+    /// Where the permutation consists of the first elements of the words located in
+    /// memory[ptr..ptr + count], i.e., perm_i is the first element of the word at ptr + i, and
+    /// inv_0, ..., inv_{count-1} is its inverse, i.e., inv[perm_i] = i for all i. inv_0 is at the
+    /// top of the advice stack, and thus the inverse can be read back via `adv_push.1` in order.
+    ///
+    /// Execution fails if perm_0, ..., perm_{count-1} is not a permutation of 0..count.
+    InversePermutation { count: u32 },
+
+    /// Searches a sorted region of memory for the word located at the top of the operand stack, and
+    /// pushes the result of the search onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [T, ptr, len, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [T, ptr, len, ...]
+    ///   Advice stack: [found, i, ...]
+    ///
+    /// Where memory[ptr..ptr + len] is expected to contain words sorted in non-decreasing order (words
+    /// are compared the same way as in `SortWords`). If T is present in the region, found is set to 1
+    /// and i is the offset of the first occurrence of T, i.e., memory[ptr + i] = T. Otherwise, found is
+    /// set to 0 and i is the offset at which T would have to be inserted to keep the region sorted
+    /// (this is 0 if T is smaller than all words in the region, and len if T is larger than all of them).
+    ///
+    /// The result is unspecified if the region is not sorted; callers are expected to verify it by
+    /// checking that memory[ptr + i - 1] < T <= memory[ptr + i] for the words which are in the region.
+    BinarySearch,
+
+    /// Pushes the keys currently present in the advice map onto the advice stack, sorted in
+    /// non-decreasing order, followed by the number of keys.
+    ///
+    /// Inputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [...]
+    ///   Advice map: {K_0: values_0, ..., K_{n-1}: values_{n-1}}
+    ///
+    /// Outputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [n, K_0, K_1, ..., K_{n-1}, ...]
+    ///   Advice map: {K_0: values_0, ..., K_{n-1}: values_{n-1}}
+    ///
+    /// Where n is the number of keys in the advice map, and K_0, ..., K_{n-1} are the keys sorted
+    /// the same way as in `SortWords` (i.e., lexicographically by the integer values of their
+    /// elements, starting with the first element of the key). Thus, 4 * n + 1 elements are pushed
+    /// onto the advice stack, and the keys can be read in sorted order via consecutive `adv_loadw`
+    /// instructions after n is read via `adv_push.1`.
+    MapKeysSorted,
+
+    /// Draws `count` pseudo-random field elements from a random coin seeded with the word located
+    /// at the top of the operand stack, and pushes them onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [SEED, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [SEED, ...]
+    ///   Advice stack: [r_0, r_1, ..., r_{count - 1}, ...]
+    ///
+    /// Where r_i is the i-th element drawn from an `RpoRandomCoin` instantiated with SEED, i.e.,
+    /// the same coin the prover uses to build its Fiat-Shamir transcript. Thus, r_0 is the first
+    /// element drawn, and it is located at the top of the advice stack.
+    ///
+    /// The drawn values are fully determined by SEED: the same seed always produces the same
+    /// sequence of elements, and a larger `count` extends the sequence produced for a smaller one.
+    DrawRandom { count: u32 },
+
+    /// Computes the CRC32 checksum of a byte string located in memory using the specified
+    /// polynomial, and pushes it onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [crc, ...]
+    ///
+    /// Where the byte string consists of the first `len` bytes of memory[ptr..], and `len` is
+    /// expected to be a u32 value. Every element of a word is expected to be a u32 value packing 4 bytes in little-endian order, and the
+    /// elements are read starting with the first element of the word at ptr (i.e., the i-th byte
+    /// is byte i % 4 of element (i / 4) % 4 of the word at ptr + i / 16).
+    ///
+    /// `poly` is given in the normal (MSB-first) representation, e.g., 0x04C11DB7 for the
+    /// standard CRC-32 and 0x1EDC6F41 for CRC-32C. As with these standard variants, the bits of
+    /// each byte are processed starting with the least significant one, the register is
+    /// initialized to 0xFFFFFFFF, and the final value is XOR-ed with 0xFFFFFFFF. Thus, the
+    /// checksum of the ASCII string "123456789" is 0xCBF43926 for the standard polynomial.
+    Crc32 { poly: u32 },
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -120,6 +470,27 @@ pub enum AdviceInjector {
     /// top of the stack.
     Ext2Inv,
 
+    /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, a1),
+    /// computes its square root and pushes the result onto the advice stack together with a flag
+    /// indicating whether the square root exists.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [flag, r0, r1, ...]
+    ///
+    /// If (a0, a1) is a quadratic residue, flag is set to 1 and (r0, r1) is an extension field element
+    /// such that (r0, r1)^2 = (a0, a1). Either of the two square roots may be returned; it is up to the
+    /// caller to verify the result and to choose between r and -r if needed.
+    ///
+    /// If (a0, a1) is a quadratic non-residue, flag is set to 0 and both r0 and r1 are set to 0. The
+    /// advice stack layout is the same in both cases, so that the caller can always read 3 elements
+    /// from the advice stack.
+    Ext2Sqrt,
+
     /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
     ///  into a polynomial in coefficient form and pushes the result into the advice stack.
     ///
@@ -221,7 +592,8 @@ pub enum AdviceInjector {
     // ADVICE MAP INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
-    /// the key `KEY` located at the top of the stack.
+    /// the key `KEY` located at the top of the stack. If `include_len` is set to true, the number
+    /// of elements read from memory is prepended to the stored values.
     ///
     /// Inputs:
     ///   Operand stack: [KEY, start_addr, end_addr, ...]
@@ -229,10 +601,15 @@ pub enum AdviceInjector {
     ///
     /// Outputs:
     ///   Operand stack: [KEY, start_addr, end_addr, ...]
-    ///   Advice map: {KEY: values}
+    ///   Advice map: {KEY: [values_len?, values]}
     ///
-    /// Where `values` are the elements located in memory[start_addr..end_addr].
-    MemToMap,
+    /// Where `values` are the elements located in memory[start_addr..end_addr], and `values_len`
+    /// is equal to 4 * (end_addr - start_addr).
+    ///
+    /// The length becomes part of the stored value. Thus, reading the entry back via
+    /// `MapValueToStack` with `include_len` set to false yields the same advice stack as reading
+    /// an entry stored without the length with `include_len` set to true.
+    MemToMap { include_len: bool },
 
     /// Reads two word from the operand stack and inserts them into the advice map under the key
     /// defined by the hash of these words.
@@ -280,34 +657,522 @@ pub enum AdviceInjector {
     SigToStack { kind: SignatureKind },
 }
 
+impl AdviceInjector {
+    /// The maximum number of fractional bits of the approximation pushed by
+    /// [AdviceInjector::FixedRecip].
+    pub const MAX_FIXED_RECIP_FRAC_BITS: u8 = 31;
+
+    /// The maximum exponent of the power of two divided by the modulus in
+    /// [AdviceInjector::BarrettMu].
+    pub const MAX_BARRETT_K: u8 = 63;
+
+    /// Returns true if this injector can be invoked directly from Miden assembly via a dedicated
+    /// `adv.*` instruction.
+    ///
+    /// Injectors for which this returns false are used only internally, e.g., by the instructions
+    /// which get their results via the advice provider (such as `ext2inv`), or are available only
+    /// to hosts and tooling constructing decorators directly.
+    pub const fn is_user_invokable(&self) -> bool {
+        match self {
+            Self::MerkleNodeMerge
+            | Self::UpdateMerkleNode
+            | Self::U32Sqrt
+            | Self::ILog2Ceil
+            | Self::U32Gcd
+            | Self::Ext2Inv => false,
+            Self::MerkleNodeToStack
+            | Self::MerklePathToStack
+            | Self::MapValueToStack { .. }
+            | Self::RpoPreimageToStack
+            | Self::DivU64
+            | Self::MulU64
+            | Self::ModReduce { .. }
+            | Self::BarrettMu { .. }
+            | Self::FactorHint
+            | Self::FieldInv
+            | Self::FieldOrder
+            | Self::FixedRecip { .. }
+            | Self::SortWords { .. }
+            | Self::InversePermutation { .. }
+            | Self::BinarySearch
+            | Self::MapKeysSorted
+            | Self::DrawRandom { .. }
+            | Self::Crc32 { .. }
+            | Self::Ext2Sqrt
+            | Self::Ext2Intt
+            | Self::SmtGet
+            | Self::SmtSet
+            | Self::SmtPeek
+            | Self::MemToMap { .. }
+            | Self::HdwordToMap { .. }
+            | Self::HpermToMap
+            | Self::SigToStack { .. } => true,
+        }
+    }
+
+    /// Returns a key which uniquely identifies this injector and determines its position in the
+    /// total order of injectors; the key consists of the index of the variant followed by the
+    /// values of its parameters (padded with zeros).
+    fn ord_key(&self) -> (u8, [u64; 3]) {
+        match *self {
+            Self::MerkleNodeMerge => (0, [0; 3]),
+            Self::MerkleNodeToStack => (1, [0; 3]),
+            Self::MerklePathToStack => (2, [0; 3]),
+            Self::UpdateMerkleNode => (3, [0; 3]),
+            Self::MapValueToStack {
+                include_len,
+                key_offset,
+                consume,
+            } => (4, [include_len as u64, key_offset as u64, consume as u64]),
+            Self::RpoPreimageToStack => (5, [0; 3]),
+            Self::DivU64 => (6, [0; 3]),
+            Self::MulU64 => (7, [0; 3]),
+            Self::ModReduce { modulus } => (8, [modulus, 0, 0]),
+            Self::BarrettMu { modulus, k } => (9, [modulus, k as u64, 0]),
+            Self::U32Sqrt => (10, [0; 3]),
+            Self::ILog2Ceil => (11, [0; 3]),
+            Self::U32Gcd => (12, [0; 3]),
+            Self::FactorHint => (13, [0; 3]),
+            Self::FieldInv => (14, [0; 3]),
+            Self::FieldOrder => (15, [0; 3]),
+            Self::FixedRecip { frac_bits } => (16, [frac_bits as u64, 0, 0]),
+            Self::SortWords { count } => (17, [count as u64, 0, 0]),
+            Self::InversePermutation { count } => (18, [count as u64, 0, 0]),
+            Self::BinarySearch => (19, [0; 3]),
+            Self::MapKeysSorted => (20, [0; 3]),
+            Self::DrawRandom { count } => (21, [count as u64, 0, 0]),
+            Self::Crc32 { poly } => (22, [poly as u64, 0, 0]),
+            Self::Ext2Inv => (23, [0; 3]),
+            Self::Ext2Sqrt => (24, [0; 3]),
+            Self::Ext2Intt => (25, [0; 3]),
+            Self::SmtGet => (26, [0; 3]),
+            Self::SmtSet => (27, [0; 3]),
+            Self::SmtPeek => (28, [0; 3]),
+            Self::MemToMap { include_len } => (29, [include_len as u64, 0, 0]),
+            Self::HdwordToMap { domain } => (30, [domain.as_int(), 0, 0]),
+            Self::HpermToMap => (31, [0; 3]),
+            Self::SigToStack { kind } => (32, [kind as u64, 0, 0]),
+        }
+    }
+}
+
+impl Ord for AdviceInjector {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.ord_key().cmp(&other.ord_key())
+    }
+}
+
+impl PartialOrd for AdviceInjector {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl fmt::Display for AdviceInjector {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::MerkleNodeMerge => write!(f, "merkle_node_merge"),
             Self::MerkleNodeToStack => write!(f, "merkle_node_to_stack"),
+            Self::MerklePathToStack => write!(f, "merkle_path_to_stack"),
             Self::UpdateMerkleNode => {
                 write!(f, "update_merkle_node")
             }
             Self::MapValueToStack {
                 include_len,
                 key_offset,
-            } => {
-                if *include_len {
-                    write!(f, "map_value_to_stack_with_len.{key_offset}")
-                } else {
-                    write!(f, "map_value_to_stack.{key_offset}")
-                }
-            }
+                consume,
+            } => match (include_len, consume) {
+                (false, false) => write!(f, "map_value_to_stack.{key_offset}"),
+                (true, false) => write!(f, "map_value_to_stack_with_len.{key_offset}"),
+                (false, true) => write!(f, "map_value_to_stack_consume.{key_offset}"),
+                (true, true) => write!(f, "map_value_to_stack_with_len_consume.{key_offset}"),
+            },
+            Self::RpoPreimageToStack => write!(f, "rpo_preimage_to_stack"),
             Self::DivU64 => write!(f, "div_u64"),
+            Self::MulU64 => write!(f, "mul_u64"),
+            Self::ModReduce { modulus } => write!(f, "mod_reduce.{modulus}"),
+            Self::BarrettMu { modulus, k } => write!(f, "barrett_mu.{modulus}.{k}"),
+            Self::U32Sqrt => write!(f, "u32_sqrt"),
+            Self::ILog2Ceil => write!(f, "ilog2_ceil"),
+            Self::U32Gcd => write!(f, "u32gcd"),
+            Self::FactorHint => write!(f, "factor_hint"),
+            Self::FieldInv => write!(f, "field_inv"),
+            Self::FieldOrder => write!(f, "field_order"),
+            Self::FixedRecip { frac_bits } => write!(f, "fixed_recip.{frac_bits}"),
+            Self::SortWords { count } => write!(f, "sort_words.{count}"),
+            Self::InversePermutation { count } => write!(f, "inverse_permutation.{count}"),
+            Self::BinarySearch => write!(f, "binary_search"),
+            Self::MapKeysSorted => write!(f, "map_keys_sorted"),
+            Self::DrawRandom { count } => write!(f, "draw_random.{count}"),
+            Self::Crc32 { poly } => write!(f, "crc32.{poly}"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
+            Self::Ext2Sqrt => write!(f, "ext2_sqrt"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
             Self::SmtGet => write!(f, "smt_get"),
             Self::SmtSet => write!(f, "smt_set"),
             Self::SmtPeek => write!(f, "smt_peek"),
-            Self::MemToMap => write!(f, "mem_to_map"),
+            Self::MemToMap { include_len } => {
+                if *include_len {
+                    write!(f, "mem_to_map_with_len")
+                } else {
+                    write!(f, "mem_to_map")
+                }
+            }
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
             Self::SigToStack { kind } => write!(f, "sig_to_stack.{kind}"),
         }
     }
 }
+
+impl FromStr for AdviceInjector {
+    type Err = AdviceInjectorParseError;
+
+    /// Parses an advice injector from the string produced by its `Display` implementation.
+    ///
+    /// # Errors
+    /// Returns an error if the name of the injector is not recognized, or if the parameter of the
+    /// injector is missing, unexpected, or invalid.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, param) = match s.split_once('.') {
+            Some((name, param)) => (name, Some(param)),
+            None => (s, None),
+        };
+
+        let invalid_param = || AdviceInjectorParseError::InvalidParam(s.to_string());
+        let no_param = |injector: Self| match param {
+            None => Ok(injector),
+            Some(_) => Err(invalid_param()),
+        };
+        let parse_param =
+            || param.and_then(|param| param.parse::<u64>().ok()).ok_or_else(invalid_param);
+        let map_value_to_stack = |include_len: bool, consume: bool| {
+            let key_offset = usize::try_from(parse_param()?).map_err(|_| invalid_param())?;
+            Ok(Self::MapValueToStack {
+                include_len,
+                key_offset,
+                consume,
+            })
+        };
+
+        match name {
+            "merkle_node_merge" => no_param(Self::MerkleNodeMerge),
+            "merkle_node_to_stack" => no_param(Self::MerkleNodeToStack),
+            "merkle_path_to_stack" => no_param(Self::MerklePathToStack),
+            "update_merkle_node" => no_param(Self::UpdateMerkleNode),
+            "map_value_to_stack" => map_value_to_stack(false, false),
+            "map_value_to_stack_with_len" => map_value_to_stack(true, false),
+            "map_value_to_stack_consume" => map_value_to_stack(false, true),
+            "map_value_to_stack_with_len_consume" => map_value_to_stack(true, true),
+            "rpo_preimage_to_stack" => no_param(Self::RpoPreimageToStack),
+            "div_u64" => no_param(Self::DivU64),
+            "mul_u64" => no_param(Self::MulU64),
+            "mod_reduce" => {
+                let modulus = parse_param()?;
+                if modulus == 0 {
+                    return Err(invalid_param());
+                }
+                Ok(Self::ModReduce { modulus })
+            }
+            "barrett_mu" => {
+                let (modulus, k) = param
+                    .and_then(|param| param.split_once('.'))
+                    .and_then(|(modulus, k)| Some((modulus.parse().ok()?, k.parse().ok()?)))
+                    .filter(|&(modulus, k): &(u64, u8)| modulus != 0 && k <= Self::MAX_BARRETT_K)
+                    .ok_or_else(invalid_param)?;
+                Ok(Self::BarrettMu { modulus, k })
+            }
+            "u32_sqrt" => no_param(Self::U32Sqrt),
+            "ilog2_ceil" => no_param(Self::ILog2Ceil),
+            "u32gcd" => no_param(Self::U32Gcd),
+            "factor_hint" => no_param(Self::FactorHint),
+            "field_inv" => no_param(Self::FieldInv),
+            "field_order" => no_param(Self::FieldOrder),
+            "fixed_recip" => {
+                let frac_bits = u8::try_from(parse_param()?)
+                    .ok()
+                    .filter(|&frac_bits| frac_bits <= Self::MAX_FIXED_RECIP_FRAC_BITS)
+                    .ok_or_else(invalid_param)?;
+                Ok(Self::FixedRecip { frac_bits })
+            }
+            "sort_words" => {
+                let count = u32::try_from(parse_param()?).map_err(|_| invalid_param())?;
+                Ok(Self::SortWords { count })
+            }
+            "inverse_permutation" => {
+                let count = u32::try_from(parse_param()?).map_err(|_| invalid_param())?;
+                Ok(Self::InversePermutation { count })
+            }
+            "binary_search" => no_param(Self::BinarySearch),
+            "map_keys_sorted" => no_param(Self::MapKeysSorted),
+            "draw_random" => {
+                let count = u32::try_from(parse_param()?).map_err(|_| invalid_param())?;
+                Ok(Self::DrawRandom { count })
+            }
+            "crc32" => {
+                let poly = u32::try_from(parse_param()?).map_err(|_| invalid_param())?;
+                Ok(Self::Crc32 { poly })
+            }
+            "ext2_inv" => no_param(Self::Ext2Inv),
+            "ext2_sqrt" => no_param(Self::Ext2Sqrt),
+            "ext2_intt" => no_param(Self::Ext2Intt),
+            "smt_get" => no_param(Self::SmtGet),
+            "smt_set" => no_param(Self::SmtSet),
+            "smt_peek" => no_param(Self::SmtPeek),
+            "mem_to_map" => no_param(Self::MemToMap { include_len: false }),
+            "mem_to_map_with_len" => no_param(Self::MemToMap { include_len: true }),
+            "hdword_to_map" => {
+                let domain = parse_param()?;
+                if domain >= Felt::MODULUS {
+                    return Err(invalid_param());
+                }
+                Ok(Self::HdwordToMap {
+                    domain: Felt::new(domain),
+                })
+            }
+            "hperm_to_map" => no_param(Self::HpermToMap),
+            "sig_to_stack" => {
+                let kind = param
+                    .and_then(|param| param.parse::<SignatureKind>().ok())
+                    .ok_or_else(invalid_param)?;
+                Ok(Self::SigToStack { kind })
+            }
+            _ => Err(AdviceInjectorParseError::UnknownInjector(s.to_string())),
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+/// Injectors are serialized as the strings produced by their `Display` implementation, i.e., the
+/// name of the injector followed by its parameters separated by dots (e.g., `"sort_words.4"`,
+/// `"hdword_to_map.5"`, or `"sig_to_stack.rpo_falcon512"`). Field elements are written as their
+/// integer values. Deserialization parses such strings via the `FromStr` implementation, and thus
+/// accepts exactly the strings produced by serialization.
+#[cfg(feature = "serde")]
+impl serde::Serialize for AdviceInjector {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AdviceInjector {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct InjectorVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for InjectorVisitor {
+            type Value = AdviceInjector;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "an advice injector string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(InjectorVisitor)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceInjector, AdviceInjectorParseError, Felt, SignatureKind, ToString};
+
+    #[test]
+    fn display_from_str_round_trip() {
+        for injector in all_injectors() {
+            let parsed = injector.to_string().parse::<AdviceInjector>();
+            assert_eq!(parsed, Ok(injector), "failed to round trip {injector}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        for injector in all_injectors() {
+            let json = serde_json::to_string(&injector).unwrap();
+            assert_eq!(json, format!("\"{injector}\""));
+            let parsed = serde_json::from_str::<AdviceInjector>(&json).unwrap();
+            assert_eq!(parsed, injector, "failed to round trip {injector}");
+        }
+
+        // the serialized form of parameterized injectors includes their parameters
+        let injector = AdviceInjector::HdwordToMap {
+            domain: Felt::new(5),
+        };
+        assert_eq!(serde_json::to_string(&injector).unwrap(), "\"hdword_to_map.5\"");
+        let injector = AdviceInjector::SigToStack {
+            kind: SignatureKind::RpoFalcon512,
+        };
+        assert_eq!(serde_json::to_string(&injector).unwrap(), "\"sig_to_stack.rpo_falcon512\"");
+
+        // invalid strings and non-string values are rejected
+        assert!(serde_json::from_str::<AdviceInjector>("\"sort_words\"").is_err());
+        assert!(serde_json::from_str::<AdviceInjector>("\"push_mtnode\"").is_err());
+        assert!(serde_json::from_str::<AdviceInjector>("5").is_err());
+    }
+
+    #[test]
+    fn from_str_errors() {
+        let unknown = |s: &str| Err(AdviceInjectorParseError::UnknownInjector(s.to_string()));
+        let invalid = |s: &str| Err(AdviceInjectorParseError::InvalidParam(s.to_string()));
+
+        assert_eq!("push_mtnode".parse::<AdviceInjector>(), unknown("push_mtnode"));
+        assert_eq!("div_u64.1".parse::<AdviceInjector>(), invalid("div_u64.1"));
+        assert_eq!("sort_words".parse::<AdviceInjector>(), invalid("sort_words"));
+        assert_eq!("sort_words.x".parse::<AdviceInjector>(), invalid("sort_words.x"));
+        assert_eq!("sig_to_stack.rsa".parse::<AdviceInjector>(), invalid("sig_to_stack.rsa"));
+        assert_eq!("fixed_recip".parse::<AdviceInjector>(), invalid("fixed_recip"));
+        assert_eq!("fixed_recip.32".parse::<AdviceInjector>(), invalid("fixed_recip.32"));
+        assert_eq!("crc32.4294967296".parse::<AdviceInjector>(), invalid("crc32.4294967296"));
+        assert_eq!("mod_reduce.0".parse::<AdviceInjector>(), invalid("mod_reduce.0"));
+        assert_eq!("barrett_mu.7".parse::<AdviceInjector>(), invalid("barrett_mu.7"));
+        assert_eq!("barrett_mu.0.8".parse::<AdviceInjector>(), invalid("barrett_mu.0.8"));
+        assert_eq!("barrett_mu.7.64".parse::<AdviceInjector>(), invalid("barrett_mu.7.64"));
+        assert_eq!("barrett_mu.7.8.9".parse::<AdviceInjector>(), invalid("barrett_mu.7.8.9"));
+
+        let modulus = format!("hdword_to_map.{}", u64::MAX);
+        assert_eq!(modulus.parse::<AdviceInjector>(), invalid(&modulus));
+    }
+
+    #[test]
+    fn signature_kind_from_str() {
+        for kind in [SignatureKind::RpoFalcon512, SignatureKind::EcdsaSecp256k1] {
+            assert_eq!(kind.to_string().parse::<SignatureKind>(), Ok(kind));
+        }
+        assert_eq!(
+            "rsa".parse::<SignatureKind>(),
+            Err(AdviceInjectorParseError::UnknownSignatureKind("rsa".to_string()))
+        );
+    }
+
+    #[test]
+    fn user_invokable() {
+        // the injectors which have no dedicated instruction in Miden assembly
+        let internal = [
+            "merkle_node_merge",
+            "update_merkle_node",
+            "u32_sqrt",
+            "ilog2_ceil",
+            "u32gcd",
+            "ext2_inv",
+        ];
+        for injector in all_injectors() {
+            let display = injector.to_string();
+            let name = display.split('.').next().unwrap();
+            assert_eq!(
+                injector.is_user_invokable(),
+                !internal.contains(&name),
+                "unexpected classification of {injector}"
+            );
+        }
+    }
+
+    #[test]
+    fn total_order() {
+        let expected = all_injectors();
+
+        // sorting any permutation of the injectors yields the same sequence
+        let mut injectors = expected.clone();
+        injectors.reverse();
+        injectors.sort();
+        assert_eq!(injectors, expected);
+
+        let mut injectors = expected.clone();
+        injectors.rotate_left(expected.len() / 2);
+        injectors.sort();
+        assert_eq!(injectors, expected);
+
+        // the order is consistent with equality
+        for (i, a) in expected.iter().enumerate() {
+            for (j, b) in expected.iter().enumerate() {
+                assert_eq!(a.cmp(b), i.cmp(&j), "inconsistent order of {a} and {b}");
+            }
+        }
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a list containing every variant of [AdviceInjector] (with several values of the
+    /// parameters for parameterized variants) in ascending order.
+    fn all_injectors() -> Vec<AdviceInjector> {
+        let mut injectors = vec![
+            AdviceInjector::MerkleNodeMerge,
+            AdviceInjector::MerkleNodeToStack,
+            AdviceInjector::MerklePathToStack,
+            AdviceInjector::UpdateMerkleNode,
+        ];
+        for include_len in [false, true] {
+            for key_offset in [0, 4, 12] {
+                for consume in [false, true] {
+                    injectors.push(AdviceInjector::MapValueToStack {
+                        include_len,
+                        key_offset,
+                        consume,
+                    });
+                }
+            }
+        }
+        injectors.extend([
+            AdviceInjector::RpoPreimageToStack,
+            AdviceInjector::DivU64,
+            AdviceInjector::MulU64,
+            AdviceInjector::ModReduce { modulus: 1 },
+            AdviceInjector::ModReduce { modulus: u64::MAX },
+            AdviceInjector::BarrettMu { modulus: 1, k: 63 },
+            AdviceInjector::BarrettMu { modulus: 7, k: 0 },
+            AdviceInjector::BarrettMu { modulus: 7, k: 6 },
+            AdviceInjector::BarrettMu {
+                modulus: u64::MAX,
+                k: 63,
+            },
+            AdviceInjector::U32Sqrt,
+            AdviceInjector::ILog2Ceil,
+            AdviceInjector::U32Gcd,
+            AdviceInjector::FactorHint,
+            AdviceInjector::FieldInv,
+            AdviceInjector::FieldOrder,
+            AdviceInjector::FixedRecip { frac_bits: 0 },
+            AdviceInjector::FixedRecip { frac_bits: 31 },
+            AdviceInjector::SortWords { count: 0 },
+            AdviceInjector::SortWords { count: u32::MAX },
+            AdviceInjector::InversePermutation { count: 0 },
+            AdviceInjector::InversePermutation { count: 5 },
+            AdviceInjector::BinarySearch,
+            AdviceInjector::MapKeysSorted,
+            AdviceInjector::DrawRandom { count: 7 },
+            AdviceInjector::Crc32 { poly: 0x04c11db7 },
+            AdviceInjector::Crc32 { poly: 0x1edc6f41 },
+            AdviceInjector::Ext2Inv,
+            AdviceInjector::Ext2Sqrt,
+            AdviceInjector::Ext2Intt,
+            AdviceInjector::SmtGet,
+            AdviceInjector::SmtSet,
+            AdviceInjector::SmtPeek,
+            AdviceInjector::MemToMap { include_len: false },
+            AdviceInjector::MemToMap { include_len: true },
+            AdviceInjector::HdwordToMap {
+                domain: Felt::new(0),
+            },
+            AdviceInjector::HdwordToMap {
+                domain: Felt::new(u64::MAX),
+            },
+            AdviceInjector::HpermToMap,
+            AdviceInjector::SigToStack {
+                kind: SignatureKind::RpoFalcon512,
+            },
+            AdviceInjector::SigToStack {
+                kind: SignatureKind::EcdsaSecp256k1,
+            },
+        ]);
+        injectors
+    }
+}
@@ -1,5 +1,8 @@
-use crate::utils::collections::Vec;
-use core::fmt;
+use crate::{
+    errors::AdviceInjectorParseError,
+    utils::{collections::Vec, string::ToString},
+};
+use core::{fmt, str::FromStr};
 
 mod advice;
 pub use advice::AdviceInjector;
@@ -93,12 +96,30 @@ impl<'a> Iterator for DecoratorIterator<'a> {
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SignatureKind {
     RpoFalcon512,
+    EcdsaSecp256k1,
 }
 
 impl fmt::Display for SignatureKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::RpoFalcon512 => write!(f, "rpo_falcon512"),
+            Self::EcdsaSecp256k1 => write!(f, "ecdsa_secp256k1"),
+        }
+    }
+}
+
+impl FromStr for SignatureKind {
+    type Err = AdviceInjectorParseError;
+
+    /// Parses a signature kind from the string produced by its `Display` implementation.
+    ///
+    /// # Errors
+    /// Returns an error if the name of the signature kind is not recognized.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rpo_falcon512" => Ok(Self::RpoFalcon512),
+            "ecdsa_secp256k1" => Ok(Self::EcdsaSecp256k1),
+            _ => Err(AdviceInjectorParseError::UnknownSignatureKind(s.to_string())),
         }
     }
 }
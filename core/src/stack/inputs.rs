@@ -1,4 +1,6 @@
-use super::{vec, ByteWriter, Felt, InputError, Serializable, ToElements, Vec};
+use super::{
+    vec, ByteWriter, Felt, InputError, Serializable, StarkField, ToElements, Vec, STACK_TOP_SIZE,
+};
 use core::slice;
 
 // STACK INPUTS
@@ -14,6 +16,13 @@ pub struct StackInputs {
 }
 
 impl StackInputs {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of values accepted by [StackInputs::try_new()]; this is equal to the
+    /// number of stack registers which can be accessed by the VM directly.
+    pub const MAX_INPUTS: usize = STACK_TOP_SIZE;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -23,6 +32,20 @@ impl StackInputs {
         Self { values }
     }
 
+    /// Attempts to create `[StackInputs]` from a list of values, reversing them into a stack.
+    ///
+    /// At most [StackInputs::MAX_INPUTS] (16) values can be provided, as this is the number of
+    /// stack registers which the VM can access directly.
+    ///
+    /// # Errors
+    /// Returns an error if more than [StackInputs::MAX_INPUTS] values are provided.
+    pub fn try_new(values: Vec<Felt>) -> Result<Self, InputError> {
+        if values.len() > Self::MAX_INPUTS {
+            return Err(InputError::TooManyInputs(values.len(), Self::MAX_INPUTS));
+        }
+        Ok(Self::new(values))
+    }
+
     /// Attempts to create stack inputs from an iterator of numbers, failing if they do not
     /// represent a valid field element.
     pub fn try_from_values<I>(iter: I) -> Result<Self, InputError>
@@ -39,6 +62,38 @@ impl StackInputs {
             .map(Self::new)
     }
 
+    /// Attempts to create stack inputs from an iterator of integers, failing if any of them is
+    /// not smaller than the field modulus.
+    ///
+    /// # Errors
+    /// Returns an error naming the offending value and its position in the iterator if a value is
+    /// greater than or equal to the field modulus.
+    pub fn try_from_ints<I>(iter: I) -> Result<Self, InputError>
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        iter.into_iter()
+            .enumerate()
+            .map(|(pos, v)| {
+                if v >= Felt::MODULUS {
+                    return Err(InputError::InvalidStackInput(v, pos));
+                }
+                Ok(Felt::new(v))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Returns `[StackInputs]` from a list of values ordered as they should appear on the stack,
+    /// i.e., with the value at the top of the stack in the first position.
+    ///
+    /// This is the inverse of [StackInputs::into_stack_order()]. Note that this is the reverse of
+    /// the order expected by [StackInputs::new()], where the values are treated as if they were
+    /// pushed onto the stack one by one.
+    pub fn from_stack_order(values: Vec<Felt>) -> Self {
+        Self { values }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -46,6 +101,15 @@ impl StackInputs {
     pub fn values(&self) -> &[Felt] {
         &self.values
     }
+
+    /// Consumes these stack inputs and returns the values in the order in which they appear on
+    /// the stack, i.e., with the value at the top of the stack in the first position.
+    ///
+    /// This is the reverse of the order in which the values are provided to the verifier, where
+    /// the last value is expected to be at the top of the stack.
+    pub fn into_stack_order(self) -> Vec<Felt> {
+        self.values
+    }
 }
 
 impl<'a> IntoIterator for &'a StackInputs {
@@ -83,3 +147,53 @@ impl ToElements<Felt> for StackInputs {
         self.values.to_vec()
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, InputError, StackInputs, StarkField, Vec};
+
+    #[test]
+    fn try_from_ints() {
+        // the largest valid field element is accepted
+        let inputs = StackInputs::try_from_ints([1, Felt::MODULUS - 1]).unwrap();
+        assert_eq!(inputs.values(), [Felt::new(Felt::MODULUS - 1), Felt::new(1)]);
+
+        // a value equal to the modulus is rejected, and its position is reported
+        let err = StackInputs::try_from_ints([1, 2, Felt::MODULUS]).unwrap_err();
+        assert!(matches!(err, InputError::InvalidStackInput(v, 2) if v == Felt::MODULUS));
+    }
+
+    #[test]
+    fn try_new() {
+        // exactly the maximum number of inputs is accepted
+        let values = (0..StackInputs::MAX_INPUTS as u64).map(Felt::new).collect::<Vec<_>>();
+        let inputs = StackInputs::try_new(values.clone()).unwrap();
+        assert_eq!(inputs.values(), StackInputs::new(values.clone()).values());
+
+        // one more than the maximum is rejected
+        let mut values = values;
+        values.push(Felt::new(16));
+        let err = StackInputs::try_new(values).unwrap_err();
+        assert!(matches!(err, InputError::TooManyInputs(17, 16)));
+    }
+
+    #[test]
+    fn stack_order_conversions() {
+        let values = [1, 2, 3].map(Felt::new).to_vec();
+
+        // values are provided as if pushed onto the stack one by one, so the last value ends up
+        // at the top of the stack
+        let inputs = StackInputs::new(values.clone());
+        let stack = inputs.clone().into_stack_order();
+        assert_eq!(stack, [3, 2, 1].map(Felt::new));
+        assert_eq!(stack, inputs.values());
+
+        // the two conversions are inverses of each other
+        let inputs = StackInputs::from_stack_order(stack.clone());
+        assert_eq!(inputs.values(), StackInputs::new(values.clone()).values());
+        assert_eq!(StackInputs::from_stack_order(values.clone()).into_stack_order(), values);
+    }
+}
@@ -19,3 +19,16 @@ pub use outputs::StackOutputs;
 /// The number of stack registers which can be accessed by the VM directly. This is also the
 /// minimum stack depth enforced by the VM.
 pub const STACK_TOP_SIZE: usize = 16;
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the provided stack elements without the zeros at the bottom of the stack.
+///
+/// The elements are expected to be ordered with the value at the top of the stack first. Since
+/// the stack is implicitly padded with zeros, any run of zeros at the end of the slice is padding;
+/// zeros followed by a non-zero element are significant and are kept.
+pub fn strip_padding(stack: &[u64]) -> &[u64] {
+    let len = stack.iter().rposition(|&v| v != 0).map_or(0, |pos| pos + 1);
+    &stack[..len]
+}
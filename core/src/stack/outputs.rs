@@ -1,6 +1,6 @@
 use super::{
-    ByteWriter, Felt, OutputError, Serializable, StackTopState, StarkField, ToElements, Vec,
-    STACK_TOP_SIZE,
+    strip_padding, ByteWriter, Felt, OutputError, Serializable, StackTopState, StarkField,
+    ToElements, Vec, STACK_TOP_SIZE,
 };
 
 // STACK OUTPUTS
@@ -90,6 +90,25 @@ impl StackOutputs {
         &self.stack
     }
 
+    /// Returns the element at the specified position of the output stack, where position 0 is the
+    /// top of the stack, or None if the position is out of bounds.
+    pub fn get(&self, pos: usize) -> Option<Felt> {
+        self.stack.get(pos).map(|&v| Felt::new(v))
+    }
+
+    /// Returns the number of elements in the output stack.
+    ///
+    /// Since the output stack is padded to `STACK_TOP_SIZE` (16) elements, this is never smaller
+    /// than 16.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Returns true if the output stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
     /// Returns the number of requested stack outputs or returns the full stack if fewer than the
     /// requested number of stack values exist.
     pub fn stack_truncated(&self, num_outputs: usize) -> &[u64] {
@@ -140,6 +159,18 @@ impl StackOutputs {
         overflow
     }
 
+    /// Returns true if the stack outputs of `self` and `other` are equal once padding is ignored.
+    ///
+    /// Padding is any run of zero elements at the bottom of the output stack (i.e., at the end
+    /// of the list returned by [StackOutputs::stack()]). This includes the zeros added by the
+    /// constructor to fill the stack up to `STACK_TOP_SIZE` elements, as well as any explicit
+    /// trailing zeros. Stacks are compared after all such zeros are removed, so a zero in the
+    /// middle of the stack is still significant. Overflow table addresses are not compared,
+    /// because they depend on the depth of the stack, including its padding.
+    pub fn eq_ignoring_padding(&self, other: &StackOutputs) -> bool {
+        strip_padding(&self.stack) == strip_padding(&other.stack)
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -195,3 +226,56 @@ impl ToElements<Felt> for StackOutputs {
             .collect()
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, StackOutputs, STACK_TOP_SIZE};
+
+    #[test]
+    fn get_and_len() {
+        let outputs = StackOutputs::new(vec![5, 4, 3], vec![]).unwrap();
+        assert_eq!(outputs.len(), STACK_TOP_SIZE);
+        assert!(!outputs.is_empty());
+
+        // in-range positions, including the padding
+        assert_eq!(outputs.get(0), Some(Felt::new(5)));
+        assert_eq!(outputs.get(2), Some(Felt::new(3)));
+        assert_eq!(outputs.get(STACK_TOP_SIZE - 1), Some(Felt::new(0)));
+
+        // out-of-range positions
+        assert_eq!(outputs.get(STACK_TOP_SIZE), None);
+        assert_eq!(outputs.get(usize::MAX), None);
+    }
+
+    #[test]
+    fn eq_ignoring_padding() {
+        let outputs = StackOutputs::new(vec![5, 4, 3], vec![]).unwrap();
+
+        // explicit trailing zeros are ignored
+        let padded = StackOutputs::new(vec![5, 4, 3, 0, 0], vec![]).unwrap();
+        assert!(outputs.eq_ignoring_padding(&padded));
+        assert!(padded.eq_ignoring_padding(&outputs));
+
+        // trailing zeros which overflow the stack top are also ignored
+        let mut stack = vec![5, 4, 3];
+        stack.resize(STACK_TOP_SIZE + 2, 0);
+        let overflowed = StackOutputs::new(stack, vec![0, 1, 2]).unwrap();
+        assert_ne!(outputs, overflowed);
+        assert!(outputs.eq_ignoring_padding(&overflowed));
+
+        // zeros which are not at the bottom of the stack are significant
+        let with_gap = StackOutputs::new(vec![5, 0, 4, 3], vec![]).unwrap();
+        assert!(!outputs.eq_ignoring_padding(&with_gap));
+
+        // differing non-zero elements are detected
+        let different = StackOutputs::new(vec![5, 4, 3, 1], vec![]).unwrap();
+        assert!(!outputs.eq_ignoring_padding(&different));
+
+        // an all-zero stack is equal to the default outputs
+        assert!(StackOutputs::default()
+            .eq_ignoring_padding(&StackOutputs::new(vec![], vec![]).unwrap()));
+    }
+}
@@ -17,4 +17,10 @@ pub use prover::{
     math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
     StackOutputs, StarkProof, Word,
 };
-pub use verifier::{verify, VerificationError};
+pub use verifier::{
+    proof_extension_degree, proof_info, verify, verify_continuation, verify_ints,
+    verify_with_public_inputs, ContinuationSegment, ProofInfo, PublicInputs, VerificationError,
+};
+
+#[cfg(feature = "metrics")]
+pub use verifier::{verify_timed, VerifyStats};
@@ -5,6 +5,7 @@ mod cli;
 mod exec_iters;
 mod flow_control;
 mod operations;
+mod verifier;
 
 // TESTS
 // ================================================================================================
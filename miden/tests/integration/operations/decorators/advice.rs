@@ -1,4 +1,15 @@
-use test_utils::{build_test, crypto::MerkleStore, rand::rand_value, Felt};
+use test_utils::{
+    build_test,
+    crypto::{
+        init_merkle_store, MerklePath, MerkleStore, MerkleTree, NodeIndex, Rpo256, RpoDigest,
+    },
+    math::QuadExtension,
+    rand::rand_value,
+    Felt, FieldElement, StarkField, TestError,
+};
+use vm_core::crypto::random::{RandomCoin, RpoRandomCoin};
+
+type QuadFelt = QuadExtension<Felt>;
 
 // ADVICE INJECTION
 // ================================================================================================
@@ -117,6 +128,586 @@ fn advice_push_u64div_conditional_execution() {
     test.expect_stack(&[0, 0, 0, 0, 0, 4, 0, 8]);
 }
 
+#[test]
+fn advice_push_u64mul() {
+    // push a*b onto the advice stack and then move these values onto the operand stack.
+    let source = "begin adv.push_u64mul adv_push.4 end";
+
+    let values = [
+        (0, 0),
+        (0, u64::MAX),
+        (1, u64::MAX),
+        (u32::MAX as u64, u32::MAX as u64),
+        (u64::MAX, u64::MAX),
+        (rand_value::<u64>(), rand_value::<u64>()),
+    ];
+
+    for (a, b) in values {
+        let a_hi = a >> 32;
+        let a_lo = a as u32 as u64;
+        let b_hi = b >> 32;
+        let b_lo = b as u32 as u64;
+
+        // compute expected product limbs
+        let c = a as u128 * b as u128;
+        let c = [0, 1, 2, 3].map(|i| (c >> (32 * i)) as u32 as u64);
+
+        let test = build_test!(source, &[a_lo, a_hi, b_lo, b_hi]);
+        let expected = [c[3], c[2], c[1], c[0], b_hi, b_lo, a_hi, a_lo];
+        test.expect_stack(&expected);
+    }
+}
+
+#[test]
+fn advice_push_u64mul_not_u32() {
+    let source = "begin adv.push_u64mul adv_push.4 end";
+
+    let test = build_test!(source, &[1, 1, 1, u32::MAX as u64 + 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn advice_push_u64mod() {
+    // push a / m and a % m onto the advice stack and then move these values onto the operand
+    // stack.
+    let moduli = [1, 3, 7, 0xfffffffb, 4294967311, (1 << 61) - 1, 0xffffffff00000001, u64::MAX];
+    let values = [0, 1, 2, 1000, u32::MAX as u64, u64::MAX - 1, u64::MAX, rand_value::<u64>()];
+
+    for m in moduli.into_iter().chain([rand_value::<u64>().max(1)]) {
+        let source = format!("begin adv.push_u64mod.{m} adv_push.4 end");
+
+        for a in values {
+            let a_hi = a >> 32;
+            let a_lo = a as u32 as u64;
+
+            let q = a / m;
+            let q_hi = q >> 32;
+            let q_lo = q as u32 as u64;
+
+            let r = a % m;
+            let r_hi = r >> 32;
+            let r_lo = r as u32 as u64;
+
+            let test = build_test!(&source, &[a_lo, a_hi]);
+            let expected = [r_hi, r_lo, q_hi, q_lo, a_hi, a_lo];
+            test.expect_stack(&expected);
+        }
+    }
+}
+
+#[test]
+fn advice_push_u64mod_zero() {
+    let source = "begin adv.push_u64mod.0 adv_push.4 end";
+
+    let test = build_test!(source, &[1, 0]);
+    test.expect_error(TestError::AssemblyError("parameter"));
+}
+
+#[test]
+fn advice_push_u64mod_not_u32() {
+    let source = "begin adv.push_u64mod.7 adv_push.4 end";
+
+    // the high limb is not a u32 value
+    let test = build_test!(source, &[1, u32::MAX as u64 + 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // the low limb is not a u32 value
+    let test = build_test!(source, &[u32::MAX as u64 + 1, 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn advice_push_barrettmu() {
+    // push floor(2^k / m) onto the advice stack and then move it onto the operand stack.
+    let values = [
+        (1, 0),
+        (1, 63),
+        (3, 4),
+        (7, 6),
+        (251, 16),
+        (65521, 32),
+        (0xfffffffb, 48),
+        (4294967311, 63),
+        // moduli greater than 2^k
+        (1000, 5),
+        (0xffffffff00000001, 63),
+        (u64::MAX, 63),
+    ];
+    for (m, k) in values {
+        let source = format!("begin adv.push_barrettmu.{m}.{k} adv_push.1 end");
+
+        let mu = (1u64 << k) / m;
+        let test = build_test!(&source);
+        test.expect_stack(&[mu]);
+    }
+}
+
+#[test]
+fn advice_push_barrettmu_invalid_params() {
+    // the modulus must not be zero
+    let source = "begin adv.push_barrettmu.0.8 adv_push.1 end";
+    let test = build_test!(source);
+    test.expect_error(TestError::AssemblyError("parameter"));
+
+    // the exponent must not be greater than 63
+    let source = "begin adv.push_barrettmu.7.64 adv_push.1 end";
+    let test = build_test!(source);
+    test.expect_error(TestError::AssemblyError("parameter"));
+}
+
+#[test]
+fn advice_push_inv() {
+    // push a^-1 onto the advice stack, move it onto the operand stack, and verify that
+    // a * a^-1 = 1.
+    let source = "begin adv.push_inv adv_push.1 dup.1 dup.1 mul push.1 assert_eq end";
+
+    for a in [1, 2, Felt::MODULUS - 1, rand_value::<u64>() % Felt::MODULUS] {
+        if a == 0 {
+            continue;
+        }
+        let inv = Felt::new(a).inv().as_int();
+        let test = build_test!(source, &[a]);
+        test.expect_stack(&[inv, a]);
+    }
+}
+
+#[test]
+fn advice_push_inv_zero() {
+    let source = "begin adv.push_inv adv_push.1 end";
+
+    let test = build_test!(source, &[0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn advice_push_order() {
+    // push the order n of a onto the advice stack, move it onto the operand stack, and verify that
+    // a^n = 1.
+    let source = "begin adv.push_order adv_push.1 dup.1 dup.1 exp push.1 assert_eq end";
+
+    let p_minus_one = Felt::MODULUS - 1;
+    let values = [
+        // 1 is the only element of order 1, and -1 is the only element of order 2
+        (Felt::ONE, 1),
+        (-Felt::ONE, 2),
+        // primitive roots of unity of power-of-two orders
+        (Felt::get_root_of_unity(5), 1 << 5),
+        (Felt::get_root_of_unity(16), 1 << 16),
+        (Felt::get_root_of_unity(32), 1 << 32),
+        // primitive roots of unity of orders which are not powers of two
+        (Felt::GENERATOR.exp(p_minus_one / 3), 3),
+        (Felt::GENERATOR.exp(p_minus_one / 65537), 65537),
+        (Felt::GENERATOR.exp(p_minus_one / (3 << 10)), 3 << 10),
+        // the generator of the multiplicative group has the largest possible order
+        (Felt::GENERATOR, p_minus_one),
+    ];
+
+    for (a, order) in values {
+        let test = build_test!(source, &[a.as_int()]);
+        test.expect_stack(&[order, a.as_int()]);
+    }
+}
+
+#[test]
+fn advice_push_order_zero() {
+    let source = "begin adv.push_order adv_push.1 end";
+
+    let test = build_test!(source, &[0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn advice_push_recip() {
+    // push floor(2^b / a) onto the advice stack and then move it onto the operand stack.
+    for b in [0, 1, 16, 31] {
+        let source = format!("begin adv.push_recip.{b} adv_push.1 end");
+
+        let values = [1, 2, 3, 7, 1000, 1 << 16, 1 << 31, u32::MAX as u64];
+        for a in values.into_iter().chain([rand_value::<u32>().max(1) as u64]) {
+            let recip = (1 << b) / a;
+            let test = build_test!(&source, &[a]);
+            test.expect_stack(&[recip, a]);
+        }
+    }
+}
+
+#[test]
+fn advice_push_recip_zero() {
+    let source = "begin adv.push_recip.16 adv_push.1 end";
+
+    let test = build_test!(source, &[0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn advice_push_recip_not_u32() {
+    let source = "begin adv.push_recip.8 adv_push.1 end";
+
+    let test = build_test!(source, &[u32::MAX as u64 + 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn advice_push_factor() {
+    // push a factorization of n onto the advice stack and then move it onto the operand stack.
+    let source = "begin adv.push_factor adv_push.2 end";
+
+    let values = [
+        // composite values
+        (4, 2, 2),
+        (15, 3, 5),
+        (65536, 2, 32768),
+        (1 << 31, 2, 1 << 30),
+        (65521 * 65521, 65521, 65521),
+        (u32::MAX as u64, 3, u32::MAX as u64 / 3),
+        // prime values
+        (2, 1, 2),
+        (13, 1, 13),
+        (u32::MAX as u64 - 4, 1, u32::MAX as u64 - 4),
+        // values smaller than 2
+        (0, 1, 0),
+        (1, 1, 1),
+    ];
+    for (n, p, q) in values {
+        let test = build_test!(source, &[n]);
+        test.expect_stack(&[q, p, n]);
+    }
+}
+
+#[test]
+fn advice_push_factor_not_u32() {
+    let source = "begin adv.push_factor adv_push.2 end";
+
+    let test = build_test!(source, &[u32::MAX as u64 + 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn advice_push_sortw() {
+    // words which differ in the first element only
+    advice_push_sortw_test(&[[3, 0, 0, 0], [1, 0, 0, 0], [2, 0, 0, 0]]);
+
+    // words with common prefixes and duplicates
+    advice_push_sortw_test(&[[1, 2, 3, 5], [1, 2, 3, 4], [0, 9, 9, 9], [1, 2, 3, 4]]);
+
+    // words which differ in the last element only
+    advice_push_sortw_test(&[[7, 7, 7, 2], [7, 7, 7, 1]]);
+
+    // a single word
+    advice_push_sortw_test(&[[5, 6, 7, 8]]);
+
+    // random words
+    let words: [[u64; 4]; 4] =
+        core::array::from_fn(|_| core::array::from_fn(|_| rand_value::<u64>() % Felt::MODULUS));
+    advice_push_sortw_test(&words);
+}
+
+#[test]
+fn advice_push_sortw_out_of_bounds() {
+    let source = "begin push.4294967295 adv.push_sortw.2 end";
+
+    let test = build_test!(source);
+    test.expect_error(TestError::ExecutionError("MemoryAddressOutOfBounds"));
+}
+
+#[test]
+fn advice_push_invperm() {
+    // store the permutation [1, 2, 0] in the first elements of the words at addresses 8..11, and
+    // move its inverse [2, 0, 1] from the advice stack onto the operand stack.
+    let source = "
+    begin
+        push.1.0.0.0 mem_storew.8 dropw
+        push.2.0.0.0 mem_storew.9 dropw
+        push.0.0.0.0 mem_storew.10 dropw
+        push.8
+        adv.push_invperm.3 adv_push.3
+    end";
+
+    let test = build_test!(source);
+    test.expect_stack(&[1, 0, 2, 8]);
+}
+
+#[test]
+fn advice_push_invperm_not_permutation() {
+    let source = "
+    begin
+        push.1.0.0.0 mem_storew.8 dropw
+        push.1.0.0.0 mem_storew.9 dropw
+        push.8
+        adv.push_invperm.2
+    end";
+
+    let test = build_test!(source);
+    test.expect_error(TestError::ExecutionError("InvalidPermutation"));
+}
+
+#[test]
+fn advice_push_bsearchw() {
+    let words = [[1, 0, 0, 0], [3, 0, 0, 0], [3, 0, 0, 0], [3, 5, 0, 0], [7, 1, 1, 1]];
+
+    // found
+    advice_push_bsearchw_test(&words, [1, 0, 0, 0], 1, 0);
+    advice_push_bsearchw_test(&words, [3, 0, 0, 0], 1, 1);
+    advice_push_bsearchw_test(&words, [3, 5, 0, 0], 1, 3);
+    advice_push_bsearchw_test(&words, [7, 1, 1, 1], 1, 4);
+
+    // not found in the middle
+    advice_push_bsearchw_test(&words, [2, 9, 9, 9], 0, 1);
+    advice_push_bsearchw_test(&words, [3, 0, 0, 1], 0, 3);
+    advice_push_bsearchw_test(&words, [7, 1, 1, 0], 0, 4);
+
+    // not found at the ends
+    advice_push_bsearchw_test(&words, [0, 5, 5, 5], 0, 0);
+    advice_push_bsearchw_test(&words, [7, 1, 1, 2], 0, 5);
+
+    // empty region
+    advice_push_bsearchw_test(&[], [1, 2, 3, 4], 0, 0);
+}
+
+#[test]
+fn advice_push_bsearchw_out_of_bounds() {
+    // ptr + len does not fit into 64 bits
+    let source = "begin push.9223372036854775808.9223372036854775808.0.0.0.0 adv.push_bsearchw end";
+
+    let test = build_test!(source);
+    test.expect_error(TestError::ExecutionError("MemoryAddressOutOfBounds"));
+}
+
+/// Stores the provided words in memory, searches for the target word via `adv.push_bsearchw`,
+/// and checks the result pushed onto the advice stack.
+fn advice_push_bsearchw_test(words: &[[u64; 4]], target: [u64; 4], found: u64, index: u64) {
+    let ptr = 100u64;
+    let mut source = String::from("begin ");
+    for (i, word) in words.iter().enumerate() {
+        source.push_str(&format!(
+            "push.{}.{}.{}.{} push.{} mem_storew dropw ",
+            word[0],
+            word[1],
+            word[2],
+            word[3],
+            ptr + i as u64
+        ));
+    }
+    source.push_str(&format!(
+        "push.{}.{ptr}.{}.{}.{}.{} adv.push_bsearchw adv_push.2 end",
+        words.len(),
+        target[0],
+        target[1],
+        target[2],
+        target[3]
+    ));
+
+    let test = build_test!(&source);
+    let len = words.len() as u64;
+    test.expect_stack(&[index, found, target[3], target[2], target[1], target[0], ptr, len]);
+}
+
+#[test]
+fn advice_push_rand() {
+    // draw 5 pseudo-random values and move them onto the operand stack
+    let source = "begin adv.push_rand.5 adv_push.5 end";
+
+    let seed = [1_u64, 2, 3, 4];
+    let mut coin = RpoRandomCoin::new(&seed.map(Felt::new));
+    let values: Vec<u64> = (0..5).map(|_| coin.draw::<Felt>().unwrap().as_int()).collect();
+
+    // the first drawn value is at the top of the advice stack, and thus ends up deepest on the
+    // operand stack
+    let mut expected = values.into_iter().rev().collect::<Vec<u64>>();
+    expected.extend_from_slice(&[4, 3, 2, 1]);
+
+    let test = build_test!(source, &seed);
+    test.expect_stack(&expected);
+
+    // drawing fewer values from the same seed yields a prefix of the same sequence
+    let source = "begin adv.push_rand.2 adv_push.2 end";
+    let test = build_test!(source, &seed);
+    test.expect_stack(&[expected[3], expected[4], 4, 3, 2, 1]);
+}
+
+/// Stores the provided words in memory, sorts them via `adv.push_sortw`, and reads the sorted
+/// words back from the advice stack, checking that they are a permutation of the original words
+/// in non-decreasing order.
+fn advice_push_sortw_test(words: &[[u64; 4]]) {
+    let ptr = 100;
+    let mut source = String::from("begin ");
+    for (i, word) in words.iter().enumerate() {
+        source.push_str(&format!(
+            "push.{}.{}.{}.{} push.{} mem_storew dropw ",
+            word[0],
+            word[1],
+            word[2],
+            word[3],
+            ptr + i
+        ));
+    }
+    source.push_str(&format!("push.{ptr} adv.push_sortw.{} drop ", words.len()));
+    source.push_str(&"padw adv_loadw ".repeat(words.len()));
+    source.push_str("end");
+
+    // the smallest word is read first, so the largest word ends up at the top of the stack
+    let mut sorted = words.to_vec();
+    sorted.sort();
+    let mut expected = sorted.concat();
+    expected.reverse();
+
+    let test = build_test!(&source);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn advice_push_crc32() {
+    // store the ASCII string "123456789" in memory with 4 bytes packed into every element in
+    // little-endian order, and push the checksums of its standard CRC-32 and CRC-32C variants
+    // onto the advice stack.
+    let source = "
+    begin
+        push.875770417.943142453.57.0 mem_storew.8 dropw
+        push.9.8
+        adv.push_crc32.79764919 adv_push.1
+        movdn.2
+        adv.push_crc32.517762881 adv_push.1
+    end";
+
+    let test = build_test!(source);
+    test.expect_stack(&[0xe3069283, 8, 9, 0xcbf43926]);
+}
+
+#[test]
+fn advice_push_crc32_not_u32() {
+    let source =
+        "begin push.4294967296.0.0.0 mem_storew.8 dropw push.4.8 adv.push_crc32.79764919 end";
+
+    let test = build_test!(source);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn advice_push_crc32_len_not_u32() {
+    let source = "begin push.4294967296.8 adv.push_crc32.79764919 end";
+
+    let test = build_test!(source);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn advice_push_ext2sqrt() {
+    // push the square root of a onto the advice stack, move it onto the operand stack, and
+    // verify that (r1, r0)^2 = (a1, a0).
+    let source = "begin
+        adv.push_ext2sqrt adv_push.3
+        # => [r1, r0, flag, a1, a0, ...]
+        dup.1 dup.1 ext2mul
+        # => [c1, c0, flag, a1, a0, ...]
+        movup.3 assert_eq movup.2 assert_eq
+    end";
+
+    let roots = [
+        QuadFelt::ZERO,
+        QuadFelt::ONE,
+        QuadFelt::new(Felt::new(5), Felt::ZERO),
+        QuadFelt::new(Felt::ZERO, Felt::new(3)),
+        QuadFelt::new(Felt::new(Felt::MODULUS - 1), Felt::new(7)),
+        // a root of the form d * (2x - 1), whose square lies in the base field but is not a
+        // square there
+        QuadFelt::new(-Felt::new(3), Felt::new(6)),
+        rand_value::<QuadFelt>(),
+    ];
+
+    for root in roots {
+        let [a0, a1] = (root * root).to_base_elements();
+        let test = build_test!(source, &[a0.as_int(), a1.as_int()]);
+        test.expect_stack(&[1]);
+    }
+}
+
+#[test]
+fn advice_push_ext2sqrt_non_residue() {
+    let source = "begin adv.push_ext2sqrt adv_push.3 end";
+
+    // an element of the extension field is a square iff its norm is a square in the base field;
+    // the norm of (a0, a1) is a0^2 + a0 * a1 + 2 * a1^2.
+    let is_square = |x: Felt| x.exp((Felt::MODULUS - 1) / 2) == Felt::ONE;
+    let a0 = (1..)
+        .map(Felt::new)
+        .find(|&a0| !is_square(a0.square() + a0 + Felt::new(2)))
+        .unwrap();
+
+    let test = build_test!(source, &[a0.as_int(), 1]);
+    test.expect_stack(&[0, 0, 0, 1, a0.as_int()]);
+}
+
+#[test]
+fn advice_push_mtpath() {
+    // push the path of the leaf at index 5 of a depth 3 tree and move it onto the operand stack
+    let source = "begin adv.push_mtpath dropw drop drop adv_push.12 end";
+
+    let index = 5_u64;
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let root = tree.root();
+    let stack_inputs = [
+        root[0].as_int(),
+        root[1].as_int(),
+        root[2].as_int(),
+        root[3].as_int(),
+        index,
+        tree.depth() as u64,
+    ];
+
+    // the sibling of the leaf is at the top of the advice stack, and thus ends up deepest on the
+    // operand stack
+    let path = tree.get_path(NodeIndex::new(tree.depth(), index).unwrap()).unwrap();
+    let expected = path
+        .iter()
+        .rev()
+        .flat_map(|sibling| sibling.as_elements().iter().rev().map(|v| v.as_int()))
+        .collect::<Vec<u64>>();
+
+    let test = build_test!(source, &stack_inputs, &[], store);
+    test.expect_stack(&expected);
+
+    // the pushed path reproduces the root of the tree
+    let mut pushed = test.get_last_stack_state()[..12].to_vec();
+    pushed.reverse();
+    let pushed = MerklePath::new(
+        pushed.chunks(4).map(|s| RpoDigest::new([s[0], s[1], s[2], s[3]])).collect(),
+    );
+    assert_eq!(pushed.compute_root(index, leaves[index as usize].into()).unwrap(), root);
+}
+
+#[test]
+fn advice_push_rpo_preimage() {
+    // push the preimage of the digest onto the advice stack, re-hash it, and make sure the result
+    // matches the original digest
+    let source = "begin
+        adv.push_rpo_preimage
+        padw adv_loadw padw adv_loadw
+        hmerge
+        assert_eqw
+    end";
+
+    let preimage = (0..8).map(|_| Felt::new(rand_value())).collect::<Vec<Felt>>();
+    let digest = Rpo256::hash_elements(&preimage);
+    let stack_inputs = digest.as_elements().iter().map(|v| v.as_int()).collect::<Vec<u64>>();
+    let key = [stack_inputs[0], stack_inputs[1], stack_inputs[2], stack_inputs[3]];
+    let adv_map = [(key_to_bytes(key), preimage.clone())];
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[]);
+
+    // a different preimage registered under the digest does not pass the re-hashing check
+    let mut wrong_preimage = preimage;
+    wrong_preimage[0] += Felt::ONE;
+    let adv_map = [(key_to_bytes(key), wrong_preimage)];
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    assert!(test.execute().is_err());
+
+    // the injector fails if no preimage is registered under the digest
+    let test = build_test!(source, &stack_inputs);
+    assert!(test.execute().is_err());
+}
+
 #[test]
 fn advice_insert_mem() {
     let source = "begin
@@ -174,6 +765,40 @@ fn advice_insert_mem() {
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn advice_insert_memn() {
+    let source = "begin
+    # stack: [1, 2, 3, 4, 5, 6, 7, 8]
+
+    # write two words to memory and use the second word as the key for advice map.
+    mem_storew.2 dropw mem_storew.3
+    # State Transition:
+    # stack: [5, 6, 7, 8]
+    # mem[2]: [4, 3, 2, 1]
+    # mem[3]: [8, 7, 6, 5]
+
+    # copy words at addresses [2, 4) from memory to advice map, prepending the number of elements
+    push.4.2 movdn.5 movdn.5
+    adv.insert_memn
+    # State Transition:
+    # advice_map: k: [8, 7, 6, 5], v: [8, 4, 3, 2, 1, 8, 7, 6, 5]
+
+    # copy from advice map to advice stack; the length is read back as part of the value
+    adv.push_mapval dropw
+    # State Transition:
+    # stack: [2, 4]
+    # advice_stack: [8, 4, 3, 2, 1, 8, 7, 6, 5]
+
+    # move the length and the values onto the stack
+    adv_push.9
+    # State Transition:
+    # stack: [5, 6, 7, 8, 1, 2, 3, 4, 8, 2, 4]
+    end";
+    let stack_inputs = [8, 7, 6, 5, 4, 3, 2, 1];
+    let test = build_test!(source, &stack_inputs);
+    test.expect_stack(&[5, 6, 7, 8, 1, 2, 3, 4, 8, 2, 4]);
+}
+
 #[test]
 fn advice_push_mapval() {
     // --- test simple adv.mapval ---------------------------------------------
@@ -273,6 +898,120 @@ fn advice_push_mapval() {
     test.expect_stack(&[15, 14, 13, 12, 11, 5]);
 }
 
+#[test]
+fn advice_pop_mapval() {
+    // --- test simple adv.pop_mapval -----------------------------------------
+    let source: &str = "begin
+    # stack: [4, 3, 2, 1, ...]
+
+    # load the advice stack with values from the advice map and drop the key
+    adv.pop_mapval
+    dropw
+
+    # move the values from the advice stack to the operand stack
+    adv_push.4
+
+    end";
+
+    let stack_inputs = [1, 2, 3, 4];
+    let adv_map = [(
+        key_to_bytes(stack_inputs),
+        vec![Felt::new(8), Felt::new(7), Felt::new(6), Felt::new(5)],
+    )];
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[5, 6, 7, 8]);
+
+    // --- test adv.pop_mapvaln with offset -----------------------------------
+    let source: &str = "begin
+    # stack: [4, 3, 2, 1, ...]
+
+    # shift the key on the stack by 2 slots
+    push.0 push.0
+
+    # load the advice stack with values from the advice map (including the number
+    # of elements) and drop the key
+    adv.pop_mapvaln.2
+    dropw drop drop
+
+    # move the values from the advice stack to the operand stack
+    adv_push.6
+
+    end";
+
+    let stack_inputs = [1, 2, 3, 4];
+    let adv_map = [(
+        key_to_bytes(stack_inputs),
+        vec![Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14), Felt::new(15)],
+    )];
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[15, 14, 13, 12, 11, 5]);
+}
+
+#[test]
+fn advice_pop_mapval_consumes_entry() {
+    // the entry can be looked up any number of times before it is consumed
+    let source: &str = "begin
+        adv.push_mapval adv.pop_mapval dropw
+        adv_push.8
+    end";
+
+    let stack_inputs = [1, 2, 3, 4];
+    let adv_map = [(
+        key_to_bytes(stack_inputs),
+        vec![Felt::new(8), Felt::new(7), Felt::new(6), Felt::new(5)],
+    )];
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map.clone());
+    test.expect_stack(&[5, 6, 7, 8, 5, 6, 7, 8]);
+
+    // once consumed, the entry is no longer present in the advice map
+    for source in [
+        "begin adv.pop_mapval adv.push_mapval dropw end",
+        "begin adv.pop_mapvaln adv.pop_mapvaln dropw end",
+    ] {
+        let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map.clone());
+        test.expect_error(TestError::ExecutionError("AdviceMapKeyNotFound"));
+    }
+
+    // the entry can be consumed again after it has been re-inserted into the advice map
+    let source: &str = "begin
+        # stack: [4, 3, 2, 1, ...]
+
+        # move the values from the advice map onto the operand stack and save them in memory
+        adv.pop_mapval adv_push.4 push.10 mem_storew dropw
+
+        # re-insert the values into the advice map under the same key and consume them again
+        push.11 push.10 movdn.5 movdn.5 adv.insert_mem movup.4 drop movup.4 drop
+        adv.pop_mapval dropw
+        adv_push.4
+    end";
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[5, 6, 7, 8]);
+}
+
+#[test]
+fn advice_push_mapkeys() {
+    // push the sorted keys of the advice map onto the advice stack, and then move the number of
+    // keys and the keys onto the operand stack.
+    let source =
+        "begin adv.push_mapkeys adv_push.1 padw adv_loadw padw adv_loadw padw adv_loadw end";
+
+    // keys are sorted by their first element, and then by the following ones
+    let keys = [[7, 0, 0, 0], [1, 9, 0, 0], [1, 2, 3, 4]];
+    let adv_map = keys.map(|key| (key_to_bytes(key), vec![Felt::new(1)]));
+
+    let test = build_test!(source, &[], [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[0, 0, 0, 7, 0, 0, 9, 1, 4, 3, 2, 1, 3]);
+
+    // an empty advice map results in only the number of keys being pushed
+    let source = "begin adv.push_mapkeys adv_push.1 end";
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[0]);
+}
+
 #[test]
 fn advice_insert_hdword() {
     // --- test hashing without domain ----------------------------------------
@@ -1,5 +1,8 @@
-use super::{test_param_out_of_bounds, test_unchecked_execution};
-use test_utils::{build_op_test, proptest::prelude::*, rand::rand_value, TestError, U32_BOUND};
+use super::{test_inputs_out_of_bounds, test_param_out_of_bounds, test_unchecked_execution};
+use assembly::U32_EXACT_DIV_ERR_CODE;
+use test_utils::{
+    build_op_test, proptest::prelude::*, rand::rand_value, Felt, TestError, U32_BOUND,
+};
 
 // U32 OPERATIONS TESTS - MANUAL - ARITHMETIC OPERATIONS
 // ================================================================================================
@@ -201,6 +204,39 @@ fn u32overflowing_add() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32saturating_add() {
+    let asm_op = "u32saturating_add";
+
+    // --- no overflow ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[1, 2]);
+    test.expect_stack(&[3]);
+
+    let test = build_op_test!(asm_op, &[u32::MAX as u64 - 1, 1]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    // --- overflow saturates to u32::MAX ---------------------------------------------------------
+    let test = build_op_test!(asm_op, &[u32::MAX as u64, 1]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    let test = build_op_test!(asm_op, &[u32::MAX as u64, u32::MAX as u64]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    // --- immediate value ------------------------------------------------------------------------
+    let test = build_op_test!(format!("{asm_op}.1"), &[u32::MAX as u64]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    let test = build_op_test!(format!("{asm_op}.5"), &[10]);
+    test.expect_stack(&[15]);
+
+    // --- random u32 values and the rest of the stack isn't affected -----------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let e = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[e, a as u64, b as u64]);
+    test.expect_stack(&[a.saturating_add(b) as u64, e]);
+}
+
 #[test]
 fn u32overflowing_add3() {
     let asm_op = "u32overflowing_add3";
@@ -420,6 +456,31 @@ fn u32wrapping_sub_b() {
     test.expect_stack(&[c as u64, e]);
 }
 
+#[test]
+fn u32wrapping_neg() {
+    let asm_op = "u32wrapping_neg";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[0]);
+    test.expect_stack(&[0]);
+
+    let test = build_op_test!(asm_op, &[1]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    let test = build_op_test!(asm_op, &[u32::MAX as u64]);
+    test.expect_stack(&[1]);
+
+    // --- random u32 values and the rest of the stack isn't affected -----------------------------
+    let a = rand_value::<u32>();
+    let e = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[e, a as u64]);
+    test.expect_stack(&[a.wrapping_neg() as u64, e]);
+
+    // --- same result as u32checked_not followed by u32wrapping_add.1 ----------------------------
+    let test = build_op_test!("u32checked_not u32wrapping_add.1", &[a as u64]);
+    test.expect_stack(&[a.wrapping_neg() as u64]);
+}
+
 #[test]
 fn u32overflowing_sub() {
     let asm_op = "u32overflowing_sub";
@@ -465,6 +526,39 @@ fn u32overflowing_sub() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32saturating_sub() {
+    let asm_op = "u32saturating_sub";
+
+    // --- no underflow ---------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[3, 2]);
+    test.expect_stack(&[1]);
+
+    let test = build_op_test!(asm_op, &[1, 1]);
+    test.expect_stack(&[0]);
+
+    // --- underflow saturates to 0 ---------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[0, 1]);
+    test.expect_stack(&[0]);
+
+    let test = build_op_test!(asm_op, &[1, u32::MAX as u64]);
+    test.expect_stack(&[0]);
+
+    // --- immediate value ------------------------------------------------------------------------
+    let test = build_op_test!(format!("{asm_op}.1"), &[0]);
+    test.expect_stack(&[0]);
+
+    let test = build_op_test!(format!("{asm_op}.5"), &[10]);
+    test.expect_stack(&[5]);
+
+    // --- random u32 values and the rest of the stack isn't affected -----------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let e = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[e, a as u64, b as u64]);
+    test.expect_stack(&[a.saturating_sub(b) as u64, e]);
+}
+
 #[test]
 fn u32checked_mul() {
     let asm_op = "u32checked_mul";
@@ -513,6 +607,26 @@ fn u32checked_mul_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn u32checked_arithmetic_33bit_inputs() {
+    // a 33-bit input is silently accepted in wrapping mode, while checked mode rejects it before
+    // performing the operation.
+    let a = U32_BOUND | 5;
+    let b = 1_u64;
+
+    for op in ["add", "sub", "mul"] {
+        let checked_op = format!("u32checked_{op}");
+        let test = build_op_test!(checked_op.as_str(), &[a, b]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+        let test = build_op_test!(checked_op.as_str(), &[b, a]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+        let wrapping_op = format!("u32wrapping_{op}");
+        let test = build_op_test!(wrapping_op.as_str(), &[a, b]);
+        assert!(test.execute().is_ok());
+    }
+}
+
 #[test]
 fn u32checked_mul_b() {
     let build_asm_op = |param: u16| format!("u32checked_mul.{param}");
@@ -661,6 +775,38 @@ fn u32overflowing_mul() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32mulhi() {
+    let asm_op = "u32mulhi";
+
+    // --- no overflow ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[1, 2]);
+    test.expect_stack(&[0]);
+
+    // --- high bits set --------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[U32_BOUND / 2, 4]);
+    test.expect_stack(&[2]);
+
+    let max = u32::MAX as u64;
+    let test = build_op_test!(asm_op, &[max, max]);
+    test.expect_stack(&[(max * max) >> 32]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = ((a as u64 * b as u64) >> 32) as u32;
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[c as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let e = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[e, a as u64, b as u64]);
+    test.expect_stack(&[c as u64, e]);
+
+    // should not fail when inputs are out of bounds.
+    test_unchecked_execution(asm_op, 2);
+}
+
 #[test]
 fn u32overflowing_madd() {
     let asm_op = "u32overflowing_madd";
@@ -983,6 +1129,403 @@ fn u32unchecked_divmod_fail() {
     test.expect_error(TestError::ExecutionError("DivideByZero"));
 }
 
+#[test]
+fn u32exactdiv() {
+    let asm_op = "u32exactdiv";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[0, 1]);
+    test.expect_stack(&[0]);
+
+    let test = build_op_test!(asm_op, &[10, 5]);
+    test.expect_stack(&[2]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let b = (rand_value::<u32>() >> 16).max(1);
+    let quot = rand_value::<u32>() >> 16;
+    let a = (quot * b) as u64;
+    let test = build_op_test!(asm_op, &[a, b as u64]);
+    test.expect_stack(&[quot as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let e = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[e, a, b as u64]);
+    test.expect_stack(&[quot as u64, e]);
+}
+
+#[test]
+fn u32exactdiv_fail() {
+    let asm_op = "u32exactdiv";
+
+    // should fail with the exact division error code if there is a remainder
+    let expected_err = format!("BaseElement({}))", Felt::from(U32_EXACT_DIV_ERR_CODE).inner());
+    let test = build_op_test!(asm_op, &[7, 2]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    test.expect_error(TestError::ExecutionError(&expected_err));
+
+    // should fail if a >= 2^32
+    let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail if b >= 2^32
+    let test = build_op_test!(asm_op, &[1, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail if b == 0
+    let test = build_op_test!(asm_op, &[1, 0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn u32exactdiv_b() {
+    let build_asm_op = |param: u32| format!("u32exactdiv.{param}");
+
+    // --- simple cases ---------------------------------------------------------------------------
+    let test = build_op_test!(build_asm_op(1).as_str(), &[0]);
+    test.expect_stack(&[0]);
+
+    let test = build_op_test!(build_asm_op(1).as_str(), &[7]);
+    test.expect_stack(&[7]);
+
+    let test = build_op_test!(build_asm_op(3).as_str(), &[12]);
+    test.expect_stack(&[4]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let e = rand_value::<u64>();
+    let test = build_op_test!(build_asm_op(4).as_str(), &[e, 20]);
+    test.expect_stack(&[5, e]);
+}
+
+#[test]
+fn u32exactdiv_b_fail() {
+    let build_asm_op = |param: u64| format!("u32exactdiv.{param}");
+
+    // should fail with the exact division error code if there is a remainder
+    let expected_err = format!("BaseElement({}))", Felt::from(U32_EXACT_DIV_ERR_CODE).inner());
+    let test = build_op_test!(build_asm_op(3).as_str(), &[10]);
+    test.expect_error(TestError::ExecutionError(&expected_err));
+
+    // should fail during execution if a >= 2^32.
+    let test = build_op_test!(build_asm_op(1).as_str(), &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail during compilation if b >= 2^32.
+    test_param_out_of_bounds("u32exactdiv", U32_BOUND);
+
+    // should fail during compilation if b = 0.
+    let test = build_op_test!(build_asm_op(0).as_str());
+    test.expect_error(TestError::AssemblyError("division by zero"));
+}
+
+#[test]
+fn u32div_round() {
+    let asm_op = "u32div_round";
+
+    // --- exact division -------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0, 1]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[10, 5]).expect_stack(&[2]);
+
+    // --- rounding down --------------------------------------------------------------------------
+    build_op_test!(asm_op, &[7, 5]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[13, 4]).expect_stack(&[3]);
+
+    // --- rounding up, including exact halves ----------------------------------------------------
+    build_op_test!(asm_op, &[8, 5]).expect_stack(&[2]);
+    build_op_test!(asm_op, &[5, 2]).expect_stack(&[3]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 2]).expect_stack(&[1 << 31]);
+    build_op_test!(asm_op, &[u32::MAX as u64, u32::MAX as u64]).expect_stack(&[1]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>().max(1);
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[div_round(a, b)]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let e = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[e, a as u64, b as u64]);
+    test.expect_stack(&[div_round(a, b), e]);
+}
+
+#[test]
+fn u32div_round_fail() {
+    let asm_op = "u32div_round";
+
+    // should fail if a >= 2^32
+    let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail if b >= 2^32
+    let test = build_op_test!(asm_op, &[1, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail if b == 0
+    let test = build_op_test!(asm_op, &[1, 0]);
+    test.expect_error(TestError::ExecutionError("DivideByZero"));
+}
+
+#[test]
+fn u32div_round_b() {
+    let build_asm_op = |param: u32| format!("u32div_round.{param}");
+
+    // --- exact division -------------------------------------------------------------------------
+    build_op_test!(build_asm_op(1).as_str(), &[7]).expect_stack(&[7]);
+    build_op_test!(build_asm_op(3).as_str(), &[12]).expect_stack(&[4]);
+
+    // --- rounding down --------------------------------------------------------------------------
+    build_op_test!(build_asm_op(5).as_str(), &[7]).expect_stack(&[1]);
+
+    // --- rounding up, including exact halves ----------------------------------------------------
+    build_op_test!(build_asm_op(5).as_str(), &[8]).expect_stack(&[2]);
+    build_op_test!(build_asm_op(2).as_str(), &[5]).expect_stack(&[3]);
+    build_op_test!(build_asm_op(2).as_str(), &[u32::MAX as u64]).expect_stack(&[1 << 31]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let e = rand_value::<u64>();
+    let test = build_op_test!(build_asm_op(4).as_str(), &[e, 22]);
+    test.expect_stack(&[6, e]);
+}
+
+#[test]
+fn u32div_round_b_fail() {
+    let build_asm_op = |param: u64| format!("u32div_round.{param}");
+
+    // should fail during execution if a >= 2^32.
+    let test = build_op_test!(build_asm_op(1).as_str(), &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail during compilation if b >= 2^32.
+    test_param_out_of_bounds("u32div_round", U32_BOUND);
+
+    // should fail during compilation if b = 0.
+    let test = build_op_test!(build_asm_op(0).as_str());
+    test.expect_error(TestError::AssemblyError("division by zero"));
+}
+
+#[test]
+fn u32pow() {
+    let asm_op = "u32pow";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    for (base, exp) in [(0_u32, 0_u32), (0, 5), (1, u32::MAX), (2, 10), (2, 31), (2, 32), (3, 7)] {
+        let test = build_op_test!(asm_op, &[base as u64, exp as u64]);
+        test.expect_stack(&[base.wrapping_pow(exp) as u64]);
+    }
+
+    // --- result wraps around ------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[65536, 2]);
+    test.expect_stack(&[0]);
+
+    let test = build_op_test!(asm_op, &[7, 1000]);
+    test.expect_stack(&[7_u32.wrapping_pow(1000) as u64]);
+
+    let base = u32::MAX;
+    let exp = u32::MAX;
+    let test = build_op_test!(asm_op, &[base as u64, exp as u64]);
+    test.expect_stack(&[base.wrapping_pow(exp) as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let base = rand_value::<u32>();
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, base as u64, 0]);
+    test.expect_stack(&[1, c]);
+
+    // --- the base becoming zero should not affect provability -----------------------------------
+    let stack = vec![65536, 3];
+    build_op_test!(asm_op, &stack).prove_and_verify(stack, false);
+}
+
+#[test]
+fn u32pow_fail() {
+    let asm_op = "u32pow";
+
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds(asm_op, 2);
+}
+
+#[test]
+fn u32sqrt() {
+    let asm_op = "u32sqrt";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    for (n, expected) in [(0, 0), (1, 1), (2, 1), (3, 1), (4, 2), (15, 3), (16, 4), (17, 4)] {
+        build_op_test!(asm_op, &[n]).expect_stack(&[expected]);
+    }
+
+    // --- edge cases -----------------------------------------------------------------------------
+    build_op_test!(asm_op, &[65535 * 65535]).expect_stack(&[65535]);
+    build_op_test!(asm_op, &[65535 * 65535 - 1]).expect_stack(&[65534]);
+    build_op_test!(asm_op, &[U32_BOUND - 1]).expect_stack(&[65535]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let n = rand_value::<u32>();
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, n as u64]);
+    test.expect_stack(&[(n as f64).sqrt() as u64, c]);
+}
+
+#[test]
+fn u32sqrt_fail() {
+    let asm_op = "u32sqrt";
+
+    // should fail if the input is out of bounds
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32log2_ceil() {
+    let asm_op = "u32log2_ceil";
+
+    // --- n = 1 ----------------------------------------------------------------------------------
+    build_op_test!(asm_op, &[1]).expect_stack(&[0]);
+
+    // --- powers of two --------------------------------------------------------------------------
+    for r in 1..32 {
+        build_op_test!(asm_op, &[1 << r]).expect_stack(&[r]);
+    }
+
+    // --- values which are not powers of two -----------------------------------------------------
+    for r in 1..32 {
+        build_op_test!(asm_op, &[(1 << r) + 1]).expect_stack(&[r + 1]);
+        build_op_test!(asm_op, &[(1 << (r + 1)) - 1]).expect_stack(&[r + 1]);
+    }
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, 1000]);
+    test.expect_stack(&[10, c]);
+}
+
+#[test]
+fn u32log2_ceil_fail() {
+    let asm_op = "u32log2_ceil";
+
+    // should fail if the input is zero
+    let test = build_op_test!(asm_op, &[0]);
+    test.expect_error(TestError::ExecutionError("LogArgumentZero"));
+
+    // should fail if the input is out of bounds
+    let test = build_op_test!(asm_op, &[U32_BOUND + 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32nextpow2() {
+    let asm_op = "u32nextpow2";
+
+    // --- n = 0 and n = 1 ------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[1]).expect_stack(&[1]);
+
+    // --- powers of two and values around them ---------------------------------------------------
+    for r in 1..32 {
+        let p: u64 = 1 << r;
+        build_op_test!(asm_op, &[p]).expect_stack(&[p]);
+        build_op_test!(asm_op, &[p - 1]).expect_stack(&[(p - 1).next_power_of_two()]);
+        build_op_test!(asm_op, &[p + 1]).expect_stack(&[(p + 1).next_power_of_two()]);
+    }
+
+    // --- values above 2^31 result in 2^32 -------------------------------------------------------
+    build_op_test!(asm_op, &[U32_BOUND - 1]).expect_stack(&[U32_BOUND]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, 1000]);
+    test.expect_stack(&[1024, c]);
+}
+
+#[test]
+fn u32nextpow2_fail() {
+    let asm_op = "u32nextpow2";
+
+    // should fail if the input is out of bounds
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32gcd() {
+    let asm_op = "u32gcd";
+
+    // --- coprime and non-coprime pairs ----------------------------------------------------------
+    for (a, b) in [(1, 1), (2, 3), (35, 64), (12, 18), (18, 12), (1071, 462), (7, 7), (48, 180)] {
+        let expected = num_integer::gcd(a, b);
+        build_op_test!(asm_op, &[a, b]).expect_stack(&[expected]);
+    }
+
+    // --- zero inputs ----------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0, 25]).expect_stack(&[25]);
+    build_op_test!(asm_op, &[25, 0]).expect_stack(&[25]);
+    build_op_test!(asm_op, &[0, u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+
+    // --- edge cases -----------------------------------------------------------------------------
+    build_op_test!(asm_op, &[u32::MAX as u64, u32::MAX as u64 - 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[1 << 31, 1 << 20]).expect_stack(&[1 << 20]);
+
+    // --- random u32 values and the rest of the stack isn't affected -----------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[c, a as u64, b as u64]);
+    test.expect_stack(&[num_integer::gcd(a, b) as u64, c]);
+}
+
+#[test]
+fn u32gcd_fail() {
+    let asm_op = "u32gcd";
+
+    // should fail if either of the inputs is out of bounds
+    let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_op_test!(asm_op, &[1, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32midpoint() {
+    let asm_op = "u32midpoint";
+    let max = u32::MAX as u64;
+
+    // --- simple cases ---------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[1, 2]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[2, 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[10, 20]).expect_stack(&[15]);
+
+    // --- pairs for which naive addition overflows -----------------------------------------------
+    for (a, b) in [(max, max), (max, max - 1), (max - 1, max), (max, 1), (1 << 31, 1 << 31)] {
+        build_op_test!(asm_op, &[a, b]).expect_stack(&[midpoint(a, b)]);
+    }
+    build_op_test!(asm_op, &[max, max]).expect_stack(&[max]);
+    build_op_test!(asm_op, &[max, 0]).expect_stack(&[max / 2]);
+
+    // --- random u32 values and the rest of the stack isn't affected -----------------------------
+    let a = rand_value::<u32>() as u64;
+    let b = rand_value::<u32>() as u64;
+    let c = rand_value::<u64>();
+    let test = build_op_test!(asm_op, &[c, a, b]);
+    test.expect_stack(&[midpoint(a, b), c]);
+}
+
+#[test]
+fn u32midpoint_fail() {
+    let asm_op = "u32midpoint";
+
+    // should fail if either of the inputs is out of bounds
+    let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_op_test!(asm_op, &[1, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - ARITHMETIC OPERATIONS
 // ================================================================================================
 proptest! {
@@ -1068,6 +1611,23 @@ proptest! {
         test.prop_expect_stack(&[d, c as u64])?;
     }
 
+    #[test]
+    fn u32wrapping_neg_proptest(a in any::<u32>()) {
+        let asm_op = "u32wrapping_neg";
+
+        let test = build_op_test!(asm_op, &[a as u64]);
+        test.prop_expect_stack(&[a.wrapping_neg() as u64])?;
+    }
+
+    #[test]
+    fn u32saturating_add_sub_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let test = build_op_test!("u32saturating_add", &[a as u64, b as u64]);
+        test.prop_expect_stack(&[a.saturating_add(b) as u64])?;
+
+        let test = build_op_test!(format!("u32saturating_sub.{b}"), &[a as u64]);
+        test.prop_expect_stack(&[a.saturating_sub(b) as u64])?;
+    }
+
     #[test]
     fn u32checked_mul_proptest(a in any::<u16>(), b in any::<u16>()) {
         let asm_op = "u32checked_mul";
@@ -1103,6 +1663,16 @@ proptest! {
         test.prop_expect_stack(&[d, c as u64])?;
     }
 
+    #[test]
+    fn u32mulhi_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let asm_op = "u32mulhi";
+
+        let c = ((a as u64 * b as u64) >> 32) as u32;
+
+        let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[c as u64])?;
+    }
+
     #[test]
     fn u32overflowing_madd_proptest(a in any::<u32>(), b in any::<u32>(), c in any::<u32>()) {
         let asm_op = "u32overflowing_madd";
@@ -1190,11 +1760,76 @@ proptest! {
         let test = build_op_test!(&asm_op, &[a as u64]);
         test.prop_expect_stack(&[rem, quot])?;
     }
+
+    #[test]
+    fn u32div_round_proptest(a in any::<u32>(), b in 1..u32::MAX) {
+        let expected = div_round(a, b);
+
+        // b provided via the stack.
+        let test = build_op_test!("u32div_round", &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected])?;
+
+        // b provided as a parameter.
+        let asm_op = format!("u32div_round.{b}");
+        let test = build_op_test!(&asm_op, &[a as u64]);
+        test.prop_expect_stack(&[expected])?;
+    }
+
+    #[test]
+    fn u32pow_proptest(base in any::<u32>(), exp in any::<u32>()) {
+        let asm_op = "u32pow";
+        let expected = base.wrapping_pow(exp) as u64;
+
+        let test = build_op_test!(asm_op, &[base as u64, exp as u64]);
+        test.prop_expect_stack(&[expected])?;
+    }
+
+    #[test]
+    fn u32sqrt_proptest(n in any::<u32>()) {
+        let asm_op = "u32sqrt";
+        let expected = (n as f64).sqrt() as u64;
+
+        let test = build_op_test!(asm_op, &[n as u64]);
+        test.prop_expect_stack(&[expected])?;
+    }
+
+    #[test]
+    fn u32log2_ceil_proptest(n in 1_u32..) {
+        let asm_op = "u32log2_ceil";
+        let expected = u32::BITS - (n - 1).leading_zeros();
+
+        let test = build_op_test!(asm_op, &[n as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+
+    #[test]
+    fn u32nextpow2_proptest(n in any::<u32>()) {
+        let test = build_op_test!("u32nextpow2", &[n as u64]);
+        test.prop_expect_stack(&[(n as u64).next_power_of_two()])?;
+    }
+
+    #[test]
+    fn u32gcd_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let test = build_op_test!("u32gcd", &[a as u64, b as u64]);
+        test.prop_expect_stack(&[num_integer::gcd(a, b) as u64])?;
+    }
+
+    #[test]
+    fn u32midpoint_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let test = build_op_test!("u32midpoint", &[a as u64, b as u64]);
+        test.prop_expect_stack(&[midpoint(a as u64, b as u64)])?;
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns floor((a + b) / 2) for two u32 values. Since the values are provided as u64 values,
+/// their sum cannot overflow.
+fn midpoint(a: u64, b: u64) -> u64 {
+    (a + b) / 2
+}
+
 /// This helper function tests division without remainder for two u32 inputs for a number of simple
 /// cases as well as for random values. It checks that the floor of a / b is pushed to the
 /// stack. Finally, it ensures that the rest of the stack was unaffected.
@@ -1296,3 +1931,11 @@ fn test_divmod(asm_op: &str) {
     let test = build_op_test!(asm_op, &[e, a as u64, b as u64]);
     test.expect_stack(&[rem, quot, e]);
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the quotient of `a` and `b` rounded half up.
+fn div_round(a: u32, b: u32) -> u64 {
+    (a as u64 + b as u64 / 2) / b as u64
+}
@@ -1,5 +1,7 @@
 use super::{test_input_out_of_bounds, test_param_out_of_bounds};
-use test_utils::{build_op_test, proptest::prelude::*, rand::rand_value, TestError, U32_BOUND};
+use test_utils::{
+    build_op_test, build_test, proptest::prelude::*, rand::rand_value, TestError, U32_BOUND,
+};
 
 // U32 OPERATIONS TESTS - MANUAL - BITWISE OPERATIONS
 // ================================================================================================
@@ -162,6 +164,70 @@ fn u32checked_not_fail() {
     test_input_out_of_bounds(asm_op);
 }
 
+#[test]
+fn u32nand() {
+    let asm_op = "u32nand";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    build_op_test!(asm_op, &[1, 1]).expect_stack(&[!1_u32 as u64]);
+    build_op_test!(asm_op, &[0, 1]).expect_stack(&[u32::MAX as u64]);
+    build_op_test!(asm_op, &[1, 0]).expect_stack(&[u32::MAX as u64]);
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[u32::MAX as u64]);
+    build_op_test!(asm_op, &[U32_BOUND - 1, U32_BOUND - 1]).expect_stack(&[0]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let d = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[c as u64, d as u64, a as u64, b as u64]);
+    test.expect_stack(&[!(a & b) as u64, d as u64, c as u64]);
+}
+
+#[test]
+fn u32nand_fail() {
+    let asm_op = "u32nand";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_op_test!(asm_op, &[0, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32nor() {
+    let asm_op = "u32nor";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    build_op_test!(asm_op, &[1, 1]).expect_stack(&[!1_u32 as u64]);
+    build_op_test!(asm_op, &[0, 1]).expect_stack(&[!1_u32 as u64]);
+    build_op_test!(asm_op, &[1, 0]).expect_stack(&[!1_u32 as u64]);
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[u32::MAX as u64]);
+    build_op_test!(asm_op, &[U32_BOUND - 1, 0]).expect_stack(&[0]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let d = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[c as u64, d as u64, a as u64, b as u64]);
+    test.expect_stack(&[!(a | b) as u64, d as u64, c as u64]);
+}
+
+#[test]
+fn u32nor_fail() {
+    let asm_op = "u32nor";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_op_test!(asm_op, &[0, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 #[test]
 fn u32checked_shl() {
     // left shift: pops a from the stack and pushes (a * 2^b) mod 2^32 for a provided value b
@@ -678,6 +744,13 @@ fn u32checked_rotr() {
 
     let test = build_op_test!(asm_op, &[a as u64, b as u64]);
     test.expect_stack(&[a.rotate_right(b) as u64]);
+
+    // --- test all shift values ------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    for b in 0..32 {
+        let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+        test.expect_stack(&[a.rotate_right(b) as u64]);
+    }
 }
 
 #[test]
@@ -789,11 +862,91 @@ fn u32unchecked_rotr() {
     let test = build_op_test!(asm_op, &[a as u64, b as u64]);
     test.expect_stack(&[a.rotate_right(b) as u64]);
 
+    // --- test all shift values ------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    for b in 0..32 {
+        let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+        test.expect_stack(&[a.rotate_right(b) as u64]);
+    }
+
     // --- test out of bounds inputs (should not fail) --------------------------------------------
     let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
     assert!(test.execute().is_ok());
 }
 
+#[test]
+fn u32rcl() {
+    let asm_op = "u32rcl";
+
+    // --- test all-zeros and all-ones values with the carry set and clear ------------------------
+    for a in [0, u32::MAX, 1 << 31, 1, 0x12345678] {
+        for carry in [false, true] {
+            let (new_carry, b) = rcl(a, carry);
+            let test = build_op_test!(asm_op, &[a as u64, carry as u64]);
+            test.expect_stack(&[new_carry as u64, b as u64]);
+        }
+    }
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[0, 0]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 1]).expect_stack(&[1, u32::MAX as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let c = rand_value::<u64>();
+    let (new_carry, b) = rcl(a, true);
+    let test = build_op_test!(asm_op, &[c, a as u64, 1]);
+    test.expect_stack(&[new_carry as u64, b as u64, c]);
+}
+
+#[test]
+fn u32rcl_fail() {
+    let asm_op = "u32rcl";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_op_test!(asm_op, &[1, 2]);
+    test.expect_error(TestError::ExecutionError("NotBinaryValue"));
+}
+
+#[test]
+fn u32rcr() {
+    let asm_op = "u32rcr";
+
+    // --- test all-zeros and all-ones values with the carry set and clear ------------------------
+    for a in [0, u32::MAX, 1 << 31, 1, 0x12345678] {
+        for carry in [false, true] {
+            let (new_carry, b) = rcr(a, carry);
+            let test = build_op_test!(asm_op, &[a as u64, carry as u64]);
+            test.expect_stack(&[new_carry as u64, b as u64]);
+        }
+    }
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[0, 0]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 1]).expect_stack(&[1, u32::MAX as u64]);
+
+    // --- rotating right undoes rotating left ----------------------------------------------------
+    let a = rand_value::<u32>();
+    let (carry, b) = rcl(a, false);
+    let test = build_op_test!(asm_op, &[b as u64, carry as u64]);
+    test.expect_stack(&[0, a as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+    let (new_carry, b) = rcr(a, true);
+    let test = build_op_test!(asm_op, &[c, a as u64, 1]);
+    test.expect_stack(&[new_carry as u64, b as u64, c]);
+}
+
+#[test]
+fn u32rcr_fail() {
+    let asm_op = "u32rcr";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_op_test!(asm_op, &[1, 2]);
+    test.expect_error(TestError::ExecutionError("NotBinaryValue"));
+}
+
 #[test]
 fn u32checked_popcnt() {
     let asm_op = "u32checked_popcnt";
@@ -822,6 +975,694 @@ fn u32unchecked_popcnt() {
     build_op_test!(asm_op, &[4294967295]).expect_stack(&[32]);
 }
 
+#[test]
+fn u32hamming() {
+    let asm_op = "u32hamming";
+
+    // --- identical and complementary inputs -----------------------------------------------------
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[555, 555]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0, 4294967295]).expect_stack(&[32]);
+    build_op_test!(asm_op, &[0x0F0F0F0F, 0xF0F0F0F0]).expect_stack(&[32]);
+
+    // --- simple cases ---------------------------------------------------------------------------
+    build_op_test!(asm_op, &[1, 0]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[555, 65536]).expect_stack(&[6]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let expected = (a ^ b).count_ones();
+
+    let test = build_op_test!(asm_op, &[c as u64, a as u64, b as u64]);
+    test.expect_stack(&[expected as u64, c as u64]);
+}
+
+#[test]
+fn u32hamming_fail() {
+    let asm_op = "u32hamming";
+
+    build_op_test!(asm_op, &[U32_BOUND, 1]).expect_error(TestError::ExecutionError("NotU32Value"));
+    build_op_test!(asm_op, &[1, U32_BOUND]).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32popeq() {
+    let asm_op = "u32popeq";
+
+    // --- values with equal popcounts ------------------------------------------------------------
+    build_op_test!(asm_op, &[0b1100, 0b0011]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0x8000_0000, 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0x0F0F_0F0F, 0xF0F0_F0F0]).expect_stack(&[1]);
+
+    // --- values with different popcounts --------------------------------------------------------
+    build_op_test!(asm_op, &[0b1100, 0b0111]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0, 1]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 0xFFFF_FFFE]).expect_stack(&[0]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let expected = a.count_ones() == b.count_ones();
+
+    let test = build_op_test!(asm_op, &[c as u64, a as u64, b as u64]);
+    test.expect_stack(&[expected as u64, c as u64]);
+}
+
+#[test]
+fn u32popeq_fail() {
+    let asm_op = "u32popeq";
+
+    build_op_test!(asm_op, &[U32_BOUND, 1]).expect_error(TestError::ExecutionError("NotU32Value"));
+    build_op_test!(asm_op, &[1, U32_BOUND]).expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32distinctbytes() {
+    let asm_op = "u32distinctbytes";
+    build_op_test!(asm_op, &[0x00000000]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0x01020304]).expect_stack(&[4]);
+    build_op_test!(asm_op, &[0x01010203]).expect_stack(&[3]);
+    build_op_test!(asm_op, &[0x01020102]).expect_stack(&[2]);
+    build_op_test!(asm_op, &[0xFF0000FF]).expect_stack(&[2]);
+    build_op_test!(asm_op, &[0x00FF0000]).expect_stack(&[2]);
+    build_op_test!(asm_op, &[4294967295]).expect_stack(&[1]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let mut bytes = a.to_le_bytes().to_vec();
+    bytes.sort();
+    bytes.dedup();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[bytes.len() as u64, b as u64]);
+}
+
+#[test]
+fn u32distinctbytes_fail() {
+    let asm_op = "u32distinctbytes";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32bitfill() {
+    let asm_op = "u32bitfill";
+    build_op_test!(asm_op, &[0]).expect_stack(&[bitfill(0) as u64]);
+    build_op_test!(asm_op, &[1]).expect_stack(&[bitfill(1) as u64]);
+    build_op_test!(asm_op, &[0x8000_0000]).expect_stack(&[bitfill(0x8000_0000) as u64]);
+    build_op_test!(asm_op, &[0x0012_3456]).expect_stack(&[bitfill(0x0012_3456) as u64]);
+    build_op_test!(asm_op, &[4294967295]).expect_stack(&[4294967295]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[bitfill(a) as u64, b as u64]);
+}
+
+#[test]
+fn u32bitfill_fail() {
+    let asm_op = "u32bitfill";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32rev() {
+    let asm_op = "u32rev";
+    build_op_test!(asm_op, &[0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[1]).expect_stack(&[1 << 31]);
+    build_op_test!(asm_op, &[1 << 31]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0x0000FFFF]).expect_stack(&[0xFFFF0000]);
+    build_op_test!(asm_op, &[0x12345678]).expect_stack(&[0x1E6A2C48]);
+    build_op_test!(asm_op, &[4294967295]).expect_stack(&[4294967295]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[a.reverse_bits() as u64, b as u64]);
+}
+
+#[test]
+fn u32rev_fail() {
+    let asm_op = "u32rev";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32byteswap() {
+    let asm_op = "u32byteswap";
+    build_op_test!(asm_op, &[0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[1]).expect_stack(&[1 << 24]);
+    build_op_test!(asm_op, &[0x12345678]).expect_stack(&[0x78563412]);
+    build_op_test!(asm_op, &[0xFF0000FF]).expect_stack(&[0xFF0000FF]);
+    build_op_test!(asm_op, &[4294967295]).expect_stack(&[4294967295]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[a.swap_bytes() as u64, b as u64]);
+}
+
+#[test]
+fn u32byteswap_fail() {
+    let asm_op = "u32byteswap";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32morton_encode() {
+    let asm_op = "u32morton_encode";
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[1, 0]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0, 1]).expect_stack(&[2]);
+    build_op_test!(asm_op, &[0xFFFF, 0]).expect_stack(&[0x55555555]);
+    build_op_test!(asm_op, &[0, 0xFFFF]).expect_stack(&[0xAAAAAAAA]);
+    build_op_test!(asm_op, &[0xFFFF, 0xFFFF]).expect_stack(&[u32::MAX as u64]);
+    build_op_test!(asm_op, &[0x1234, 0xABCD]).expect_stack(&[morton(0x1234, 0xABCD) as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u16>();
+    let b = rand_value::<u16>();
+    let c = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[c as u64, a as u64, b as u64]);
+    test.expect_stack(&[morton(a, b) as u64, c as u64]);
+}
+
+#[test]
+fn u32morton_encode_fail() {
+    let asm_op = "u32morton_encode";
+
+    // should fail if either of the values is not a 16-bit value
+    let test = build_op_test!(asm_op, &[1 << 16, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    let test = build_op_test!(asm_op, &[0, 1 << 16]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32morton_decode() {
+    let asm_op = "u32morton_decode";
+    build_op_test!(asm_op, &[0]).expect_stack(&[0, 0]);
+    build_op_test!(asm_op, &[1]).expect_stack(&[0, 1]);
+    build_op_test!(asm_op, &[2]).expect_stack(&[1, 0]);
+    build_op_test!(asm_op, &[0x55555555]).expect_stack(&[0, 0xFFFF]);
+    build_op_test!(asm_op, &[0xAAAAAAAA]).expect_stack(&[0xFFFF, 0]);
+    build_op_test!(asm_op, &[u32::MAX as u64]).expect_stack(&[0xFFFF, 0xFFFF]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u16>();
+    let b = rand_value::<u16>();
+    let c = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[c as u64, morton(a, b) as u64]);
+    test.expect_stack(&[b as u64, a as u64, c as u64]);
+}
+
+#[test]
+fn u32morton_decode_fail() {
+    let asm_op = "u32morton_decode";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32testbit() {
+    let asm_op = "u32testbit";
+
+    // --- test bit 0 -----------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0b1010, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0b1011, 0]).expect_stack(&[1]);
+
+    // --- test bit 31 ----------------------------------------------------------------------------
+    build_op_test!(asm_op, &[1 << 31, 31]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[(1 << 31) - 1, 31]).expect_stack(&[0]);
+
+    // --- test random values and that the rest of the stack isn't affected -----------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let i = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64, i as u64]);
+    test.expect_stack(&[((a >> i) & 1) as u64, b as u64]);
+}
+
+#[test]
+fn u32testbit_b() {
+    let op_base = "u32testbit";
+    let get_asm_op = |i: u32| format!("{op_base}.{i}");
+
+    // --- test bit 0 -----------------------------------------------------------------------------
+    build_op_test!(get_asm_op(0).as_str(), &[0b1010]).expect_stack(&[0]);
+    build_op_test!(get_asm_op(0).as_str(), &[0b1011]).expect_stack(&[1]);
+
+    // --- test bit 31 ----------------------------------------------------------------------------
+    build_op_test!(get_asm_op(31).as_str(), &[1 << 31]).expect_stack(&[1]);
+    build_op_test!(get_asm_op(31).as_str(), &[(1 << 31) - 1]).expect_stack(&[0]);
+
+    // --- test random values and that the rest of the stack isn't affected -----------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let i = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(get_asm_op(i).as_str(), &[b as u64, a as u64]);
+    test.expect_stack(&[((a >> i) & 1) as u64, b as u64]);
+}
+
+#[test]
+fn u32testbit_b_fail() {
+    // should fail during compilation if the bit index is greater than 31
+    test_param_out_of_bounds("u32testbit", 32);
+}
+
+#[test]
+fn u32setbit() {
+    let asm_op = "u32setbit";
+
+    build_op_test!(asm_op, &[0, 0]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0, 31]).expect_stack(&[1 << 31]);
+    build_op_test!(asm_op, &[0b1010, 2]).expect_stack(&[0b1110]);
+
+    // --- setting a bit which is already set is a no-op ------------------------------------------
+    build_op_test!(asm_op, &[0b1010, 1]).expect_stack(&[0b1010]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 31]).expect_stack(&[u32::MAX as u64]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let i = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64, i as u64]);
+    test.expect_stack(&[(a | (1 << i)) as u64, b as u64]);
+}
+
+#[test]
+fn u32setbit_b() {
+    let op_base = "u32setbit";
+    let get_asm_op = |i: u32| format!("{op_base}.{i}");
+
+    build_op_test!(get_asm_op(0).as_str(), &[0]).expect_stack(&[1]);
+    build_op_test!(get_asm_op(31).as_str(), &[0]).expect_stack(&[1 << 31]);
+
+    // --- setting a bit which is already set is a no-op ------------------------------------------
+    build_op_test!(get_asm_op(1).as_str(), &[0b1010]).expect_stack(&[0b1010]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let i = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(get_asm_op(i).as_str(), &[b as u64, a as u64]);
+    test.expect_stack(&[(a | (1 << i)) as u64, b as u64]);
+}
+
+#[test]
+fn u32setbit_b_fail() {
+    test_param_out_of_bounds("u32setbit", 32);
+}
+
+#[test]
+fn u32clearbit() {
+    let asm_op = "u32clearbit";
+
+    build_op_test!(asm_op, &[1, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 31]).expect_stack(&[(u32::MAX >> 1) as u64]);
+    build_op_test!(asm_op, &[0b1110, 2]).expect_stack(&[0b1010]);
+
+    // --- clearing a bit which is already clear is a no-op ---------------------------------------
+    build_op_test!(asm_op, &[0b1010, 2]).expect_stack(&[0b1010]);
+    build_op_test!(asm_op, &[0, 31]).expect_stack(&[0]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let i = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64, i as u64]);
+    test.expect_stack(&[(a & !(1 << i)) as u64, b as u64]);
+}
+
+#[test]
+fn u32clearbit_b() {
+    let op_base = "u32clearbit";
+    let get_asm_op = |i: u32| format!("{op_base}.{i}");
+
+    build_op_test!(get_asm_op(0).as_str(), &[1]).expect_stack(&[0]);
+    build_op_test!(get_asm_op(31).as_str(), &[u32::MAX as u64])
+        .expect_stack(&[(u32::MAX >> 1) as u64]);
+
+    // --- clearing a bit which is already clear is a no-op ---------------------------------------
+    build_op_test!(get_asm_op(2).as_str(), &[0b1010]).expect_stack(&[0b1010]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let i = rand_value::<u32>() % 32;
+
+    let test = build_op_test!(get_asm_op(i).as_str(), &[b as u64, a as u64]);
+    test.expect_stack(&[(a & !(1 << i)) as u64, b as u64]);
+}
+
+#[test]
+fn u32clearbit_b_fail() {
+    test_param_out_of_bounds("u32clearbit", 32);
+}
+
+#[test]
+fn u32bfe() {
+    let asm_op = "u32bfe";
+
+    // --- extract the full value -----------------------------------------------------------------
+    build_op_test!(asm_op, &[u32::MAX as u64, 0, 32]).expect_stack(&[u32::MAX as u64]);
+
+    // --- extract an empty bit field -------------------------------------------------------------
+    build_op_test!(asm_op, &[u32::MAX as u64, 5, 0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 32, 0]).expect_stack(&[0]);
+
+    // --- extract bit fields at the boundaries ---------------------------------------------------
+    build_op_test!(asm_op, &[0xabcd_1234, 0, 8]).expect_stack(&[0x34]);
+    build_op_test!(asm_op, &[0xabcd_1234, 24, 8]).expect_stack(&[0xab]);
+    build_op_test!(asm_op, &[0xabcd_1234, 31, 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[0xabcd_1234, 12, 8]).expect_stack(&[0xd1]);
+
+    // --- test random values and that the rest of the stack isn't affected -----------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let offset = rand_value::<u32>() % 33;
+    let width = rand_value::<u32>() % (33 - offset);
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64, offset as u64, width as u64]);
+    test.expect_stack(&[bfe(a, offset, width) as u64, b as u64]);
+}
+
+#[test]
+fn u32bfe_fail() {
+    let asm_op = "u32bfe";
+
+    // should fail if the bit field does not fit into 32 bits
+    let test = build_op_test!(asm_op, &[1, 16, 17]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    let test = build_op_test!(asm_op, &[1, 33, 0]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn u32bfe_b() {
+    let op_base = "u32bfe";
+    let get_asm_op = |offset: u32, width: u32| format!("{op_base}.{offset}.{width}");
+
+    // --- extract the full value -----------------------------------------------------------------
+    build_op_test!(get_asm_op(0, 32).as_str(), &[u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+
+    // --- extract an empty bit field -------------------------------------------------------------
+    build_op_test!(get_asm_op(5, 0).as_str(), &[u32::MAX as u64]).expect_stack(&[0]);
+    build_op_test!(get_asm_op(32, 0).as_str(), &[u32::MAX as u64]).expect_stack(&[0]);
+
+    // --- extract bit fields at the boundaries ---------------------------------------------------
+    build_op_test!(get_asm_op(0, 8).as_str(), &[0xabcd_1234]).expect_stack(&[0x34]);
+    build_op_test!(get_asm_op(24, 8).as_str(), &[0xabcd_1234]).expect_stack(&[0xab]);
+    build_op_test!(get_asm_op(31, 1).as_str(), &[0xabcd_1234]).expect_stack(&[1]);
+    build_op_test!(get_asm_op(12, 8).as_str(), &[0xabcd_1234]).expect_stack(&[0xd1]);
+
+    // --- test random values and that the rest of the stack isn't affected -----------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let offset = rand_value::<u32>() % 33;
+    let width = rand_value::<u32>() % (33 - offset);
+
+    let test = build_op_test!(get_asm_op(offset, width).as_str(), &[b as u64, a as u64]);
+    test.expect_stack(&[bfe(a, offset, width) as u64, b as u64]);
+}
+
+#[test]
+fn u32bfe_b_fail() {
+    // should fail during compilation if the bit field does not fit into 32 bits
+    test_param_out_of_bounds("u32bfe.16", 17);
+    test_param_out_of_bounds("u32bfe.33", 0);
+    test_param_out_of_bounds("u32bfe.0", 33);
+}
+
+#[test]
+fn u32bfi() {
+    let op_base = "u32bfi";
+    let get_asm_op = |offset: u32, width: u32| format!("{op_base}.{offset}.{width}");
+
+    // --- insert into the low bits ---------------------------------------------------------------
+    build_op_test!(get_asm_op(0, 8).as_str(), &[0xabcd_1234, 0x56]).expect_stack(&[0xabcd_1256]);
+
+    // --- insert into the middle bits ------------------------------------------------------------
+    build_op_test!(get_asm_op(12, 8).as_str(), &[0xabcd_1234, 0x56]).expect_stack(&[0xabc5_6234]);
+
+    // --- insert into the high bits --------------------------------------------------------------
+    build_op_test!(get_asm_op(24, 8).as_str(), &[0xabcd_1234, 0x56]).expect_stack(&[0x56cd_1234]);
+    build_op_test!(get_asm_op(31, 1).as_str(), &[0, 1]).expect_stack(&[1 << 31]);
+
+    // --- only the lowest width bits of the inserted value are used ------------------------------
+    build_op_test!(get_asm_op(4, 4).as_str(), &[0, u32::MAX as u64]).expect_stack(&[0xf0]);
+
+    // --- surrounding bits are preserved when clearing the bit field -----------------------------
+    build_op_test!(get_asm_op(8, 16).as_str(), &[u32::MAX as u64, 0]).expect_stack(&[0xff00_00ff]);
+
+    // --- full-width and empty bit fields --------------------------------------------------------
+    build_op_test!(get_asm_op(0, 32).as_str(), &[0xabcd_1234, 7]).expect_stack(&[7]);
+    build_op_test!(get_asm_op(32, 0).as_str(), &[0xabcd_1234, 7]).expect_stack(&[0xabcd_1234]);
+
+    // --- test random values and that the rest of the stack isn't affected -----------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let offset = rand_value::<u32>() % 33;
+    let width = rand_value::<u32>() % (33 - offset);
+
+    let test = build_op_test!(get_asm_op(offset, width).as_str(), &[c as u64, a as u64, b as u64]);
+    test.expect_stack(&[bfi(a, b, offset, width) as u64, c as u64]);
+}
+
+#[test]
+fn u32bfi_fail() {
+    let asm_op = "u32bfi.8.8";
+
+    // should fail if either of the inputs is not a u32 value
+    build_op_test!(asm_op, &[U32_BOUND, 0]).expect_error(TestError::ExecutionError("NotU32Value"));
+    build_op_test!(asm_op, &[0, U32_BOUND]).expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail during compilation if the bit field does not fit into 32 bits
+    test_param_out_of_bounds("u32bfi.16", 17);
+    test_param_out_of_bounds("u32bfi.33", 0);
+}
+
+#[test]
+fn u32to16() {
+    let asm_op = "u32to16";
+    build_op_test!(asm_op, &[0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0xbeef]).expect_stack(&[0xbeef]);
+    build_op_test!(asm_op, &[0x1_0000]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0xdead_beef]).expect_stack(&[0xbeef]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[a as u16 as u64, b as u64]);
+}
+
+#[test]
+fn u32to16_fail() {
+    let asm_op = "u32to16";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32to8() {
+    let asm_op = "u32to8";
+    build_op_test!(asm_op, &[0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0xef]).expect_stack(&[0xef]);
+    build_op_test!(asm_op, &[0x100]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0xdead_beef]).expect_stack(&[0xef]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[a as u8 as u64, b as u64]);
+}
+
+#[test]
+fn u32to8_fail() {
+    let asm_op = "u32to8";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32maj() {
+    let asm_op = "u32maj";
+
+    // each bit of the result is set if at least two of the corresponding input bits are set
+    let (a, b, c) = (0b1111_0000, 0b1100_1100, 0b1010_1010);
+    build_op_test!(asm_op, &[a, b, c]).expect_stack(&[0b1110_1000]);
+    build_op_test!(asm_op, &[0, 0, u32::MAX as u64]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 0, u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let d = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[d as u64, a as u64, b as u64, c as u64]);
+    test.expect_stack(&[maj(a, b, c) as u64, d as u64]);
+}
+
+#[test]
+fn u32maj_fail() {
+    let asm_op = "u32maj";
+
+    // should fail if any of the inputs is not a u32 value
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    let test = build_op_test!(asm_op, &[0, U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    let test = build_op_test!(asm_op, &[0, 0, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32ch() {
+    let asm_op = "u32ch";
+
+    // each bit of the result is taken from b if the corresponding bit of a is set, and from c
+    // otherwise
+    let (a, b, c) = (0b1111_0000, 0b1100_1100, 0b1010_1010);
+    build_op_test!(asm_op, &[a, b, c]).expect_stack(&[0b1100_1010]);
+    build_op_test!(asm_op, &[0, 5, 6]).expect_stack(&[6]);
+    build_op_test!(asm_op, &[u32::MAX as u64, 5, 6]).expect_stack(&[5]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let c = rand_value::<u32>();
+    let d = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[d as u64, a as u64, b as u64, c as u64]);
+    test.expect_stack(&[ch(a, b, c) as u64, d as u64]);
+}
+
+#[test]
+fn u32ch_fail() {
+    let asm_op = "u32ch";
+
+    // should fail if any of the inputs is not a u32 value
+    let test = build_op_test!(asm_op, &[U32_BOUND, 0, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    let test = build_op_test!(asm_op, &[0, U32_BOUND, 0]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+    let test = build_op_test!(asm_op, &[0, 0, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32sigma() {
+    // the small sigma functions of SHA-256; the big sigma functions combine three rotations and
+    // thus cannot be expressed via this instruction
+    let params = [(7, 18, 3), (17, 19, 10)];
+    let values = [0, 1, 0x8000_0000, 0xdead_beef, u32::MAX, rand_value::<u32>()];
+    for (a, b, c) in params {
+        let asm_op = format!("u32sigma.{a}.{b}.{c}");
+        for x in values {
+            build_op_test!(&asm_op, &[x as u64]).expect_stack(&[sigma(x, a, b, c) as u64]);
+        }
+    }
+
+    // for a single set bit, the result has the rotated and shifted bits set
+    build_op_test!("u32sigma.7.18.3", &[0x8000_0000]).expect_stack(&[0x1100_2000]);
+    build_op_test!("u32sigma.17.19.10", &[0x8000_0000]).expect_stack(&[0x0020_5000]);
+
+    // zero amounts are supported as well
+    build_op_test!("u32sigma.0.0.0", &[0xdead_beef]).expect_stack(&[0xdead_beef]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let x = rand_value::<u32>();
+    let y = rand_value::<u32>();
+
+    let test = build_op_test!("u32sigma.7.18.3", &[y as u64, x as u64]);
+    test.expect_stack(&[sigma(x, 7, 18, 3) as u64, y as u64]);
+}
+
+#[test]
+fn u32sigma_fail() {
+    let asm_op = "u32sigma.7.18.3";
+
+    // should fail if the input is not a u32 value
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // should fail during compilation if any of the amounts is out of bounds
+    test_param_out_of_bounds("u32sigma.32.18", 3);
+    test_param_out_of_bounds("u32sigma.7.32", 3);
+    test_param_out_of_bounds("u32sigma.7.18", 32);
+}
+
+#[test]
+fn i32cls() {
+    let asm_op = "i32cls";
+
+    // --- zero and minus one have 31 redundant sign bits -----------------------------------------
+    build_op_test!(asm_op, &[0]).expect_stack(&[31]);
+    build_op_test!(asm_op, &[u32::MAX as u64]).expect_stack(&[31]);
+
+    // --- positive values ------------------------------------------------------------------------
+    build_op_test!(asm_op, &[1]).expect_stack(&[30]);
+    build_op_test!(asm_op, &[0x0012_3456]).expect_stack(&[cls(0x0012_3456) as u64]);
+    build_op_test!(asm_op, &[i32::MAX as u64]).expect_stack(&[0]);
+
+    // --- negative values ------------------------------------------------------------------------
+    build_op_test!(asm_op, &[(-2_i32) as u32 as u64]).expect_stack(&[30]);
+    build_op_test!(asm_op, &[0xffed_cba9]).expect_stack(&[cls(0xffed_cba9) as u64]);
+    build_op_test!(asm_op, &[i32::MIN as u32 as u64]).expect_stack(&[0]);
+
+    // --- random values and the rest of the stack isn't affected ---------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[cls(a) as u64, b as u64]);
+}
+
+#[test]
+fn i32cls_fail() {
+    let asm_op = "i32cls";
+
+    let test = build_op_test!(asm_op, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - BITWISE OPERATIONS
 // ================================================================================================
 
@@ -868,6 +1709,28 @@ proptest! {
         test.prop_expect_stack(&[!value as u64])?;
     }
 
+    #[test]
+    fn u32nand_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let asm_opcode = "u32nand";
+        let values = [a as u64, b as u64];
+        // should result in bitwise NAND
+        let expected = !(a & b) as u64;
+
+        let test = build_op_test!(asm_opcode, &values);
+        test.prop_expect_stack(&[expected])?;
+    }
+
+    #[test]
+    fn u32nor_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let asm_opcode = "u32nor";
+        let values = [a as u64, b as u64];
+        // should result in bitwise NOR
+        let expected = !(a | b) as u64;
+
+        let test = build_op_test!(asm_opcode, &values);
+        test.prop_expect_stack(&[expected])?;
+    }
+
     #[test]
     fn u32checked_shl_proptest(a in any::<u32>(), b in 0_u32..32) {
         let asm_opcode = "u32checked_shl";
@@ -1019,4 +1882,172 @@ proptest! {
         let test = build_op_test!(asm_opcode, &[a as u64]);
         test.prop_expect_stack(&[expected as u64])?;
     }
+    #[test]
+    fn u32rev_proptest(a in any::<u32>()) {
+        let asm_opcode = "u32rev";
+        let expected = a.reverse_bits();
+        let test = build_op_test!(asm_opcode, &[a as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+    #[test]
+    fn u32morton_round_trip_proptest(a in any::<u16>(), b in any::<u16>()) {
+        let source = "begin u32morton_encode dup u32morton_decode end";
+        let test = build_test!(source, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[b as u64, a as u64, morton(a, b) as u64])?;
+    }
+
+    #[test]
+    fn u32byteswap_proptest(a in any::<u32>()) {
+        let asm_opcode = "u32byteswap";
+        let expected = a.swap_bytes();
+        let test = build_op_test!(asm_opcode, &[a as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+    #[test]
+    fn u32setbit_proptest(a in any::<u32>(), i in 0_u32..32) {
+        let asm_opcode = "u32setbit";
+        let expected = a | (1 << i);
+        let test = build_op_test!(asm_opcode, &[a as u64, i as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+
+        // setting the same bit again does not change the result
+        let test = build_op_test!(asm_opcode, &[expected as u64, i as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+    #[test]
+    fn u32clearbit_proptest(a in any::<u32>(), i in 0_u32..32) {
+        let asm_opcode = "u32clearbit";
+        let expected = a & !(1 << i);
+        let test = build_op_test!(asm_opcode, &[a as u64, i as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+
+        // clearing the same bit again does not change the result
+        let test = build_op_test!(asm_opcode, &[expected as u64, i as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+
+    #[test]
+    fn u32bfe_proptest(a in any::<u32>(), offset in 0_u32..=32, width in 0_u32..=32) {
+        prop_assume!(offset + width <= 32);
+        let asm_opcode = "u32bfe";
+        let expected = bfe(a, offset, width);
+        let test = build_op_test!(asm_opcode, &[a as u64, offset as u64, width as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+
+    #[test]
+    fn u32bfi_proptest(
+        a in any::<u32>(),
+        b in any::<u32>(),
+        offset in 0_u32..=32,
+        width in 0_u32..=32,
+    ) {
+        prop_assume!(offset + width <= 32);
+        let asm_opcode = format!("u32bfi.{offset}.{width}");
+        let expected = bfi(a, b, offset, width);
+        let test = build_op_test!(&asm_opcode, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+
+        // the inserted bit field can be extracted again
+        let source = format!("begin {asm_opcode} u32bfe.{offset}.{width} end");
+        let test = build_test!(&source, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[bfe(b, 0, width) as u64])?;
+    }
+
+    #[test]
+    fn u32maj_proptest(a in any::<u32>(), b in any::<u32>(), c in any::<u32>()) {
+        let asm_opcode = "u32maj";
+        let test = build_op_test!(asm_opcode, &[a as u64, b as u64, c as u64]);
+        test.prop_expect_stack(&[maj(a, b, c) as u64])?;
+    }
+
+    #[test]
+    fn u32ch_proptest(a in any::<u32>(), b in any::<u32>(), c in any::<u32>()) {
+        let asm_opcode = "u32ch";
+        let test = build_op_test!(asm_opcode, &[a as u64, b as u64, c as u64]);
+        test.prop_expect_stack(&[ch(a, b, c) as u64])?;
+    }
+
+    #[test]
+    fn u32sigma_proptest(x in any::<u32>(), a in 0_u32..32, b in 0_u32..32, c in 0_u32..32) {
+        let asm_opcode = format!("u32sigma.{a}.{b}.{c}");
+        let test = build_op_test!(&asm_opcode, &[x as u64]);
+        test.prop_expect_stack(&[sigma(x, a, b, c) as u64])?;
+    }
+
+    #[test]
+    fn i32cls_proptest(a in any::<u32>()) {
+        let asm_opcode = "i32cls";
+        let test = build_op_test!(asm_opcode, &[a as u64]);
+        test.prop_expect_stack(&[cls(a) as u64])?;
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Rotates the 33-bit value formed by `a` and `carry` to the left by one bit, and returns the new
+/// carry bit and the new value.
+fn rcl(a: u32, carry: bool) -> (bool, u32) {
+    (a >> 31 == 1, (a << 1) | carry as u32)
+}
+
+/// Rotates the 33-bit value formed by `a` and `carry` to the right by one bit, and returns the new
+/// carry bit and the new value.
+fn rcr(a: u32, carry: bool) -> (bool, u32) {
+    (a & 1 == 1, (a >> 1) | ((carry as u32) << 31))
+}
+
+/// Returns a mask with all bits up to (and including) the most significant set bit of `a` set.
+fn bitfill(a: u32) -> u32 {
+    match a {
+        0 => 0,
+        _ => u32::MAX >> a.leading_zeros(),
+    }
+}
+
+/// Returns the bit field of `width` bits starting at bit `offset` of `a`.
+fn bfe(a: u32, offset: u32, width: u32) -> u32 {
+    ((a as u64 >> offset) & ((1 << width) - 1)) as u32
+}
+
+/// Replaces the bit field of `width` bits starting at bit `offset` of `a` with the lowest `width`
+/// bits of `b`.
+fn bfi(a: u32, b: u32, offset: u32, width: u32) -> u32 {
+    let mask = ((1_u64 << width) - 1) << offset;
+    ((a as u64 & !mask) | ((b as u64) << offset & mask)) as u32
+}
+
+/// Returns the bitwise majority of `a`, `b`, and `c` as defined in SHA-256.
+fn maj(a: u32, b: u32, c: u32) -> u32 {
+    (a & b) ^ (a & c) ^ (b & c)
+}
+
+/// Returns the bitwise choice of `b` and `c` by `a` as defined in SHA-256.
+fn ch(a: u32, b: u32, c: u32) -> u32 {
+    (a & b) ^ (!a & c)
+}
+
+/// Returns rotr(x, a) ^ rotr(x, b) ^ shr(x, c), as used in the sigma functions of SHA-256.
+fn sigma(x: u32, a: u32, b: u32, c: u32) -> u32 {
+    x.rotate_right(a) ^ x.rotate_right(b) ^ (x >> c)
+}
+
+/// Returns the number of leading bits of `a` which are equal to its sign bit, excluding the sign
+/// bit itself, with `a` interpreted as a two's-complement 32-bit integer.
+fn cls(a: u32) -> u32 {
+    let a = a as i32;
+    if a < 0 {
+        a.leading_ones() - 1
+    } else {
+        a.leading_zeros() - 1
+    }
+}
+
+/// Interleaves the bits of `a` and `b`, such that bit i of `a` becomes bit 2i of the result and
+/// bit i of `b` becomes bit 2i + 1 of the result.
+fn morton(a: u16, b: u16) -> u32 {
+    (0..16).fold(0, |c, i| {
+        c | (((a as u32 >> i) & 1) << (2 * i)) | (((b as u32 >> i) & 1) << (2 * i + 1))
+    })
 }
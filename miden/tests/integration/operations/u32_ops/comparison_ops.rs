@@ -1,6 +1,8 @@
 use super::{test_inputs_out_of_bounds, test_param_out_of_bounds, test_unchecked_execution};
 use core::cmp::Ordering;
-use test_utils::{build_op_test, proptest::prelude::*, rand::rand_value, TestError, U32_BOUND};
+use test_utils::{
+    build_op_test, proptest::prelude::*, rand::rand_value, Felt, StarkField, TestError, U32_BOUND,
+};
 
 // U32 OPERATIONS TESTS - MANUAL - COMPARISON OPERATIONS
 // ================================================================================================
@@ -279,6 +281,28 @@ fn u32unchecked_gte() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32cmp() {
+    let asm_op = "u32cmp";
+
+    // should push -1 if a < b, 0 if a = b, and 1 if a > b
+    test_comparison_op(asm_op, Felt::MODULUS - 1, 0, 1);
+
+    // --- edge cases -----------------------------------------------------------------------------
+    let max = U32_BOUND - 1;
+    build_op_test!(asm_op, &[0, max]).expect_stack(&[Felt::MODULUS - 1]);
+    build_op_test!(asm_op, &[max, max]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[max, 0]).expect_stack(&[1]);
+}
+
+#[test]
+fn u32cmp_fail() {
+    let asm_op = "u32cmp";
+
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds(asm_op, 2);
+}
+
 #[test]
 fn u32checked_min() {
     let asm_op = "u32checked_min";
@@ -333,6 +357,197 @@ fn u32unchecked_max() {
     test_unchecked_execution(asm_op, 2);
 }
 
+#[test]
+fn u32abs_diff() {
+    let asm_op = "u32abs_diff";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    build_op_test!(asm_op, &[0, 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[1, 0]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[5, 5]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0, U32_BOUND - 1]).expect_stack(&[U32_BOUND - 1]);
+    build_op_test!(asm_op, &[U32_BOUND - 1, 0]).expect_stack(&[U32_BOUND - 1]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let expected = a.abs_diff(b) as u64;
+
+    let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+    test.expect_stack(&[expected]);
+
+    let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+    test.expect_stack(&[expected]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, a as u64, b as u64]);
+    test.expect_stack(&[expected, c]);
+}
+
+#[test]
+fn u32abs_diff_fail() {
+    let asm_op = "u32abs_diff";
+
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds(asm_op, 2);
+}
+
+#[test]
+fn u32clamp() {
+    let asm_op = "u32clamp";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    // value below min
+    build_op_test!(asm_op, &[1, 5, 10]).expect_stack(&[5]);
+    // value inside the range
+    build_op_test!(asm_op, &[7, 5, 10]).expect_stack(&[7]);
+    build_op_test!(asm_op, &[5, 5, 10]).expect_stack(&[5]);
+    build_op_test!(asm_op, &[10, 5, 10]).expect_stack(&[10]);
+    // value above max
+    build_op_test!(asm_op, &[11, 5, 10]).expect_stack(&[10]);
+    build_op_test!(asm_op, &[U32_BOUND - 1, 0, 10]).expect_stack(&[10]);
+    // equal bounds
+    build_op_test!(asm_op, &[1, 5, 5]).expect_stack(&[5]);
+    build_op_test!(asm_op, &[5, 5, 5]).expect_stack(&[5]);
+    build_op_test!(asm_op, &[9, 5, 5]).expect_stack(&[5]);
+
+    // --- random u32 values ----------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let b = rand_value::<u32>();
+    let (min, max) = (a.min(b), a.max(b));
+    let value = rand_value::<u32>();
+    let expected = value.clamp(min, max) as u64;
+
+    let test = build_op_test!(asm_op, &[value as u64, min as u64, max as u64]);
+    test.expect_stack(&[expected]);
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, value as u64, min as u64, max as u64]);
+    test.expect_stack(&[expected, c]);
+}
+
+#[test]
+fn u32clamp_fail() {
+    let asm_op = "u32clamp";
+
+    // should fail if any one of 3 inputs is out of bounds
+    test_inputs_out_of_bounds(asm_op, 3);
+}
+
+#[test]
+fn i32lt() {
+    let asm_op = "i32lt";
+    test_i32_comparison_op(asm_op, |a, b| a < b);
+
+    // --- mixed-sign cases -----------------------------------------------------------------------
+    build_op_test!(asm_op, &[i32_to_u64(-1), 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[1, i32_to_u64(-1)]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[i32_to_u64(-2), i32_to_u64(-1)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(i32::MIN), i32_to_u64(i32::MAX)]).expect_stack(&[1]);
+}
+
+#[test]
+fn i32lt_fail() {
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds("i32lt", 2);
+}
+
+#[test]
+fn i32lte() {
+    let asm_op = "i32lte";
+    test_i32_comparison_op(asm_op, |a, b| a <= b);
+
+    // --- mixed-sign cases -----------------------------------------------------------------------
+    build_op_test!(asm_op, &[i32_to_u64(-1), 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[1, i32_to_u64(-1)]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[i32_to_u64(-1), i32_to_u64(-1)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(i32::MAX), i32_to_u64(i32::MIN)]).expect_stack(&[0]);
+}
+
+#[test]
+fn i32lte_fail() {
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds("i32lte", 2);
+}
+
+#[test]
+fn i32gt() {
+    let asm_op = "i32gt";
+    test_i32_comparison_op(asm_op, |a, b| a > b);
+
+    // --- mixed-sign cases -----------------------------------------------------------------------
+    build_op_test!(asm_op, &[1, i32_to_u64(-1)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(-1), 1]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[i32_to_u64(-1), i32_to_u64(-2)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(i32::MAX), i32_to_u64(i32::MIN)]).expect_stack(&[1]);
+}
+
+#[test]
+fn i32gt_fail() {
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds("i32gt", 2);
+}
+
+#[test]
+fn i32gte() {
+    let asm_op = "i32gte";
+    test_i32_comparison_op(asm_op, |a, b| a >= b);
+
+    // --- mixed-sign cases -----------------------------------------------------------------------
+    build_op_test!(asm_op, &[1, i32_to_u64(-1)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(-1), 1]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[i32_to_u64(-1), i32_to_u64(-1)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(i32::MIN), i32_to_u64(i32::MAX)]).expect_stack(&[0]);
+}
+
+#[test]
+fn i32gte_fail() {
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds("i32gte", 2);
+}
+
+#[test]
+fn i32min() {
+    let asm_op = "i32min";
+    test_i32_min_max_op(asm_op, |a, b| a.min(b));
+
+    // --- mixed-sign and equal values ------------------------------------------------------------
+    build_op_test!(asm_op, &[i32_to_u64(-1), 1]).expect_stack(&[i32_to_u64(-1)]);
+    build_op_test!(asm_op, &[1, i32_to_u64(-1)]).expect_stack(&[i32_to_u64(-1)]);
+    build_op_test!(asm_op, &[i32_to_u64(i32::MIN), i32_to_u64(i32::MAX)])
+        .expect_stack(&[i32_to_u64(i32::MIN)]);
+    build_op_test!(asm_op, &[i32_to_u64(-5), i32_to_u64(-5)]).expect_stack(&[i32_to_u64(-5)]);
+}
+
+#[test]
+fn i32min_fail() {
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds("i32min", 2);
+}
+
+#[test]
+fn i32max() {
+    let asm_op = "i32max";
+    test_i32_min_max_op(asm_op, |a, b| a.max(b));
+
+    // --- mixed-sign and equal values ------------------------------------------------------------
+    build_op_test!(asm_op, &[i32_to_u64(-1), 1]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[1, i32_to_u64(-1)]).expect_stack(&[1]);
+    build_op_test!(asm_op, &[i32_to_u64(i32::MIN), i32_to_u64(i32::MAX)])
+        .expect_stack(&[i32_to_u64(i32::MAX)]);
+    build_op_test!(asm_op, &[i32_to_u64(-5), i32_to_u64(-5)]).expect_stack(&[i32_to_u64(-5)]);
+}
+
+#[test]
+fn i32max_fail() {
+    // should fail if either one of 2 inputs is out of bounds
+    test_inputs_out_of_bounds("i32max", 2);
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - COMPARISON OPERATIONS
 // ================================================================================================
 
@@ -443,6 +658,19 @@ proptest! {
         test.prop_expect_stack(&[expected])?;
     }
 
+    #[test]
+    fn u32cmp_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let asm_op = "u32cmp";
+        let expected = match a.cmp(&b) {
+            Ordering::Less => Felt::MODULUS - 1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+
+        let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected])?;
+    }
+
     #[test]
     fn u32min_proptest(a in any::<u32>(), b in any::<u32>()) {
         let asm_op = "u32checked_min";
@@ -470,6 +698,19 @@ proptest! {
         let test = build_op_test!(&asm_op, &[a as u64, b as u64]);
         test.prop_expect_stack(&[expected as u64])?;
     }
+
+    #[test]
+    fn u32abs_diff_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let asm_op = "u32abs_diff";
+        let expected = a.abs_diff(b);
+
+        // the result should not depend on the order of the inputs
+        let test = build_op_test!(asm_op, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+
+        let test = build_op_test!(asm_op, &[b as u64, a as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
 }
 
 // HELPER FUNCTIONS
@@ -510,6 +751,54 @@ fn test_comparison_op(asm_op: &str, expected_lt: u64, expected_eq: u64, expected
     test.expect_stack(&[expected, c]);
 }
 
+/// This helper function tests that the provided assembly comparison operation, which interprets
+/// its inputs as two's-complement 32-bit integers, pushes the result of the `expected` comparison
+/// to the stack for a number of cases. Finally, it ensures that the rest of the stack was
+/// unaffected.
+fn test_i32_comparison_op(asm_op: &str, expected: fn(i32, i32) -> bool) {
+    let values = [i32::MIN, i32::MIN + 1, -2, -1, 0, 1, 2, i32::MAX - 1, i32::MAX];
+    for a in values {
+        for b in values {
+            let test = build_op_test!(asm_op, &[i32_to_u64(a), i32_to_u64(b)]);
+            test.expect_stack(&[expected(a, b) as u64]);
+        }
+    }
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>() as i32;
+    let b = rand_value::<u32>() as i32;
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, i32_to_u64(a), i32_to_u64(b)]);
+    test.expect_stack(&[expected(a, b) as u64, c]);
+}
+
+/// This helper function tests that the provided assembly min/max operation, which interprets its
+/// inputs as two's-complement 32-bit integers, pushes the result of the `expected` operation to
+/// the stack for a number of cases. Finally, it ensures that the rest of the stack was unaffected.
+fn test_i32_min_max_op(asm_op: &str, expected: fn(i32, i32) -> i32) {
+    let values = [i32::MIN, i32::MIN + 1, -2, -1, 0, 1, 2, i32::MAX - 1, i32::MAX];
+    for a in values {
+        for b in values {
+            let test = build_op_test!(asm_op, &[i32_to_u64(a), i32_to_u64(b)]);
+            test.expect_stack(&[i32_to_u64(expected(a, b))]);
+        }
+    }
+
+    // --- test that the rest of the stack isn't affected -----------------------------------------
+    let a = rand_value::<u32>() as i32;
+    let b = rand_value::<u32>() as i32;
+    let c = rand_value::<u64>();
+
+    let test = build_op_test!(asm_op, &[c, i32_to_u64(a), i32_to_u64(b)]);
+    test.expect_stack(&[i32_to_u64(expected(a, b)), c]);
+}
+
+/// Returns the two's-complement representation of the provided value as a u32 value.
+fn i32_to_u64(value: i32) -> u64 {
+    value as u32 as u64
+}
+
 /// Tests a u32min assembly operation (u32checked_min or u32unchecked_min) against a number of
 /// cases to ensure that the operation puts the minimum of 2 input values on the stack.
 fn test_min(asm_op: &str) {
@@ -1,7 +1,7 @@
 use super::{prop_randw, test_inputs_out_of_bounds};
 use test_utils::{
-    build_op_test, proptest::prelude::*, rand::rand_value, Felt, StarkField, TestError, U32_BOUND,
-    WORD_SIZE,
+    build_op_test, build_test, proptest::prelude::*, rand::rand_value, Felt, StarkField, TestError,
+    U32_BOUND, WORD_SIZE,
 };
 
 // U32 OPERATIONS TESTS - MANUAL - CONVERSIONS AND TESTS
@@ -14,13 +14,18 @@ fn u32test() {
 
     // vars to test
     let smaller = 1_u64;
+    let max = u32::MAX as u64;
     let equal = 1_u64 << 32;
     let larger = equal + 1;
+    let bits_33 = (1_u64 << 33) - 1;
 
     // --- a < 2^32 -------------------------------------------------------------------------------
     let test = build_op_test!(asm_op, &[smaller]);
     test.expect_stack(&[1, smaller]);
 
+    let test = build_op_test!(asm_op, &[max]);
+    test.expect_stack(&[1, max]);
+
     // --- a = 2^32 -------------------------------------------------------------------------------
     let test = build_op_test!(asm_op, &[equal]);
     test.expect_stack(&[0, equal]);
@@ -28,6 +33,15 @@ fn u32test() {
     // --- a > 2^32 -------------------------------------------------------------------------------
     let test = build_op_test!(asm_op, &[larger]);
     test.expect_stack(&[0, larger]);
+
+    // --- a is a 33-bit value --------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[bits_33]);
+    test.expect_stack(&[0, bits_33]);
+
+    // --- the result can be used to branch on malformed inputs without aborting ------------------
+    let source = "begin u32test if.true push.10 else push.20 end end";
+    let test = build_test!(source, &[bits_33]);
+    test.expect_stack(&[20, bits_33]);
 }
 
 #[test]
@@ -215,6 +229,82 @@ fn u32split() {
     test.expect_stack(&[expected_hi, expected_lo, a]);
 }
 
+#[test]
+fn u32frombytes() {
+    let asm_op = "u32frombytes";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    // the byte at the top of the stack is the most significant one
+    let test = build_op_test!(asm_op, &[1, 0, 0, 0]);
+    test.expect_stack(&[1]);
+
+    let test = build_op_test!(asm_op, &[0, 0, 0, 1]);
+    test.expect_stack(&[1 << 24]);
+
+    let test = build_op_test!(asm_op, &[0x78, 0x56, 0x34, 0x12]);
+    test.expect_stack(&[0x1234_5678]);
+
+    let test = build_op_test!(asm_op, &[255, 255, 255, 255]);
+    test.expect_stack(&[u32::MAX as u64]);
+
+    // --- random bytes and the rest of the stack isn't affected ----------------------------------
+    let a = rand_value::<u32>();
+    let c = rand_value::<u64>();
+    let mut inputs = vec![c];
+    inputs.extend(a.to_le_bytes().iter().map(|&b| b as u64));
+    let test = build_op_test!(asm_op, &inputs);
+    test.expect_stack(&[a as u64, c]);
+}
+
+#[test]
+fn u32frombytes_fail() {
+    let asm_op = "u32frombytes";
+
+    // should fail if any of the bytes is 256 or greater
+    for i in 0..4 {
+        let mut inputs = [1, 2, 3, 4];
+        inputs[i] = 256;
+        let test = build_op_test!(asm_op, &inputs);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+
+    // should fail if any of the bytes is not a u32 value
+    let test = build_op_test!(asm_op, &[1, 2, U32_BOUND, 4]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+#[test]
+fn u32tobytes() {
+    let asm_op = "u32tobytes";
+
+    // --- simple cases ---------------------------------------------------------------------------
+    // the most significant byte ends up at the top of the stack
+    let test = build_op_test!(asm_op, &[0]);
+    test.expect_stack(&[0, 0, 0, 0]);
+
+    let test = build_op_test!(asm_op, &[0x1234_5678]);
+    test.expect_stack(&[0x12, 0x34, 0x56, 0x78]);
+
+    let test = build_op_test!(asm_op, &[u32::MAX as u64]);
+    test.expect_stack(&[255, 255, 255, 255]);
+
+    // --- random u32 value and the rest of the stack isn't affected ------------------------------
+    let a = rand_value::<u32>();
+    let c = rand_value::<u64>();
+    let mut expected = a.to_be_bytes().iter().map(|&b| b as u64).collect::<Vec<_>>();
+    expected.push(c);
+    let test = build_op_test!(asm_op, &[c, a as u64]);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn u32tobytes_fail() {
+    let asm_op = "u32tobytes";
+
+    // should fail if the input is out of bounds
+    test_inputs_out_of_bounds(asm_op, 1);
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - CONVERSIONS AND TESTS
 // ================================================================================================
 proptest! {
@@ -293,4 +383,15 @@ proptest! {
         let test = build_op_test!(asm_op, &[value, value]);
         test.prop_expect_stack(&[expected_b, expected_c, value])?;
     }
+
+    #[test]
+    fn u32tobytes_frombytes_round_trip_proptest(value in any::<u32>()) {
+        let bytes = value.to_be_bytes().iter().map(|&b| b as u64).collect::<Vec<_>>();
+
+        let test = build_op_test!("u32tobytes", &[value as u64]);
+        test.prop_expect_stack(&bytes)?;
+
+        let test = build_op_test!("u32tobytes u32frombytes", &[value as u64]);
+        test.prop_expect_stack(&[value as u64])?;
+    }
 }
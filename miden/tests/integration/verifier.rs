@@ -0,0 +1,165 @@
+use miden::{
+    ContinuationSegment, DefaultHost, ExecutionProof, FieldExtension, HashFunction,
+    MemAdviceProvider, ProgramInfo, ProvingOptions, PublicInputs, StackOutputs, VerificationError,
+};
+use test_utils::{build_test, StackInputs};
+
+// VERIFIER TESTS
+// ================================================================================================
+
+#[test]
+fn proof_info() {
+    let (_, _, _, proof) = prove_program("begin push.1 push.2 add end", &[]);
+
+    let info = miden::proof_info(&proof);
+    assert_eq!(info.trace_len, proof.stark_proof().trace_length());
+    assert_eq!(info.num_queries, 27);
+    assert_eq!(info.blowup_factor, 8);
+    assert_eq!(info.fri_folding_factor, 8);
+    assert_eq!(info.grinding_bits, 16);
+}
+
+#[test]
+fn proof_extension_degree() {
+    let (_, _, _, proof) = prove_program("begin push.1 push.2 add end", &[]);
+    assert_eq!(miden::proof_extension_degree(&proof), 2);
+
+    for (field_extension, expected) in [
+        (FieldExtension::None, 1),
+        (FieldExtension::Quadratic, 2),
+        (FieldExtension::Cubic, 3),
+    ] {
+        let options =
+            ProvingOptions::new(27, 8, 16, field_extension, 8, 255, HashFunction::Blake3_192);
+        let proof = prove_program_with_options("begin push.1 push.2 add end", options);
+        assert_eq!(miden::proof_extension_degree(&proof), expected);
+    }
+}
+
+#[test]
+fn verify_with_public_inputs() {
+    let (program_info, stack_inputs, stack_outputs, proof) =
+        prove_program("begin push.1 push.2 add end", &[3, 4]);
+
+    let pub_inputs =
+        PublicInputs::new(program_info.clone(), stack_inputs.clone(), stack_outputs.clone());
+    let result = miden::verify_with_public_inputs(pub_inputs, proof.clone());
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the proof is rejected when the public inputs do not match the execution
+    let mut stack_outputs = stack_outputs;
+    stack_outputs.stack_mut()[0] += 1;
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    assert!(miden::verify_with_public_inputs(pub_inputs, proof).is_err());
+}
+
+#[test]
+fn verify_ints() {
+    let (program_info, _, stack_outputs, proof) = prove_program("begin sub end", &[5, 3]);
+    let program_hash = *program_info.program_hash();
+
+    // stack inputs are provided in push order, and stack outputs in pop order
+    assert_eq!(&stack_outputs.stack()[..2], &[2, 0]);
+    let result = miden::verify_ints(program_hash, &[5, 3], &[2], proof.clone());
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the proof is rejected when the inputs are provided in the reverse order
+    assert!(miden::verify_ints(program_hash, &[3, 5], &[2], proof.clone()).is_err());
+
+    // values which are not valid field elements are rejected before verifying the proof
+    let err = miden::verify_ints(program_hash, &[5, u64::MAX], &[2], proof.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        VerificationError::InputNotFieldElement(u64::MAX).with_program_hash(program_hash)
+    );
+    let err = miden::verify_ints(program_hash, &[5, 3], &[u64::MAX], proof).unwrap_err();
+    assert_eq!(
+        err,
+        VerificationError::OutputNotFieldElement(u64::MAX).with_program_hash(program_hash)
+    );
+}
+
+#[test]
+fn verify_continuation() {
+    let segment = |source: &str, stack_inputs: &[u64]| {
+        let (program_info, stack_inputs, stack_outputs, proof) =
+            prove_program(source, stack_inputs);
+        ContinuationSegment {
+            program_info,
+            stack_inputs,
+            stack_outputs,
+            proof,
+        }
+    };
+
+    // the second segment continues from the state the first segment ended in
+    let first = segment("begin push.1 push.2 add end", &[]);
+    let second = segment("begin dup.0 add end", &[3]);
+    assert_eq!(&first.stack_outputs.stack()[..2], &[3, 0]);
+    let result = miden::verify_continuation(&[first.clone(), second]);
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the second segment is valid on its own, but does not continue from the first one
+    let second = segment("begin dup.0 add end", &[4]);
+    let result = miden::verify_continuation(std::slice::from_ref(&second));
+    assert!(result.is_ok(), "error: {result:?}");
+    let err = miden::verify_continuation(&[first, second]).unwrap_err();
+    assert_eq!(err, VerificationError::ContinuationLinkMismatch(1));
+}
+
+#[test]
+fn verify_empty_program() {
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_program("begin end", &[]);
+
+    // the program does not change the stack
+    assert!(stack_outputs.stack().iter().all(|&v| v == 0));
+
+    // the trace is padded to the minimum length the AIR can handle
+    assert_eq!(miden::proof_info(&proof).trace_len, 64);
+
+    let result = miden::verify(program_info, stack_inputs, stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn verify_timed() {
+    let (program_info, stack_inputs, stack_outputs, proof) =
+        prove_program("begin push.1 push.2 add end", &[]);
+
+    let proof_size = proof.to_bytes().len();
+    let security_level = proof.security_level();
+    let stats = miden::verify_timed(program_info, stack_inputs, stack_outputs, proof).unwrap();
+    assert_eq!(stats.security_level, security_level);
+    assert_eq!(stats.proof_size, proof_size);
+    assert!(stats.elapsed > std::time::Duration::ZERO);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Compiles the provided source and generates a proof of its execution against the specified
+/// stack inputs using default proving options.
+fn prove_program(
+    source: &str,
+    stack_inputs: &[u64],
+) -> (ProgramInfo, StackInputs, StackOutputs, ExecutionProof) {
+    let test = build_test!(source, stack_inputs);
+    let program = test.compile();
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let (stack_outputs, proof) =
+        miden::prove(&program, test.stack_inputs.clone(), host, ProvingOptions::default()).unwrap();
+
+    (ProgramInfo::from(program), test.stack_inputs, stack_outputs, proof)
+}
+
+/// Compiles the provided source and generates a proof of its execution using the specified
+/// proving options.
+fn prove_program_with_options(source: &str, options: ProvingOptions) -> ExecutionProof {
+    let test = build_test!(source);
+    let program = test.compile();
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let (_, proof) = miden::prove(&program, test.stack_inputs, host, options).unwrap();
+
+    proof
+}
@@ -33,7 +33,7 @@ const INIT_TRACE_CAPACITY: usize = 128;
 /// ├─────┴─────┴─────┴───────┴──────┴──────┴──────┴──────┴──────┴──────┴──────┴─────┴─────┤
 ///
 /// In the above, the meaning of the columns is as follows:
-/// - Selector column s is used to specify the bitwise operator for each row.
+/// - Selector column s is used to specify the bitwise operator for each row (see [BitwiseOp]).
 /// - Columns `a` and `b` contain accumulated 4-bit limbs of input values. Specifically, at the
 ///   first row, the values of columns `a` and `b` are set to the most significant 4-bit limb
 ///   of each input value. With all subsequent rows, the next most significant limb is appended
@@ -77,74 +77,26 @@ impl Bitwise {
     // TRACE MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Computes a bitwise AND of `a` and `b` and returns the result. We assume that `a` and `b`
-    /// are 32-bit values. If that's not the case, the result of the computation is undefined.
+    /// Computes a bitwise AND of `a` and `b` and returns the result.
     ///
     /// This also adds 8 rows to the internal execution trace table required for computing the
     /// operation.
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value.
     pub fn u32and(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let a = assert_u32(a)?.as_int();
-        let b = assert_u32(b)?.as_int();
-        let mut result = 0u64;
-
-        // append 8 rows to the trace, each row computing bitwise AND in 4 bit limbs starting with
-        // the most significant limb.
-        for bit_offset in (0..32).step_by(4).rev() {
-            // append the previous row's result to the column for previous output values
-            self.trace[PREV_OUTPUT_COL_IDX].push(Felt::new(result));
-            // shift a and b so that the next 4-bit limb is in the least significant position
-            let a = a >> bit_offset;
-            let b = b >> bit_offset;
-
-            // add a new row to the trace table and populate it with binary decomposition of the 4
-            // least significant bits of a and b.
-            self.add_bitwise_trace_row(BITWISE_AND, a, b);
-
-            // compute bitwise AND of the 4 least significant bits of a and b
-            let result_4_bit = (a & b) & 0xf;
-
-            // append the 4 bit result to the result accumulator, and save the current result into
-            // the output column in the trace.
-            result = (result << 4) | result_4_bit;
-            self.trace[OUTPUT_COL_IDX].push(Felt::new(result));
-        }
-
-        Ok(Felt::new(result))
+        self.execute_u32_op(BitwiseOp::And, a, b)
     }
 
-    /// Computes a bitwise XOR of `a` and `b` and returns the result. We assume that `a` and `b`
-    /// are 32-bit values. If that's not the case, the result of the computation is undefined.
+    /// Computes a bitwise XOR of `a` and `b` and returns the result.
     ///
     /// This also adds 8 rows to the internal execution trace table required for computing the
     /// operation.
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value.
     pub fn u32xor(&mut self, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
-        let a = assert_u32(a)?.as_int();
-        let b = assert_u32(b)?.as_int();
-        let mut result = 0u64;
-
-        // append 8 rows to the trace, each row computing bitwise XOR in 4 bit limbs starting with
-        // the most significant limb.
-        for bit_offset in (0..32).step_by(4).rev() {
-            // append the previous row's result to the column for previous output values
-            self.trace[PREV_OUTPUT_COL_IDX].push(Felt::new(result));
-            // shift a and b so that the next 4-bit limb is in the least significant position
-            let a = a >> bit_offset;
-            let b = b >> bit_offset;
-
-            // add a new row to the trace table and populate it with binary decomposition of the 4
-            // least significant bits of a and b.
-            self.add_bitwise_trace_row(BITWISE_XOR, a, b);
-
-            // compute bitwise XOR of the 4 least significant bits of a and b
-            let result_4_bit = (a ^ b) & 0xf;
-
-            // append the 4 bit result to the result accumulator, and save the current result into
-            // the output column in the trace.
-            result = (result << 4) | result_4_bit;
-            self.trace[OUTPUT_COL_IDX].push(Felt::new(result));
-        }
-
-        Ok(Felt::new(result))
+        self.execute_u32_op(BitwiseOp::Xor, a, b)
     }
 
     // EXECUTION TRACE GENERATION
@@ -172,16 +124,9 @@ impl Bitwise {
             let z = self.trace[OUTPUT_COL_IDX][row];
 
             // get the operation label.
-            let op_selector: Felt = self.trace[0][row];
-            let label = if op_selector == BITWISE_AND {
-                BITWISE_AND_LABEL
-            } else {
-                assert!(
-                    op_selector == BITWISE_XOR,
-                    "Unrecognized operation selectors in Bitwise chiplet"
-                );
-                BITWISE_XOR_LABEL
-            };
+            let label = BitwiseOp::from_selector(self.trace[0][row])
+                .expect("Unrecognized operation selectors in Bitwise chiplet")
+                .label();
 
             let lookup = BitwiseLookup::new(label, a, b, z);
             chiplets_bus.provide_bitwise_operation(lookup, (bitwise_start_row + row) as u32);
@@ -197,6 +142,44 @@ impl Bitwise {
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Computes the specified bitwise operation on 32-bit values `a` and `b` and returns the
+    /// result.
+    ///
+    /// This also adds 8 rows to the internal execution trace table required for computing the
+    /// operation, with the selector column of each row set to the selector of the operation.
+    ///
+    /// # Errors
+    /// Returns an error if either `a` or `b` is not a 32-bit value.
+    fn execute_u32_op(&mut self, op: BitwiseOp, a: Felt, b: Felt) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a)?.as_int();
+        let b = assert_u32(b)?.as_int();
+        let mut result = 0u64;
+
+        // append 8 rows to the trace, each row computing the bitwise operation in 4 bit limbs
+        // starting with the most significant limb.
+        for bit_offset in (0..32).step_by(4).rev() {
+            // append the previous row's result to the column for previous output values
+            self.trace[PREV_OUTPUT_COL_IDX].push(Felt::new(result));
+            // shift a and b so that the next 4-bit limb is in the least significant position
+            let a = a >> bit_offset;
+            let b = b >> bit_offset;
+
+            // add a new row to the trace table and populate it with binary decomposition of the 4
+            // least significant bits of a and b.
+            self.add_bitwise_trace_row(op.selector(), a, b);
+
+            // apply the operation to the 4 least significant bits of a and b
+            let result_4_bit = op.apply(a, b) & 0xf;
+
+            // append the 4 bit result to the result accumulator, and save the current result into
+            // the output column in the trace.
+            result = (result << 4) | result_4_bit;
+            self.trace[OUTPUT_COL_IDX].push(Felt::new(result));
+        }
+
+        Ok(Felt::new(result))
+    }
+
     /// Appends a new row to the trace table and populates the first 14 columns of trace as follows:
     /// - Column 0 is set to the selector value for the bitwise operation being executed.
     /// - Column 1 is set to the current value of `a`.
@@ -240,6 +223,57 @@ pub fn assert_u32(value: Felt) -> Result<Felt, ExecutionError> {
     }
 }
 
+// BITWISE OPERATIONS
+// ================================================================================================
+
+/// Bitwise operations which are recorded in the execution trace of the bitwise chiplet.
+///
+/// Each operation is identified in the trace by the value of the selector column, which allows
+/// the AIR to enforce the correct operation for each row, and on the chiplets bus by its label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitwiseOp {
+    And,
+    Xor,
+}
+
+impl BitwiseOp {
+    /// Returns the value of the selector column for this operation.
+    pub const fn selector(&self) -> Felt {
+        match self {
+            Self::And => BITWISE_AND,
+            Self::Xor => BITWISE_XOR,
+        }
+    }
+
+    /// Returns the label identifying this operation on the chiplets bus.
+    pub const fn label(&self) -> Felt {
+        match self {
+            Self::And => BITWISE_AND_LABEL,
+            Self::Xor => BITWISE_XOR_LABEL,
+        }
+    }
+
+    /// Returns the operation identified by the specified selector value, or None if the value
+    /// does not identify any operation.
+    pub fn from_selector(selector: Felt) -> Option<Self> {
+        if selector == BITWISE_AND {
+            Some(Self::And)
+        } else if selector == BITWISE_XOR {
+            Some(Self::Xor)
+        } else {
+            None
+        }
+    }
+
+    /// Applies this operation to `a` and `b`.
+    fn apply(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Self::And => a & b,
+            Self::Xor => a ^ b,
+        }
+    }
+}
+
 // BITWISE LOOKUPS
 // ================================================================================================
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
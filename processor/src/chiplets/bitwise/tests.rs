@@ -1,8 +1,9 @@
 use super::{
     super::aux_trace::{ChipletLookup, ChipletsBusRow},
-    Bitwise, BitwiseLookup, ChipletsBus, Felt, StarkField, TraceFragment, Vec, A_COL_IDX,
-    A_COL_RANGE, BITWISE_AND, BITWISE_AND_LABEL, BITWISE_XOR, BITWISE_XOR_LABEL, B_COL_IDX,
-    B_COL_RANGE, OP_CYCLE_LEN, OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
+    Bitwise, BitwiseLookup, BitwiseOp, ChipletsBus, ExecutionError, Felt, StarkField,
+    TraceFragment, Vec, A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_AND_LABEL, BITWISE_XOR,
+    BITWISE_XOR_LABEL, B_COL_IDX, B_COL_RANGE, OP_CYCLE_LEN, OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX,
+    TRACE_WIDTH,
 };
 use test_utils::rand::rand_value;
 use vm_core::ZERO;
@@ -204,6 +205,62 @@ fn bitwise_multiple() {
     verify_bus(&chiplets_bus, 2, (OP_CYCLE_LEN * 3 - 1) as u32, &bitwise_lookup);
 }
 
+#[test]
+fn bitwise_op_selectors() {
+    let mut bitwise = Bitwise::new();
+
+    // execute a sequence mixing AND and XOR operations
+    bitwise.u32xor(rand_u32(), rand_u32()).unwrap();
+    bitwise.u32and(rand_u32(), rand_u32()).unwrap();
+    bitwise.u32xor(rand_u32(), rand_u32()).unwrap();
+    bitwise.u32and(rand_u32(), rand_u32()).unwrap();
+    let ops = [BitwiseOp::Xor, BitwiseOp::And, BitwiseOp::Xor, BitwiseOp::And];
+
+    // all rows of each operation cycle contain the selector of the executed operation
+    let num_rows = ops.len() * OP_CYCLE_LEN;
+    let (trace, chiplets_bus) = build_trace(bitwise, num_rows);
+    for (i, op) in ops.iter().enumerate() {
+        let rows = i * OP_CYCLE_LEN..(i + 1) * OP_CYCLE_LEN;
+        assert!(trace[0][rows].iter().all(|&selector| selector == op.selector()));
+    }
+    assert_eq!(
+        [BITWISE_XOR, BITWISE_AND, BITWISE_XOR, BITWISE_AND],
+        ops.map(|op| op.selector())
+    );
+
+    // the selector values identify the operations, and their labels are sent to the bus
+    for (i, op) in ops.iter().enumerate() {
+        let row = (i + 1) * OP_CYCLE_LEN - 1;
+        assert_eq!(Some(*op), BitwiseOp::from_selector(trace[0][row]));
+
+        let lookup = BitwiseLookup::new(
+            op.label(),
+            trace[A_COL_IDX][row],
+            trace[B_COL_IDX][row],
+            trace[OUTPUT_COL_IDX][row],
+        );
+        verify_bus(&chiplets_bus, i, row as u32, &lookup);
+    }
+    assert_eq!(None, BitwiseOp::from_selector(Felt::new(2)));
+}
+
+#[test]
+fn bitwise_non_u32_inputs() {
+    let mut bitwise = Bitwise::new();
+
+    let a = rand_u32();
+    let not_u32 = Felt::new(1 << 32);
+
+    let err = bitwise.u32and(not_u32, a).unwrap_err();
+    assert!(matches!(err, ExecutionError::NotU32Value(value, _) if value == not_u32));
+
+    let err = bitwise.u32xor(a, not_u32).unwrap_err();
+    assert!(matches!(err, ExecutionError::NotU32Value(value, _) if value == not_u32));
+
+    // make sure no rows were added to the trace
+    assert_eq!(0, bitwise.trace_len());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -29,10 +29,12 @@ pub enum ExecutionError {
     InvalidFriDomainSegment(u64),
     InvalidFriLayerFolding(QuadFelt, QuadFelt),
     InvalidMemoryRange { start_addr: u64, end_addr: u64 },
+    InvalidPermutation(Felt),
     InvalidStackDepthOnReturn(usize),
     InvalidStackWordOffset(usize),
     InvalidTreeDepth { depth: Felt },
     InvalidTreeNodeIndex { depth: Felt, value: Felt },
+    LogArgumentZero(u32),
     MemoryAddressOutOfBounds(u64),
     MerkleStoreMergeFailed(MerkleError),
     MerkleStoreLookupFailed(MerkleError),
@@ -103,6 +105,9 @@ impl Display for ExecutionError {
             } => {
                 write!(f, "Memory range start address cannot exceed end address, but was ({start_addr}, {end_addr})")
             }
+            InvalidPermutation(value) => {
+                write!(f, "The provided values do not form a permutation: value {value} is out of bounds or repeated")
+            }
             InvalidStackDepthOnReturn(depth) => {
                 write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
             }
@@ -115,6 +120,9 @@ impl Display for ExecutionError {
             InvalidTreeNodeIndex { depth, value } => {
                 write!(f, "The provided index {value} is out of bounds for a node at depth {depth}")
             }
+            LogArgumentZero(clk) => {
+                write!(f, "Logarithm of zero is undefined (clock cycle {clk})")
+            }
             MemoryAddressOutOfBounds(addr) => {
                 write!(f, "Memory address cannot exceed 2^32 but was {addr}")
             }
@@ -10,7 +10,8 @@ use vm_core::{
 // ================================================================================================
 
 /// Reads words from memory at the specified range and inserts them into the advice map under
-/// the key `KEY` located at the top of the stack.
+/// the key `KEY` located at the top of the stack. If `include_len` is set to true, the number of
+/// elements read from memory is prepended to the stored values.
 ///
 /// Inputs:
 ///   Operand stack: [KEY, start_addr, end_addr, ...]
@@ -18,9 +19,10 @@ use vm_core::{
 ///
 /// Outputs:
 ///   Operand stack: [KEY, start_addr, end_addr, ...]
-///   Advice map: {KEY: values}
+///   Advice map: {KEY: [values_len?, values]}
 ///
-/// Where `values` are the elements located in memory[start_addr..end_addr].
+/// Where `values` are the elements located in memory[start_addr..end_addr], and `values_len` is
+/// equal to 4 * (end_addr - start_addr).
 ///
 /// # Errors
 /// Returns an error:
@@ -30,11 +32,16 @@ use vm_core::{
 pub(crate) fn insert_mem_values_into_adv_map<S: ProcessState, A: AdviceProvider>(
     advice_provider: &mut A,
     process: &S,
+    include_len: bool,
 ) -> Result<HostResponse, ExecutionError> {
     let (start_addr, end_addr) = get_mem_addr_range(process, 4, 5)?;
     let ctx = process.ctx();
 
-    let mut values = Vec::with_capacity(((end_addr - start_addr) as usize) * WORD_SIZE);
+    let values_len = ((end_addr - start_addr) as usize) * WORD_SIZE;
+    let mut values = Vec::with_capacity(values_len + include_len as usize);
+    if include_len {
+        values.push(Felt::from(values_len as u32));
+    }
     for addr in start_addr..end_addr {
         let mem_value = process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD);
         values.extend_from_slice(&mem_value);
@@ -1,6 +1,9 @@
 use super::super::{AdviceSource, ExecutionError, Felt, HostResponse, StarkField};
 use crate::{AdviceProvider, Ext2InttError, FieldElement, ProcessState, Vec};
-use vm_core::{QuadExtension, SignatureKind};
+use vm_core::{
+    crypto::random::{RandomCoin, RpoRandomCoin},
+    AdviceInjector, QuadExtension, SignatureKind, Word, EMPTY_WORD, ONE, ZERO,
+};
 use winter_prover::math::fft;
 
 // TYPE ALIASES
@@ -56,9 +59,62 @@ pub(crate) fn copy_merkle_node_to_adv_stack<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the authentication path of the Merkle tree node specified by the values on the top of
+/// the operand stack onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [depth, index, TREE_ROOT, ...]
+///   Advice stack: [...]
+///   Merkle store: {TREE_ROOT<-NODE}
+///
+/// Outputs:
+///   Operand stack: [depth, index, TREE_ROOT, ...]
+///   Advice stack: [S_0, S_1, ..., S_{depth-1}, ...]
+///   Merkle store: {TREE_ROOT<-NODE}
+///
+/// Where S_0 is the sibling of the node and S_{depth-1} is a child of the root. Each sibling
+/// word is pushed in the same way as the node pushed by `copy_merkle_node_to_adv_stack()`.
+///
+/// # Errors
+/// Returns an error if:
+/// - Merkle tree for the specified root cannot be found in the advice provider.
+/// - The specified depth is either zero or greater than the depth of the Merkle tree
+///   identified by the specified root.
+/// - The path to the node at the specified depth and index is not known to the advice provider.
+pub(crate) fn copy_merkle_path_to_adv_stack<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    // read node depth, node index, and tree root from the stack
+    let depth = process.get_stack_item(0);
+    let index = process.get_stack_item(1);
+    let root = [
+        process.get_stack_item(5),
+        process.get_stack_item(4),
+        process.get_stack_item(3),
+        process.get_stack_item(2),
+    ];
+
+    // look up the path in the advice provider
+    let path = advice_provider.get_merkle_path(root, &depth, &index)?;
+
+    // push the path onto the advice stack starting with the sibling closest to the root, so that
+    // the sibling of the node ends up at the top of the advice stack
+    for sibling in path.iter().rev() {
+        let sibling = Word::from(sibling);
+        advice_provider.push_stack(AdviceSource::Value(sibling[3]))?;
+        advice_provider.push_stack(AdviceSource::Value(sibling[2]))?;
+        advice_provider.push_stack(AdviceSource::Value(sibling[1]))?;
+        advice_provider.push_stack(AdviceSource::Value(sibling[0]))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
 /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
 /// map using the specified word from the operand stack as the key. If `include_len` is set to
-/// true, the number of elements in the value is also pushed onto the advice stack.
+/// true, the number of elements in the value is also pushed onto the advice stack. If `consume`
+/// is set to true, the entry is removed from the advice map once its values have been pushed.
 ///
 /// Inputs:
 ///   Operand stack: [..., KEY, ...]
@@ -68,7 +124,7 @@ pub(crate) fn copy_merkle_node_to_adv_stack<S: ProcessState, A: AdviceProvider>(
 /// Outputs:
 ///   Operand stack: [..., KEY, ...]
 ///   Advice stack: [values_len?, values, ...]
-///   Advice map: {KEY: values}
+///   Advice map: {KEY: values} if `consume` is false, {} otherwise
 ///
 /// The `key_offset` value specifies the location of the `KEY` on the stack. For example,
 /// offset value of 0 indicates that the top word on the stack should be used as the key, the
@@ -77,6 +133,9 @@ pub(crate) fn copy_merkle_node_to_adv_stack<S: ProcessState, A: AdviceProvider>(
 ///
 /// The valid values of `key_offset` are 0 through 12 (inclusive).
 ///
+/// A consumed entry cannot be replayed: subsequent lookups of the same key fail with an error
+/// unless the entry is re-inserted into the advice map.
+///
 /// # Errors
 /// Returns an error if the required key was not found in the key-value map or if stack offset
 /// is greater than 12.
@@ -85,6 +144,7 @@ pub(crate) fn copy_map_value_to_adv_stack<S: ProcessState, A: AdviceProvider>(
     process: &S,
     include_len: bool,
     key_offset: usize,
+    consume: bool,
 ) -> Result<HostResponse, ExecutionError> {
     if key_offset > 12 {
         return Err(ExecutionError::InvalidStackWordOffset(key_offset));
@@ -97,6 +157,41 @@ pub(crate) fn copy_map_value_to_adv_stack<S: ProcessState, A: AdviceProvider>(
         process.get_stack_item(key_offset),
     ];
     advice_provider.push_stack(AdviceSource::Map { key, include_len })?;
+    if consume {
+        advice_provider.remove_from_map(key);
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the preimage of the RPO digest at the top of the operand stack onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [DIGEST, ...]
+///   Advice stack: [...]
+///   Advice map: {DIGEST: values}
+///
+/// Outputs:
+///   Operand stack: [DIGEST, ...]
+///   Advice stack: [values, ...]
+///   Advice map: {DIGEST: values}
+///
+/// The preimage must have been registered in the advice map under its digest beforehand. It
+/// is not checked that the values actually hash to the digest; the program is expected to
+/// re-hash the values and compare the result against the digest.
+///
+/// # Errors
+/// Returns an error if no preimage is registered in the advice map under the digest.
+pub(crate) fn copy_rpo_preimage_to_adv_stack<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let digest = process.get_stack_word(0);
+    advice_provider.push_stack(AdviceSource::Map {
+        key: digest,
+        include_len: false,
+    })?;
 
     Ok(HostResponse::None)
 }
@@ -149,6 +244,598 @@ pub(crate) fn push_u64_div_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the result of [u64] multiplication (all four 32-bit limbs of the 128-bit product)
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [b1, b0, a1, a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [b1, b0, a1, a0, ...]
+///   Advice stack: [c0, c1, c2, c3, ...]
+///
+/// Where (a0, a1) and (b0, b1) are the 32-bit limbs of the multiplicands (with a0 representing the
+/// 32 least significant bits and a1 representing the 32 most significant bits), and (c0, c1, c2,
+/// c3) are the 32-bit limbs of the product c = a * b ordered from the least significant to the most
+/// significant. Same as with `DivU64`, the least significant limb ends up at the top of the advice
+/// stack.
+///
+/// # Errors
+/// Returns an error if any of the limbs is not a valid u32 value.
+pub(crate) fn push_u64_mul_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let b_hi = get_u32_stack_item(process, 0)?;
+    let b_lo = get_u32_stack_item(process, 1)?;
+    let a_hi = get_u32_stack_item(process, 2)?;
+    let a_lo = get_u32_stack_item(process, 3)?;
+
+    let a = ((a_hi << 32) + a_lo) as u128;
+    let b = ((b_hi << 32) + b_lo) as u128;
+    let product = a * b;
+
+    let (c3, c2) = u64_to_u32_elements((product >> 64) as u64);
+    let (c1, c0) = u64_to_u32_elements(product as u64);
+
+    advice_provider.push_stack(AdviceSource::Value(c3))?;
+    advice_provider.push_stack(AdviceSource::Value(c2))?;
+    advice_provider.push_stack(AdviceSource::Value(c1))?;
+    advice_provider.push_stack(AdviceSource::Value(c0))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the result of reducing a [u64] value modulo `modulus` (both the quotient and the
+/// remainder) onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [a1, a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a1, a0, ...]
+///   Advice stack: [q0, q1, r0, r1, ...]
+///
+/// Where (a0, a1) are the 32-bit limbs of the value a (with a0 representing the 32 least
+/// significant bits and a1 representing the 32 most significant bits), and (q0, q1) and (r0, r1)
+/// are the limbs of the quotient q = a / modulus and the remainder r = a % modulus respectively.
+///
+/// # Errors
+/// Returns an error if:
+/// - `modulus` is zero.
+/// - Any of the limbs of a is not a valid u32 value.
+pub(crate) fn push_mod_reduce_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    modulus: u64,
+) -> Result<HostResponse, ExecutionError> {
+    if modulus == 0 {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+
+    let a_hi = get_u32_stack_item(process, 0)?;
+    let a_lo = get_u32_stack_item(process, 1)?;
+    let a = (a_hi << 32) + a_lo;
+
+    let (q_hi, q_lo) = u64_to_u32_elements(a / modulus);
+    let (r_hi, r_lo) = u64_to_u32_elements(a % modulus);
+
+    advice_provider.push_stack(AdviceSource::Value(r_hi))?;
+    advice_provider.push_stack(AdviceSource::Value(r_lo))?;
+    advice_provider.push_stack(AdviceSource::Value(q_hi))?;
+    advice_provider.push_stack(AdviceSource::Value(q_lo))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the Barrett reduction constant for the specified modulus onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [...]
+///   Advice stack: [mu, ...]
+///
+/// Where mu = floor(2^k / modulus). Since `k` is at most 63, mu is smaller than 2^63 and thus is a
+/// valid field element.
+///
+/// # Errors
+/// Returns an error if `modulus` is zero.
+pub(crate) fn push_barrett_mu<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    modulus: u64,
+    k: u8,
+) -> Result<HostResponse, ExecutionError> {
+    debug_assert!(k <= AdviceInjector::MAX_BARRETT_K);
+
+    if modulus == 0 {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+
+    let mu = (1_u64 << k) / modulus;
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(mu)))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the integer square root of the u32 value at the top of the operand stack onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [n, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [n, ...]
+///   Advice stack: [r, ...]
+///
+/// Where r is the largest integer such that r * r <= n.
+///
+/// # Errors
+/// Returns an error if n is not a u32 value.
+pub(crate) fn push_u32_sqrt_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let n = process.get_stack_item(0);
+    if n.as_int() > u32::MAX as u64 {
+        return Err(ExecutionError::NotU32Value(n, ZERO));
+    }
+
+    let root = u32_sqrt(n.as_int());
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(root)))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the ceiling of the base 2 logarithm of the element at the top of the operand stack
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [n, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [n, ...]
+///   Advice stack: [ceil(log2(n)), ...]
+///
+/// # Errors
+/// Returns an error if n is ZERO.
+pub(crate) fn push_ilog2_ceil_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let n = process.get_stack_item(0).as_int();
+    if n == 0 {
+        return Err(ExecutionError::LogArgumentZero(process.clk()));
+    }
+
+    let log2_ceil = u64::BITS - (n - 1).leading_zeros();
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(log2_ceil)))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the greatest common divisor of the two u32 values at the top of the operand stack
+/// onto the advice stack, together with the values needed to verify it.
+///
+/// Inputs:
+///   Operand stack: [b, a, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [b, a, ...]
+///   Advice stack: [g, qa, qb, x, y, ...]
+///
+/// Where g = gcd(a, b), a = g * qa, b = g * qb, and x and y are the magnitudes of the Bézout
+/// coefficients of qa and qb, such that qa * x - qb * y = ±1. All pushed values are u32
+/// values.
+///
+/// # Errors
+/// Returns an error if either a or b is not a u32 value.
+pub(crate) fn push_u32_gcd_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let b = get_u32_stack_item(process, 0)?;
+    let a = get_u32_stack_item(process, 1)?;
+
+    let (g, qa, qb, x, y) = u32_gcd_with_witness(a, b);
+
+    // push the values with the last one pushed first so that g ends up at the top of the stack
+    for value in [y, x, qb, qa, g] {
+        advice_provider.push_stack(AdviceSource::Value(Felt::new(value)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes a factorization of the u32 value at the top of the operand stack onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [n, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [n, ...]
+///   Advice stack: [p, q, ...]
+///
+/// Where p * q = n. If n has a nontrivial factorization, p is the smallest prime factor of n
+/// and q = n / p, and thus 1 < p <= q < n. Otherwise (i.e., if n is prime, or if n is 0 or 1),
+/// p is set to 1 and q is set to n. Thus, p = 1 serves as a flag indicating that n is either
+/// prime or smaller than 2, and n can be proven composite on the stack by checking that p > 1.
+///
+/// # Errors
+/// Returns an error if n is not a u32 value.
+pub(crate) fn push_factor_hint<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let n = get_u32_stack_item(process, 0)?;
+
+    // find the smallest factor of n via trial division; since n is a u32 value, at most 2^16
+    // candidates need to be checked
+    let p = (2..).take_while(|&p| p * p <= n).find(|&p| n % p == 0).unwrap_or(1);
+    let q = n / p;
+
+    // push q first so that p ends up at the top of the stack
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(q)))?;
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(p)))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the multiplicative inverse of the element at the top of the operand stack onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [a, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a, ...]
+///   Advice stack: [a^-1, ...]
+///
+/// # Errors
+/// Returns an error if a is ZERO.
+pub(crate) fn push_field_inv_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let a = process.get_stack_item(0);
+    if a == ZERO {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+
+    advice_provider.push_stack(AdviceSource::Value(a.inv()))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the multiplicative order of the element at the top of the operand stack onto the
+/// advice stack.
+///
+/// Inputs:
+///   Operand stack: [a, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a, ...]
+///   Advice stack: [n, ...]
+///
+/// Where n is the smallest positive integer such that a^n = 1 (e.g., the order of 1 is 1).
+/// Since n divides the order of the multiplicative group of the field (p - 1), the claimed
+/// order can be verified on the stack by checking that a^n = 1 and that a^(n/q) != 1 for each
+/// prime factor q of n.
+///
+/// # Errors
+/// Returns an error if a is ZERO.
+pub(crate) fn push_field_order_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let a = process.get_stack_item(0);
+    if a == ZERO {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(field_order(a))))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes a fixed-point approximation of the reciprocal of the u32 value at the top of the
+/// operand stack onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [a, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a, ...]
+///   Advice stack: [r, ...]
+///
+/// Where r = floor(2^frac_bits / a), i.e., the reciprocal of a with `frac_bits` fractional
+/// bits, rounded towards zero. Thus, the result is within one unit in the last place of the
+/// exact reciprocal, which can be verified on the stack by checking that r * a <= 2^frac_bits
+/// and (r + 1) * a > 2^frac_bits. `frac_bits` must be in the range [0, 31], which guarantees
+/// that r is a u32 value.
+///
+/// # Errors
+/// Returns an error if a is ZERO or is not a u32 value.
+pub(crate) fn push_fixed_recip_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    frac_bits: u8,
+) -> Result<HostResponse, ExecutionError> {
+    debug_assert!(frac_bits <= AdviceInjector::MAX_FIXED_RECIP_FRAC_BITS);
+
+    let a = get_u32_stack_item(process, 0)?;
+    if a == 0 {
+        return Err(ExecutionError::DivideByZero(process.clk()));
+    }
+
+    let recip = (1_u64 << frac_bits) / a;
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(recip)))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Reads `count` words from memory starting at the address located at the top of the operand
+/// stack, sorts them, and pushes the sorted words onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [ptr, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ptr, ...]
+///   Advice stack: [W_0, W_1, ..., W_{count-1}, ...]
+///
+/// Where W_0, ..., W_{count-1} are the words located in memory[ptr..ptr + count] sorted
+/// lexicographically in non-decreasing order, with W_0 at the top of the advice stack.
+///
+/// # Errors
+/// Returns an error if ptr + count is greater than 2^32.
+pub(crate) fn push_sorted_words<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    count: u32,
+) -> Result<HostResponse, ExecutionError> {
+    let start_addr = process.get_stack_item(0).as_int();
+    let mut words = read_mem_words(process, start_addr, count as u64)?;
+    words.sort_by_key(word_to_ints);
+
+    // push the words in reverse order so that the smallest word ends up at the top of the stack
+    for word in words.into_iter().rev() {
+        advice_provider.push_stack(AdviceSource::Word(word))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Reads a permutation of `count` indices from memory starting at the address located at the top
+/// of the operand stack, and pushes its inverse permutation onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [ptr, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ptr, ...]
+///   Advice stack: [inv_0, inv_1, ..., inv_{count-1}, ...]
+///
+/// Where perm_i is the first element of the word located at memory[ptr + i], and inv_0, ...,
+/// inv_{count-1} is the inverse permutation, i.e., inv[perm_i] = i for all i, with inv_0 at the
+/// top of the advice stack.
+///
+/// # Errors
+/// Returns an error if:
+/// - ptr + count is greater than 2^32.
+/// - perm_0, ..., perm_{count-1} is not a permutation of 0..count.
+pub(crate) fn push_inverse_permutation<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    count: u32,
+) -> Result<HostResponse, ExecutionError> {
+    let start_addr = process.get_stack_item(0).as_int();
+    let words = read_mem_words(process, start_addr, count as u64)?;
+
+    // invert the permutation, making sure that every index is in bounds and appears only once
+    let mut inverse = vec![None; words.len()];
+    for (i, word) in words.iter().enumerate() {
+        let slot = usize::try_from(word[0].as_int())
+            .ok()
+            .and_then(|index| inverse.get_mut(index))
+            .filter(|slot| slot.is_none())
+            .ok_or(ExecutionError::InvalidPermutation(word[0]))?;
+        *slot = Some(i as u64);
+    }
+
+    // push the inverse in reverse order so that inv_0 ends up at the top of the stack
+    for &value in inverse.iter().rev().flatten() {
+        advice_provider.push_stack(AdviceSource::Value(Felt::new(value)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Searches a sorted region of memory for the word located at the top of the operand stack, and
+/// pushes the result of the search onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [T, ptr, len, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [T, ptr, len, ...]
+///   Advice stack: [found, i, ...]
+///
+/// Where memory[ptr..ptr + len] is expected to contain words sorted in non-decreasing order (words
+/// are compared the same way as in `SortWords`). If T is present in the region, found is set to 1
+/// and i is the offset of the first occurrence of T, i.e., memory[ptr + i] = T. Otherwise, found is
+/// set to 0 and i is the offset at which T would have to be inserted to keep the region sorted
+/// (this is 0 if T is smaller than all words in the region, and len if T is larger than all of them).
+///
+/// The result is unspecified if the region is not sorted; callers are expected to verify it by
+/// checking that memory[ptr + i - 1] < T <= memory[ptr + i] for the words which are in the region.
+///
+/// # Errors
+/// Returns an error if ptr + len is greater than 2^32.
+pub(crate) fn push_binary_search_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let target = word_to_ints(&process.get_stack_word(0));
+    let start_addr = process.get_stack_item(4).as_int();
+    let len = process.get_stack_item(5).as_int();
+    get_mem_region_end(start_addr, len)?;
+
+    // only the words visited by the search are read from memory
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if word_to_ints(&read_mem_word(process, start_addr + mid)) < target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    let found = lo < len && word_to_ints(&read_mem_word(process, start_addr + lo)) == target;
+
+    advice_provider.push_stack(AdviceSource::Value(Felt::new(lo)))?;
+    advice_provider.push_stack(AdviceSource::Value(if found { ONE } else { ZERO }))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes the keys currently present in the advice map onto the advice stack, sorted in
+/// non-decreasing order, followed by the number of keys.
+///
+/// Inputs:
+///   Operand stack: [...]
+///   Advice stack: [...]
+///   Advice map: {K_0: values_0, ..., K_{n-1}: values_{n-1}}
+///
+/// Outputs:
+///   Operand stack: [...]
+///   Advice stack: [n, K_0, K_1, ..., K_{n-1}, ...]
+///   Advice map: {K_0: values_0, ..., K_{n-1}: values_{n-1}}
+///
+/// Where n is the number of keys in the advice map, and K_0, ..., K_{n-1} are the keys sorted
+/// lexicographically by the integer values of their elements (the same way as in
+/// `SortWords`), with K_0 located right below n. Thus, 4 * n + 1 elements are pushed onto the
+/// advice stack.
+pub(crate) fn push_map_keys_sorted<A: AdviceProvider>(
+    advice_provider: &mut A,
+) -> Result<HostResponse, ExecutionError> {
+    let mut keys = advice_provider.get_map_keys();
+    keys.sort_by_key(word_to_ints);
+    let num_keys = keys.len();
+
+    // push the keys in reverse order so that the smallest key ends up right below the key count
+    for key in keys.into_iter().rev() {
+        advice_provider.push_stack(AdviceSource::Word(key))?;
+    }
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(num_keys as u64)))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Draws `count` pseudo-random field elements from a random coin seeded with the word located
+/// at the top of the operand stack, and pushes them onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [SEED, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [SEED, ...]
+///   Advice stack: [r_0, r_1, ..., r_{count - 1}, ...]
+///
+/// Where r_i is the i-th element drawn from an `RpoRandomCoin` instantiated with SEED, i.e.,
+/// the same coin the prover uses to build its Fiat-Shamir transcript. Thus, r_0 is the first
+/// element drawn, and it is located at the top of the advice stack.
+///
+/// The drawn values are fully determined by SEED: the same seed always produces the same
+/// sequence of elements, and a larger `count` extends the sequence produced for a smaller one.
+pub(crate) fn push_random_values<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    count: u32,
+) -> Result<HostResponse, ExecutionError> {
+    let seed = process.get_stack_word(0);
+    let mut coin = RpoRandomCoin::new(&seed);
+
+    let mut values = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let value: Felt = coin.draw().expect("drawing from an RPO random coin never fails");
+        values.push(value);
+    }
+
+    // push the values in reverse order so that the first drawn value ends up at the top
+    for value in values.into_iter().rev() {
+        advice_provider.push_stack(AdviceSource::Value(value))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Computes the CRC32 checksum of a byte string located in memory using the specified
+/// polynomial, and pushes it onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [ptr, len, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ptr, len, ...]
+///   Advice stack: [crc, ...]
+///
+/// Where the byte string consists of the first `len` bytes of memory[ptr..], with every element
+/// of a word packing 4 bytes in little-endian order. `poly` is given in the normal (MSB-first)
+/// representation, and the checksum is computed the same way as for the standard CRC-32 variants
+/// (reflected input and output, initial value and final XOR of 0xFFFFFFFF).
+///
+/// # Errors
+/// Returns an error if:
+/// - len is not a u32 value.
+/// - The memory range occupied by the byte string is out of bounds.
+/// - Any of the elements holding the byte string is not a u32 value.
+pub(crate) fn push_crc32<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    poly: u32,
+) -> Result<HostResponse, ExecutionError> {
+    let start_addr = process.get_stack_item(0).as_int();
+    let len = get_u32_stack_item(process, 1)?;
+
+    // every word holds 16 bytes of the string; the bytes beyond `len` in the last word are ignored
+    let end_addr = get_mem_region_end(start_addr, len.div_ceil(16))?;
+    let mut remaining = len as usize;
+    let mut crc = u32::MAX;
+    for addr in start_addr..end_addr {
+        for value in read_mem_word(process, addr) {
+            let value = u32::try_from(value.as_int())
+                .map_err(|_| ExecutionError::NotU32Value(value, ZERO))?;
+            let bytes = value.to_le_bytes();
+            let num_bytes = remaining.min(bytes.len());
+            crc = crc32_update(poly, crc, &bytes[..num_bytes]);
+            remaining -= num_bytes;
+        }
+    }
+
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(!crc)))?;
+
+    Ok(HostResponse::None)
+}
+
 /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
 /// computes its multiplicative inverse and push the result onto the advice stack.
 ///
@@ -184,6 +871,45 @@ pub(crate) fn push_ext2_inv_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Given an element in a quadratic extension field on the top of the stack (i.e., a0, a1),
+/// computes its square root and pushes the result onto the advice stack together with a flag
+/// indicating whether the square root exists.
+///
+/// Inputs:
+///   Operand stack: [a1, a0, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [a1, a0, ...]
+///   Advice stack: [flag, r0, r1, ...]
+///
+/// If (a0, a1) is a quadratic residue, flag is set to 1 and (r0, r1) is an extension field element
+/// such that (r0, r1)^2 = (a0, a1). Either of the two square roots may be returned; it is up to the
+/// caller to verify the result and to choose between r and -r if needed.
+///
+/// If (a0, a1) is a quadratic non-residue, flag is set to 0 and both r0 and r1 are set to 0. The
+/// advice stack layout is the same in both cases, so that the caller can always read 3 elements
+/// from the advice stack.
+pub(crate) fn push_ext2_sqrt_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let coef0 = process.get_stack_item(1);
+    let coef1 = process.get_stack_item(0);
+
+    let element = QuadFelt::new(coef0, coef1);
+    let (flag, root) = match ext2_sqrt(element) {
+        Some(root) => (ONE, root.to_base_elements()),
+        None => (ZERO, [ZERO, ZERO]),
+    };
+
+    advice_provider.push_stack(AdviceSource::Value(root[1]))?;
+    advice_provider.push_stack(AdviceSource::Value(root[0]))?;
+    advice_provider.push_stack(AdviceSource::Value(flag))?;
+
+    Ok(HostResponse::None)
+}
+
 /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
 ///  into a polynomial in coefficient form and pushes the result into the advice stack.
 ///
@@ -299,8 +1025,204 @@ pub(crate) fn push_signature<S: ProcessState, A: AdviceProvider>(
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the stack item at the specified position as a u32 value.
+///
+/// # Errors
+/// Returns an error if the stack item is not a valid u32 value.
+fn get_u32_stack_item<S: ProcessState>(process: &S, pos: usize) -> Result<u64, ExecutionError> {
+    let value = process.get_stack_item(pos);
+    if value.as_int() > u32::MAX as u64 {
+        return Err(ExecutionError::NotU32Value(value, ZERO));
+    }
+    Ok(value.as_int())
+}
+
+/// Returns the address following the last word of the memory region which starts at
+/// `start_addr` and contains `count` words.
+///
+/// # Errors
+/// Returns an error if `start_addr + count` is greater than 2^32.
+fn get_mem_region_end(start_addr: u64, count: u64) -> Result<u64, ExecutionError> {
+    match start_addr.checked_add(count) {
+        Some(end_addr) if end_addr <= u32::MAX as u64 + 1 => Ok(end_addr),
+        end_addr => Err(ExecutionError::MemoryAddressOutOfBounds(end_addr.unwrap_or(start_addr))),
+    }
+}
+
+/// Reads the word at the specified address from memory of the current context.
+///
+/// The address is expected to be smaller than 2^32.
+fn read_mem_word<S: ProcessState>(process: &S, addr: u64) -> Word {
+    debug_assert!(addr <= u32::MAX as u64, "memory address out of bounds");
+    process.get_mem_value(process.ctx(), addr as u32).unwrap_or(EMPTY_WORD)
+}
+
+/// Reads `count` words from memory of the current context starting at `start_addr`.
+///
+/// # Errors
+/// Returns an error if `start_addr + count` is greater than 2^32.
+fn read_mem_words<S: ProcessState>(
+    process: &S,
+    start_addr: u64,
+    count: u64,
+) -> Result<Vec<Word>, ExecutionError> {
+    let end_addr = get_mem_region_end(start_addr, count)?;
+    Ok((start_addr..end_addr).map(|addr| read_mem_word(process, addr)).collect())
+}
+
+/// Updates the CRC32 register `crc` with `bytes` using the polynomial `poly` (given in the normal
+/// representation), processing the bits of each byte starting with the least significant one.
+///
+/// The register is expected to start at 0xFFFFFFFF, and the checksum is the bitwise complement of
+/// the register once all bytes have been processed.
+fn crc32_update(poly: u32, mut crc: u32, bytes: &[u8]) -> u32 {
+    let poly = poly.reverse_bits();
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ poly } else { crc >> 1 };
+        }
+    }
+    crc
+}
+
+/// Returns the integer values of the word elements; words are compared lexicographically by
+/// these values.
+fn word_to_ints(word: &Word) -> [u64; 4] {
+    word.map(|value| value.as_int())
+}
+
 fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     let hi = Felt::new(value >> 32);
     let lo = Felt::new((value as u32) as u64);
     (hi, lo)
 }
+
+/// Computes the largest integer r such that r * r <= n for a u32 value n.
+fn u32_sqrt(n: u64) -> u64 {
+    let mut root = 0;
+    for bit in (0..16).rev() {
+        let candidate = root | (1 << bit);
+        if candidate * candidate <= n {
+            root = candidate;
+        }
+    }
+    root
+}
+
+/// Computes the multiplicative order of a non-zero field element `a`.
+///
+/// The order is computed by starting with the order of the multiplicative group of the field
+/// (p - 1 = 2^32 * 3 * 5 * 17 * 257 * 65537) and dividing out each prime factor q for as long as
+/// a^(n/q) = 1.
+fn field_order(a: Felt) -> u64 {
+    debug_assert_ne!(a, ZERO, "zero has no multiplicative order");
+
+    const GROUP_ORDER_FACTORS: [u64; 6] = [2, 3, 5, 17, 257, 65537];
+    let mut order = Felt::MODULUS - 1;
+    for factor in GROUP_ORDER_FACTORS {
+        while order % factor == 0 && a.exp(order / factor) == ONE {
+            order /= factor;
+        }
+    }
+    order
+}
+
+/// Computes g = gcd(a, b) for u32 values a and b, and returns (g, qa, qb, x, y) such that
+/// a = g * qa, b = g * qb, and qa * x - qb * y = ±1.
+///
+/// When both a and b are zero, g is zero and (qa, qb, x, y) = (1, 0, 1, 0).
+fn u32_gcd_with_witness(a: u64, b: u64) -> (u64, u64, u64, u64, u64) {
+    if a == 0 && b == 0 {
+        return (0, 1, 0, 1, 0);
+    }
+
+    // run the extended Euclidean algorithm maintaining r_i = a * s_i + b * t_i
+    let (mut r0, mut r1) = (a as i64, b as i64);
+    let (mut s0, mut s1) = (1_i64, 0_i64);
+    let (mut t0, mut t1) = (0_i64, 1_i64);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (s0, s1) = (s1, s0 - q * s1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+
+    // a * s + b * t = g implies qa * s + qb * t = 1, where s and t have opposite signs (or one
+    // of them is zero); thus, qa * |s| - qb * |t| = ±1
+    let g = r0 as u64;
+    (g, a / g, b / g, s0.unsigned_abs(), t0.unsigned_abs())
+}
+
+/// Computes a square root of the specified element in the quadratic extension field, or returns
+/// None if the element is a quadratic non-residue.
+///
+/// The extension field is defined by the irreducible polynomial x^2 - x + 2, and thus, for
+/// w = 2x - 1 we have w^2 = -7. Writing a = s + t * w, a square root r = c + d * w must satisfy
+/// c^2 - 7d^2 = s and 2cd = t. Given the norm n = s^2 + 7t^2 and its square root m, c^2 is one
+/// of (s + m) / 2 and (s - m) / 2, from which c and then d are derived.
+fn ext2_sqrt(a: QuadFelt) -> Option<QuadFelt> {
+    let [a0, a1] = a.to_base_elements();
+    let two_inv = Felt::new(2).inv();
+
+    // express a in the (1, w) basis
+    let t = a1 * two_inv;
+    let s = a0 + t;
+
+    let (c, d) = if t == ZERO {
+        match felt_sqrt(s) {
+            Some(c) => (c, ZERO),
+            // if s is not a square, then -s/7 is, and a = -7d^2 = (d * w)^2
+            None => (ZERO, felt_sqrt(-s * Felt::new(7).inv())?),
+        }
+    } else {
+        let m = felt_sqrt(s.square() + Felt::new(7) * t.square())?;
+        let c = felt_sqrt((s + m) * two_inv).or_else(|| felt_sqrt((s - m) * two_inv))?;
+        (c, t * (c + c).inv())
+    };
+
+    // convert the root back into the (1, x) basis: c + d * w = (c - d) + 2d * x
+    let root = QuadFelt::new(c - d, d + d);
+    debug_assert_eq!(root * root, a);
+    Some(root)
+}
+
+/// Computes a square root of the specified base field element using the Tonelli-Shanks
+/// algorithm, or returns None if the element is a quadratic non-residue.
+fn felt_sqrt(a: Felt) -> Option<Felt> {
+    if a == ZERO {
+        return Some(ZERO);
+    }
+
+    // p - 1 = 2^s * q, with q odd
+    let s = Felt::TWO_ADICITY;
+    let q = (Felt::MODULUS - 1) >> s;
+
+    // a is a quadratic residue iff a^((p - 1) / 2) = 1
+    if a.exp((Felt::MODULUS - 1) >> 1) != ONE {
+        return None;
+    }
+
+    let mut m = s;
+    let mut c = Felt::get_root_of_unity(s);
+    let mut t = a.exp(q);
+    let mut r = a.exp((q + 1) >> 1);
+
+    while t != ONE {
+        // find the least i such that t^(2^i) = 1
+        let mut i = 0;
+        let mut t2i = t;
+        while t2i != ONE {
+            t2i = t2i.square();
+            i += 1;
+        }
+
+        let b = c.exp(1 << (m - i - 1));
+        m = i;
+        c = b.square();
+        t *= c;
+        r *= b;
+    }
+
+    Some(r)
+}
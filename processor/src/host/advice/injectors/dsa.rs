@@ -1,4 +1,7 @@
-use super::super::{ExecutionError, Felt, StarkField, Vec, Word};
+use super::{
+    super::{ExecutionError, Felt, StarkField, Vec, Word},
+    secp256k1::{self, U256},
+};
 use vm_core::{
     crypto::dsa::rpo_falcon512::{KeyPair, Polynomial},
     utils::Deserializable,
@@ -71,3 +74,60 @@ pub fn falcon_sign(pk_sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionErro
         "RPO Falcon512 signature generation is not available in no_std context",
     ))
 }
+
+/// Gets as input a vector containing the secret key, and a word representing a message and
+/// outputs a vector of values to be pushed onto the advice stack. The values are the ones required
+/// for an ECDSA signature verification over the secp256k1 curve inside the VM and they are:
+///
+/// 1. The public key in projective coordinates (X, Y, Z), with Z = 1.
+/// 2. The message hash h.
+/// 3. The signature (r, s).
+///
+/// Each of these values is represented in Montgomery form in its respective field, as eight 32-bit
+/// limbs in little-endian order. The values are pushed onto the advice stack in the order listed
+/// above, so that moving them onto the operand stack via three `adv_push.16` instructions yields
+/// [X, Y, Z, h, r, s, ...] with the least significant limb of X at the top, which is the input
+/// expected by the `std::crypto::dsa::ecdsa::secp256k1::verify` procedure.
+///
+/// The secret key is expected to be represented as eight 32-bit limbs in little-endian order. The
+/// message hash h is the 256-bit integer whose 64-bit limbs are the elements of the message word
+/// (in little-endian order), reduced modulo the order of the secp256k1 group. The signing nonce is
+/// derived from the secret key and h as specified in RFC 6979.
+///
+/// Signing is not constant time, and thus this function should be used only with secret keys
+/// which are not exposed to timing side channels (e.g., in tests).
+///
+/// # Errors
+/// Will return an error if either:
+/// - The secret key is malformed due to either incorrect length or limbs which are not u32 values.
+/// - The secret key is not in the range [1, n), where n is the order of the secp256k1 group.
+pub fn ecdsa_secp256k1_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionError> {
+    if sk.len() != 8 {
+        return Err(ExecutionError::MalformedSignatureKey("ECDSA secp256k1"));
+    }
+    let mut sk_limbs = [0u64; 4];
+    for (i, element) in sk.iter().enumerate() {
+        let value = element.as_int();
+        if value > u32::MAX as u64 {
+            return Err(ExecutionError::MalformedSignatureKey("ECDSA secp256k1"));
+        }
+        sk_limbs[i / 2] |= value << (32 * (i % 2));
+    }
+
+    let z = msg.map(|element| element.as_int());
+    let data = secp256k1::sign(sk_limbs, z)
+        .ok_or(ExecutionError::FailedSignatureGeneration("ECDSA secp256k1"))?;
+
+    let mut result = Vec::with_capacity(48);
+    for value in data.pub_key.iter().chain([data.h, data.r, data.s].iter()) {
+        result.extend(u256_to_u32_felts(value));
+    }
+    Ok(result)
+}
+
+/// Splits a 256-bit integer into eight 32-bit limbs in little-endian order.
+fn u256_to_u32_felts(value: &U256) -> impl Iterator<Item = Felt> + '_ {
+    value
+        .iter()
+        .flat_map(|&limb| [Felt::new(limb & 0xffffffff), Felt::new(limb >> 32)])
+}
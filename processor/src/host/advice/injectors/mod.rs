@@ -2,4 +2,5 @@ pub(super) mod adv_map_injectors;
 pub(super) mod adv_stack_injectors;
 pub(super) mod dsa;
 pub(super) mod merkle_store_injectors;
+mod secp256k1;
 pub(super) mod smt;
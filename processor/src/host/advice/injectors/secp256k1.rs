@@ -0,0 +1,459 @@
+use rfc6979::HmacDrbg;
+use sha2::Sha256;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Modulus of the secp256k1 base field.
+const P: Modulus = Modulus {
+    value: [0xfffffffefffffc2f, 0xffffffffffffffff, 0xffffffffffffffff, 0xffffffffffffffff],
+    r2: [0x000007a2000e90a1, 0x0000000000000001, 0x0000000000000000, 0x0000000000000000],
+    m_inv: 0xd838091dd2253531,
+};
+
+/// Modulus of the secp256k1 scalar field (i.e., the order of the group).
+const N: Modulus = Modulus {
+    value: [0xbfd25e8cd0364141, 0xbaaedce6af48a03b, 0xfffffffffffffffe, 0xffffffffffffffff],
+    r2: [0x896cf21467d7d140, 0x741496c20e7cf878, 0xe697f5e45bcd07c6, 0x9d671cd581c69bc5],
+    m_inv: 0x4b0dff665588b13f,
+};
+
+/// x-coordinate of the generator of the secp256k1 group.
+const G_X: U256 = [0x59f2815b16f81798, 0x029bfcdb2dce28d9, 0x55a06295ce870b07, 0x79be667ef9dcbbac];
+
+/// y-coordinate of the generator of the secp256k1 group.
+const G_Y: U256 = [0x9c47d08ffb10d4b8, 0xfd17b448a6855419, 0x5da4fbfc0e1108a8, 0x483ada7726a3c465];
+
+/// Maximum number of nonces which are tried before signature generation is abandoned.
+const MAX_NONCE_ATTEMPTS: u64 = 64;
+
+// TYPES
+// ================================================================================================
+
+/// A 256-bit unsigned integer represented by four 64-bit limbs in little-endian order.
+pub type U256 = [u64; 4];
+
+/// A modulus together with the constants required for Montgomery arithmetic with R = 2^256.
+struct Modulus {
+    value: U256,
+    /// R^2 mod value.
+    r2: U256,
+    /// -value^-1 mod 2^64.
+    m_inv: u64,
+}
+
+/// A point on the secp256k1 curve in affine coordinates, with both coordinates in Montgomery
+/// form. None represents the point at infinity.
+type Point = Option<(U256, U256)>;
+
+/// Data required to verify an ECDSA signature over secp256k1 in the VM.
+///
+/// All values are in Montgomery form (with R = 2^256) in their respective fields.
+pub struct EcdsaSignatureData {
+    /// The public key in projective coordinates over the base field.
+    pub pub_key: [U256; 3],
+    /// The message hash as an element of the scalar field.
+    pub h: U256,
+    /// The r component of the signature as an element of the scalar field.
+    pub r: U256,
+    /// The s component of the signature as an element of the scalar field.
+    pub s: U256,
+}
+
+// SIGNING
+// ================================================================================================
+
+/// Signs the message hash `z` with the secret key `sk` and returns the data needed to verify the
+/// signature in the VM, or None if `sk` is not a valid secret key.
+///
+/// The nonce is derived deterministically from the secret key and the message hash as specified
+/// in RFC 6979, using HMAC-SHA256. As in the RFC, candidate nonces which are not in the range
+/// [1, n) or which result in r = 0 or s = 0 are skipped.
+///
+/// This implementation is not constant time: the running time of both the curve and the field
+/// arithmetic depends on the secret key and the nonce. Thus, it is intended only for generating
+/// signatures in tests and tooling, and must not be used where the secret key can be exposed to
+/// timing side channels.
+pub fn sign(sk: U256, z: U256) -> Option<EcdsaSignatureData> {
+    if is_zero(&sk) || !lt(&sk, &N.value) {
+        return None;
+    }
+    let z = reduce(z, &N);
+
+    let sk_mont = to_mont(sk, &N);
+    let z_mont = to_mont(z, &N);
+
+    let mut nonces = nonce_generator(&sk, &z);
+    for _ in 0..MAX_NONCE_ATTEMPTS {
+        let k = next_nonce(&mut nonces);
+        if is_zero(&k) || !lt(&k, &N.value) {
+            continue;
+        }
+
+        let x = match scalar_mul(generator(), &k) {
+            Some((x, _)) => reduce(from_mont(x, &P), &N),
+            None => continue,
+        };
+        if is_zero(&x) {
+            continue;
+        }
+
+        // s = k^-1 * (z + r * sk)
+        let r_mont = to_mont(x, &N);
+        let k_inv = inv(to_mont(k, &N), &N);
+        let s = mont_mul(&k_inv, &add(&z_mont, &mont_mul(&r_mont, &sk_mont, &N), &N), &N);
+        if is_zero(&s) {
+            continue;
+        }
+
+        let (pk_x, pk_y) = public_key(sk)?;
+        return Some(EcdsaSignatureData {
+            pub_key: [pk_x, pk_y, to_mont([1, 0, 0, 0], &P)],
+            h: z_mont,
+            r: r_mont,
+            s,
+        });
+    }
+
+    None
+}
+
+/// Returns the affine coordinates of the public key corresponding to the secret key `sk`, in
+/// Montgomery form.
+pub fn public_key(sk: U256) -> Option<(U256, U256)> {
+    scalar_mul(generator(), &sk)
+}
+
+/// Returns the RFC 6979 HMAC-SHA256 generator of nonces for the secret key `sk` and the message
+/// hash `z`, which must be reduced modulo the group order.
+fn nonce_generator(sk: &U256, z: &U256) -> HmacDrbg<Sha256> {
+    HmacDrbg::new(&to_be_bytes(sk), &to_be_bytes(z), &[])
+}
+
+/// Returns the next candidate nonce of the specified generator; the candidate is not guaranteed
+/// to be in the range [1, n).
+fn next_nonce(nonces: &mut HmacDrbg<Sha256>) -> U256 {
+    let mut bytes = [0; 32];
+    nonces.fill_bytes(&mut bytes);
+    from_be_bytes(&bytes)
+}
+
+fn to_be_bytes(a: &U256) -> [u8; 32] {
+    let mut result = [0; 32];
+    for (chunk, limb) in result.chunks_mut(8).zip(a.iter().rev()) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    result
+}
+
+fn from_be_bytes(bytes: &[u8; 32]) -> U256 {
+    let mut result = [0; 4];
+    for (limb, chunk) in result.iter_mut().rev().zip(bytes.chunks(8)) {
+        *limb = u64::from_be_bytes(chunk.try_into().expect("chunk is 8 bytes long"));
+    }
+    result
+}
+
+// CURVE ARITHMETIC
+// ================================================================================================
+
+fn generator() -> Point {
+    Some((to_mont(G_X, &P), to_mont(G_Y, &P)))
+}
+
+fn point_add(a: Point, b: Point) -> Point {
+    let ((x1, y1), (x2, y2)) = match (a, b) {
+        (None, b) => return b,
+        (a, None) => return a,
+        (Some(a), Some(b)) => (a, b),
+    };
+
+    let lambda = if x1 == x2 {
+        if y1 != y2 || is_zero(&y1) {
+            return None;
+        }
+        // lambda = 3x^2 / 2y
+        let x_sq = mont_mul(&x1, &x1, &P);
+        let num = add(&add(&x_sq, &x_sq, &P), &x_sq, &P);
+        mont_mul(&num, &inv(add(&y1, &y1, &P), &P), &P)
+    } else {
+        // lambda = (y2 - y1) / (x2 - x1)
+        mont_mul(&sub(&y2, &y1, &P), &inv(sub(&x2, &x1, &P), &P), &P)
+    };
+
+    let x3 = sub(&sub(&mont_mul(&lambda, &lambda, &P), &x1, &P), &x2, &P);
+    let y3 = sub(&mont_mul(&lambda, &sub(&x1, &x3, &P), &P), &y1, &P);
+    Some((x3, y3))
+}
+
+fn scalar_mul(point: Point, k: &U256) -> Point {
+    let mut result = None;
+    for i in (0..256).rev() {
+        result = point_add(result, result);
+        if (k[i / 64] >> (i % 64)) & 1 == 1 {
+            result = point_add(result, point);
+        }
+    }
+    result
+}
+
+// MODULAR ARITHMETIC
+// ================================================================================================
+
+fn is_zero(a: &U256) -> bool {
+    a.iter().all(|&limb| limb == 0)
+}
+
+fn lt(a: &U256, b: &U256) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+/// Computes a + b, returning the result together with the carry.
+fn add_with_carry(a: &U256, b: &U256) -> (U256, bool) {
+    let mut result = [0; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (sum, c1) = a[i].overflowing_add(b[i]);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        result[i] = sum;
+        carry = c1 | c2;
+    }
+    (result, carry)
+}
+
+/// Computes a - b, returning the result together with the borrow.
+fn sub_with_borrow(a: &U256, b: &U256) -> (U256, bool) {
+    let mut result = [0; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (diff, b1) = a[i].overflowing_sub(b[i]);
+        let (diff, b2) = diff.overflowing_sub(borrow as u64);
+        result[i] = diff;
+        borrow = b1 | b2;
+    }
+    (result, borrow)
+}
+
+/// Reduces a value smaller than 2^256 modulo m; this relies on m being greater than 2^255.
+fn reduce(a: U256, m: &Modulus) -> U256 {
+    if lt(&a, &m.value) {
+        a
+    } else {
+        sub_with_borrow(&a, &m.value).0
+    }
+}
+
+fn add(a: &U256, b: &U256, m: &Modulus) -> U256 {
+    let (sum, carry) = add_with_carry(a, b);
+    if carry || !lt(&sum, &m.value) {
+        sub_with_borrow(&sum, &m.value).0
+    } else {
+        sum
+    }
+}
+
+fn sub(a: &U256, b: &U256, m: &Modulus) -> U256 {
+    let (diff, borrow) = sub_with_borrow(a, b);
+    if borrow {
+        add_with_carry(&diff, &m.value).0
+    } else {
+        diff
+    }
+}
+
+/// Computes a * b * R^-1 mod m using the CIOS method.
+fn mont_mul(a: &U256, b: &U256, m: &Modulus) -> U256 {
+    let mut t = [0u64; 6];
+    for &a_i in a.iter() {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let v = t[j] as u128 + a_i as u128 * b[j] as u128 + carry;
+            t[j] = v as u64;
+            carry = v >> 64;
+        }
+        let v = t[4] as u128 + carry;
+        t[4] = v as u64;
+        t[5] = (v >> 64) as u64;
+
+        let q = t[0].wrapping_mul(m.m_inv);
+        let mut carry = (t[0] as u128 + q as u128 * m.value[0] as u128) >> 64;
+        for j in 1..4 {
+            let v = t[j] as u128 + q as u128 * m.value[j] as u128 + carry;
+            t[j - 1] = v as u64;
+            carry = v >> 64;
+        }
+        let v = t[4] as u128 + carry;
+        t[3] = v as u64;
+        t[4] = t[5] + (v >> 64) as u64;
+    }
+
+    let result = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || !lt(&result, &m.value) {
+        sub_with_borrow(&result, &m.value).0
+    } else {
+        result
+    }
+}
+
+fn to_mont(a: U256, m: &Modulus) -> U256 {
+    mont_mul(&a, &m.r2, m)
+}
+
+fn from_mont(a: U256, m: &Modulus) -> U256 {
+    mont_mul(&a, &[1, 0, 0, 0], m)
+}
+
+/// Computes the inverse of a (in Montgomery form) as a^(m - 2).
+fn inv(a: U256, m: &Modulus) -> U256 {
+    let exp = sub_with_borrow(&m.value, &[2, 0, 0, 0]).0;
+    let mut result = to_mont([1, 0, 0, 0], m);
+    for i in (0..256).rev() {
+        result = mont_mul(&result, &result, m);
+        if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+            result = mont_mul(&result, &a, m);
+        }
+    }
+    result
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::Digest;
+
+    /// Parses a big-endian hex string into a [U256].
+    fn from_hex(hex: &str) -> U256 {
+        let mut result = [0; 4];
+        for (i, limb) in result.iter_mut().enumerate() {
+            let end = hex.len() - 16 * i;
+            *limb = u64::from_str_radix(&hex[end - 16..end], 16).unwrap();
+        }
+        result
+    }
+
+    /// Returns the SHA-256 digest of the message as a [U256].
+    fn sha256(message: &[u8]) -> U256 {
+        from_be_bytes(&Sha256::digest(message).into())
+    }
+
+    fn affine(point: (U256, U256)) -> (U256, U256) {
+        (from_mont(point.0, &P), from_mont(point.1, &P))
+    }
+
+    #[test]
+    fn public_key_matches_reference() {
+        let pk = public_key([1, 0, 0, 0]).unwrap();
+        assert_eq!(affine(pk), (G_X, G_Y));
+
+        let pk = public_key([2, 0, 0, 0]).unwrap();
+        assert_eq!(
+            affine(pk),
+            (
+                from_hex("c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"),
+                from_hex("1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a"),
+            )
+        );
+
+        let pk = public_key([3, 0, 0, 0]).unwrap();
+        assert_eq!(
+            affine(pk),
+            (
+                from_hex("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+                from_hex("388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672"),
+            )
+        );
+
+        // (n - 1) * G = -G
+        let n_minus_one = sub_with_borrow(&N.value, &[1, 0, 0, 0]).0;
+        let pk = public_key(n_minus_one).unwrap();
+        assert_eq!(affine(pk), (G_X, sub(&[0; 4], &G_Y, &P)));
+
+        // n * G is the point at infinity
+        assert_eq!(public_key(N.value), None);
+    }
+
+    #[test]
+    fn rfc6979_nonce_matches_reference() {
+        // the vectors are taken from the secp256k1 RFC 6979 tests of python-ecdsa:
+        // https://github.com/tlsfuzzer/python-ecdsa/blob/master/src/ecdsa/test_pyecdsa.py
+        let cases = [
+            (
+                "000000000000000000000000000000009d0219792467d7d37b4d43298a7d0c05",
+                b"sample".as_slice(),
+                "8fa1f95d514760e498f28957b824ee6ec39ed64826ff4fecc2b5739ec45b91cd",
+            ),
+            (
+                "cca9fbcc1b41e5a95d369eaa6ddcff73b61a4efaa279cfc6567e8daa39cbaf50",
+                b"sample".as_slice(),
+                "2df40ca70e639d89528a6b670d9d48d9165fdc0febc0974056bdce192b8e16a3",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                b"Satoshi Nakamoto".as_slice(),
+                "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15",
+            ),
+            (
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+                b"Satoshi Nakamoto".as_slice(),
+                "33a19b60e25fb6f4435af53a3d42d493644827367e6453928554f43e49aa6f90",
+            ),
+            (
+                "f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181",
+                b"Alan Turing".as_slice(),
+                "525a82b70e67874398067543fd84c83d30c175fdc45fdeee082fe13b1d7cfdf1",
+            ),
+        ];
+
+        for (sk, message, k) in cases {
+            let z = reduce(sha256(message), &N);
+            let mut nonces = nonce_generator(&from_hex(sk), &z);
+            assert_eq!(next_nonce(&mut nonces), from_hex(k));
+        }
+    }
+
+    #[test]
+    fn signature_matches_reference() {
+        // the signature of the SHA-256 digest of "Satoshi Nakamoto" with the secret key 1, as used
+        // in the RFC 6979 test vectors of several secp256k1 libraries (e.g., python-ecdsa and
+        // bitcoinjs). These libraries normalize s to the lower half of the scalar field, and thus
+        // either s or n - s is expected.
+        let r = from_hex("934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8");
+        let s = from_hex("2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5");
+
+        let data = sign([1, 0, 0, 0], sha256(b"Satoshi Nakamoto")).unwrap();
+        assert_eq!(from_mont(data.r, &N), r);
+        let data_s = from_mont(data.s, &N);
+        assert!(data_s == s || data_s == sub(&[0; 4], &s, &N));
+    }
+
+    #[test]
+    fn signature_verifies() {
+        let sk = from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let z = from_hex("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+        let data = sign(sk, z).unwrap();
+
+        // verify the signature: r == x(h * s^-1 * G + r * s^-1 * PK)
+        let s_inv = inv(data.s, &N);
+        let u1 = from_mont(mont_mul(&data.h, &s_inv, &N), &N);
+        let u2 = from_mont(mont_mul(&data.r, &s_inv, &N), &N);
+        let pk = Some((data.pub_key[0], data.pub_key[1]));
+        let point = point_add(scalar_mul(generator(), &u1), scalar_mul(pk, &u2)).unwrap();
+        assert_eq!(from_mont(point.0, &P), from_mont(data.r, &N));
+
+        // the projective z-coordinate of the public key is 1
+        assert_eq!(from_mont(data.pub_key[2], &P), [1, 0, 0, 0]);
+
+        // signing is deterministic
+        let data2 = sign(sk, z).unwrap();
+        assert_eq!((data.r, data.s), (data2.r, data2.s));
+    }
+
+    #[test]
+    fn invalid_secret_key() {
+        assert!(sign([0; 4], [1, 0, 0, 0]).is_none());
+        assert!(sign(N.value, [1, 0, 0, 0]).is_none());
+    }
+}
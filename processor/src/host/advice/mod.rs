@@ -58,18 +58,44 @@ pub trait AdviceProvider: Sized {
         match advice_injector {
             AdviceInjector::MerkleNodeMerge => self.merge_merkle_nodes(process),
             AdviceInjector::MerkleNodeToStack => self.copy_merkle_node_to_adv_stack(process),
+            AdviceInjector::MerklePathToStack => self.copy_merkle_path_to_adv_stack(process),
             AdviceInjector::MapValueToStack {
                 include_len,
                 key_offset,
-            } => self.copy_map_value_to_adv_stack(process, *include_len, *key_offset),
+                consume,
+            } => self.copy_map_value_to_adv_stack(process, *include_len, *key_offset, *consume),
             AdviceInjector::UpdateMerkleNode => self.update_operand_stack_merkle_node(process),
+            AdviceInjector::RpoPreimageToStack => self.copy_rpo_preimage_to_adv_stack(process),
             AdviceInjector::DivU64 => self.push_u64_div_result(process),
+            AdviceInjector::MulU64 => self.push_u64_mul_result(process),
+            AdviceInjector::ModReduce { modulus } => self.push_mod_reduce_result(process, *modulus),
+            AdviceInjector::BarrettMu { modulus, k } => self.push_barrett_mu(process, *modulus, *k),
+            AdviceInjector::U32Sqrt => self.push_u32_sqrt_result(process),
+            AdviceInjector::ILog2Ceil => self.push_ilog2_ceil_result(process),
+            AdviceInjector::U32Gcd => self.push_u32_gcd_result(process),
+            AdviceInjector::FactorHint => self.push_factor_hint(process),
+            AdviceInjector::FieldInv => self.push_field_inv_result(process),
+            AdviceInjector::FieldOrder => self.push_field_order_result(process),
+            AdviceInjector::FixedRecip { frac_bits } => {
+                self.push_fixed_recip_result(process, *frac_bits)
+            }
+            AdviceInjector::SortWords { count } => self.push_sorted_words(process, *count),
+            AdviceInjector::InversePermutation { count } => {
+                self.push_inverse_permutation(process, *count)
+            }
+            AdviceInjector::BinarySearch => self.push_binary_search_result(process),
+            AdviceInjector::MapKeysSorted => self.push_map_keys_sorted(),
+            AdviceInjector::DrawRandom { count } => self.push_random_values(process, *count),
+            AdviceInjector::Crc32 { poly } => self.push_crc32(process, *poly),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(process),
+            AdviceInjector::Ext2Sqrt => self.push_ext2_sqrt_result(process),
             AdviceInjector::Ext2Intt => self.push_ext2_intt_result(process),
             AdviceInjector::SmtGet => self.push_smtget_inputs(process),
             AdviceInjector::SmtSet => self.push_smtset_inputs(process),
             AdviceInjector::SmtPeek => self.push_smtpeek_result(process),
-            AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(process),
+            AdviceInjector::MemToMap { include_len } => {
+                self.insert_mem_values_into_adv_map(process, *include_len)
+            }
             AdviceInjector::HdwordToMap { domain } => {
                 self.insert_hdword_into_adv_map(process, *domain)
             }
@@ -98,7 +124,8 @@ pub trait AdviceProvider: Sized {
     // --------------------------------------------------------------------------------------------
 
     /// Reads words from memory at the specified range and inserts them into the advice map under
-    /// the key `KEY` located at the top of the stack.
+    /// the key `KEY` located at the top of the stack. If `include_len` is set to true, the number of
+    /// elements read from memory is prepended to the stored values.
     ///
     /// Inputs:
     ///   Operand stack: [KEY, start_addr, end_addr, ...]
@@ -106,9 +133,10 @@ pub trait AdviceProvider: Sized {
     ///
     /// Outputs:
     ///   Operand stack: [KEY, start_addr, end_addr, ...]
-    ///   Advice map: {KEY: values}
+    ///   Advice map: {KEY: [values_len?, values]}
     ///
-    /// Where `values` are the elements located in memory[start_addr..end_addr].
+    /// Where `values` are the elements located in memory[start_addr..end_addr], and `values_len` is
+    /// equal to 4 * (end_addr - start_addr).
     ///
     /// # Errors
     /// Returns an error:
@@ -118,8 +146,9 @@ pub trait AdviceProvider: Sized {
     fn insert_mem_values_into_adv_map<S: ProcessState>(
         &mut self,
         process: &S,
+        include_len: bool,
     ) -> Result<HostResponse, ExecutionError> {
-        injectors::adv_map_injectors::insert_mem_values_into_adv_map(self, process)
+        injectors::adv_map_injectors::insert_mem_values_into_adv_map(self, process, include_len)
     }
 
     /// Reads two word from the operand stack and inserts them into the advice map under the key
@@ -216,9 +245,39 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::copy_merkle_node_to_adv_stack(self, process)
     }
 
+    /// Pushes the authentication path of the Merkle tree node specified by the values on the top
+    /// of the operand stack onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [...]
+    ///   Merkle store: {TREE_ROOT<-NODE}
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [S_0, S_1, ..., S_{depth-1}, ...]
+    ///   Merkle store: {TREE_ROOT<-NODE}
+    ///
+    /// Where S_0 is the sibling of the node and S_{depth-1} is a child of the root.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Merkle tree for the specified root cannot be found in the advice provider.
+    /// - The specified depth is either zero or greater than the depth of the Merkle tree
+    ///   identified by the specified root.
+    /// - The path to the node at the specified depth and index is not known to the advice
+    ///   provider.
+    fn copy_merkle_path_to_adv_stack<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::copy_merkle_path_to_adv_stack(self, process)
+    }
+
     /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
     /// map using the specified word from the operand stack as the key. If `include_len` is set to
-    /// true, the number of elements in the value is also pushed onto the advice stack.
+    /// true, the number of elements in the value is also pushed onto the advice stack. If `consume`
+    /// is set to true, the entry is removed from the advice map once its values have been pushed.
     ///
     /// Inputs:
     ///   Operand stack: [..., KEY, ...]
@@ -228,7 +287,7 @@ pub trait AdviceProvider: Sized {
     /// Outputs:
     ///   Operand stack: [..., KEY, ...]
     ///   Advice stack: [values_len?, values, ...]
-    ///   Advice map: {KEY: values}
+    ///   Advice map: {KEY: values} if `consume` is false, {} otherwise
     ///
     /// The `key_offset` value specifies the location of the `KEY` on the stack. For example,
     /// offset value of 0 indicates that the top word on the stack should be used as the key, the
@@ -237,6 +296,9 @@ pub trait AdviceProvider: Sized {
     ///
     /// The valid values of `key_offset` are 0 through 12 (inclusive).
     ///
+    /// A consumed entry cannot be replayed: subsequent lookups of the same key fail with an error
+    /// unless the entry is re-inserted into the advice map.
+    ///
     /// # Errors
     /// Returns an error if the required key was not found in the key-value map or if stack offset
     /// is greater than 12.
@@ -245,15 +307,43 @@ pub trait AdviceProvider: Sized {
         process: &S,
         include_len: bool,
         key_offset: usize,
+        consume: bool,
     ) -> Result<HostResponse, ExecutionError> {
         injectors::adv_stack_injectors::copy_map_value_to_adv_stack(
             self,
             process,
             include_len,
             key_offset,
+            consume,
         )
     }
 
+    /// Pushes the preimage of the RPO digest at the top of the operand stack onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [DIGEST, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {DIGEST: values}
+    ///
+    /// Outputs:
+    ///   Operand stack: [DIGEST, ...]
+    ///   Advice stack: [values, ...]
+    ///   Advice map: {DIGEST: values}
+    ///
+    /// The preimage must have been registered in the advice map under its digest beforehand. It
+    /// is not checked that the values actually hash to the digest; the program is expected to
+    /// re-hash the values and compare the result against the digest.
+    ///
+    /// # Errors
+    /// Returns an error if no preimage is registered in the advice map under the digest.
+    fn copy_rpo_preimage_to_adv_stack<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::copy_rpo_preimage_to_adv_stack(self, process)
+    }
+
     /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
     /// stack.
     ///
@@ -279,6 +369,401 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_u64_div_result(self, process)
     }
 
+    /// Pushes the result of [u64] multiplication (all four 32-bit limbs of the 128-bit product)
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b1, b0, a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b1, b0, a1, a0, ...]
+    ///   Advice stack: [c0, c1, c2, c3, ...]
+    ///
+    /// Where (a0, a1) and (b0, b1) are the 32-bit limbs of the multiplicands (with a0 representing
+    /// the 32 least significant bits and a1 representing the 32 most significant bits), and (c0,
+    /// c1, c2, c3) are the 32-bit limbs of the product c = a * b ordered from the least significant
+    /// to the most significant. Same as with `DivU64`, the least significant limb ends up at the
+    /// top of the advice stack.
+    ///
+    /// # Errors
+    /// Returns an error if any of the limbs is not a valid u32 value.
+    fn push_u64_mul_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u64_mul_result(self, process)
+    }
+
+    /// Pushes the result of reducing a [u64] value modulo `modulus` (both the quotient and the
+    /// remainder) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [q0, q1, r0, r1, ...]
+    ///
+    /// Where (a0, a1) are the 32-bit limbs of the value a (with a0 representing the 32 least
+    /// significant bits and a1 representing the 32 most significant bits), and (q0, q1) and
+    /// (r0, r1) are the limbs of the quotient q = a / modulus and the remainder r = a % modulus
+    /// respectively.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - `modulus` is zero.
+    /// - Any of the limbs of a is not a valid u32 value.
+    fn push_mod_reduce_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+        modulus: u64,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_mod_reduce_result(self, process, modulus)
+    }
+
+    /// Pushes the Barrett reduction constant mu = floor(2^k / modulus) onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [mu, ...]
+    ///
+    /// `k` must be in the range [0, 63], which guarantees that mu is a valid field element.
+    ///
+    /// # Errors
+    /// Returns an error if `modulus` is zero.
+    fn push_barrett_mu<S: ProcessState>(
+        &mut self,
+        process: &S,
+        modulus: u64,
+        k: u8,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_barrett_mu(self, process, modulus, k)
+    }
+
+    /// Pushes the integer square root of the u32 value at the top of the operand stack onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [r, ...]
+    ///
+    /// Where r is the largest integer such that r * r <= n.
+    ///
+    /// # Errors
+    /// Returns an error if n is not a u32 value.
+    fn push_u32_sqrt_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u32_sqrt_result(self, process)
+    }
+
+    /// Pushes the ceiling of the base 2 logarithm of the element at the top of the operand stack
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [ceil(log2(n)), ...]
+    ///
+    /// # Errors
+    /// Returns an error if n is ZERO.
+    fn push_ilog2_ceil_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_ilog2_ceil_result(self, process)
+    }
+
+    /// Pushes the greatest common divisor of the two u32 values at the top of the operand stack
+    /// onto the advice stack, together with the values needed to verify it.
+    ///
+    /// Inputs:
+    ///   Operand stack: [b, a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [b, a, ...]
+    ///   Advice stack: [g, qa, qb, x, y, ...]
+    ///
+    /// Where g = gcd(a, b), a = g * qa, b = g * qb, and x and y are the magnitudes of the Bézout
+    /// coefficients of qa and qb, such that qa * x - qb * y = ±1. All pushed values are u32
+    /// values.
+    ///
+    /// # Errors
+    /// Returns an error if either a or b is not a u32 value.
+    fn push_u32_gcd_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u32_gcd_result(self, process)
+    }
+
+    /// Pushes a factorization of the u32 value at the top of the operand stack onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [n, ...]
+    ///   Advice stack: [p, q, ...]
+    ///
+    /// Where p * q = n. If n has a nontrivial factorization, p is the smallest prime factor of n
+    /// and q = n / p, and thus 1 < p <= q < n. Otherwise (i.e., if n is prime, or if n is 0 or 1),
+    /// p is set to 1 and q is set to n. Thus, p = 1 serves as a flag indicating that n is either
+    /// prime or smaller than 2, and n can be proven composite on the stack by checking that p > 1.
+    ///
+    /// # Errors
+    /// Returns an error if n is not a u32 value.
+    fn push_factor_hint<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_factor_hint(self, process)
+    }
+
+    /// Pushes the multiplicative inverse of the element at the top of the operand stack onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [a^-1, ...]
+    ///
+    /// # Errors
+    /// Returns an error if a is ZERO.
+    fn push_field_inv_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_field_inv_result(self, process)
+    }
+
+    /// Pushes the multiplicative order of the element at the top of the operand stack onto the
+    /// advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [n, ...]
+    ///
+    /// Where n is the smallest positive integer such that a^n = 1 (e.g., the order of 1 is 1).
+    /// Since n divides the order of the multiplicative group of the field (p - 1), the claimed
+    /// order can be verified on the stack by checking that a^n = 1 and that a^(n/q) != 1 for each
+    /// prime factor q of n.
+    ///
+    /// # Errors
+    /// Returns an error if a is ZERO.
+    fn push_field_order_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_field_order_result(self, process)
+    }
+
+    /// Pushes a fixed-point approximation of the reciprocal of the u32 value at the top of the
+    /// operand stack onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a, ...]
+    ///   Advice stack: [r, ...]
+    ///
+    /// Where r = floor(2^frac_bits / a), i.e., the reciprocal of a with `frac_bits` fractional
+    /// bits, rounded towards zero. Thus, the result is within one unit in the last place of the
+    /// exact reciprocal, which can be verified on the stack by checking that r * a <= 2^frac_bits
+    /// and (r + 1) * a > 2^frac_bits. `frac_bits` must be in the range [0, 31], which guarantees
+    /// that r is a u32 value.
+    ///
+    /// # Errors
+    /// Returns an error if a is ZERO or is not a u32 value.
+    fn push_fixed_recip_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+        frac_bits: u8,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_fixed_recip_result(self, process, frac_bits)
+    }
+
+    /// Reads `count` words from memory starting at the address located at the top of the operand
+    /// stack, sorts them, and pushes the sorted words onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [W_0, W_1, ..., W_{count-1}, ...]
+    ///
+    /// Where W_0, ..., W_{count-1} are the words located in memory[ptr..ptr + count] sorted
+    /// lexicographically in non-decreasing order, with W_0 at the top of the advice stack.
+    ///
+    /// # Errors
+    /// Returns an error if ptr + count is greater than 2^32.
+    fn push_sorted_words<S: ProcessState>(
+        &mut self,
+        process: &S,
+        count: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_sorted_words(self, process, count)
+    }
+
+    /// Reads a permutation of `count` indices from memory starting at the address located at the
+    /// top of the operand stack, and pushes its inverse permutation onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, ...]
+    ///   Advice stack: [inv_0, inv_1, ..., inv_{count-1}, ...]
+    ///
+    /// Where perm_i is the first element of the word located at memory[ptr + i], and inv_0, ...,
+    /// inv_{count-1} is the inverse permutation, i.e., inv[perm_i] = i for all i, with inv_0 at the
+    /// top of the advice stack.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - ptr + count is greater than 2^32.
+    /// - perm_0, ..., perm_{count-1} is not a permutation of 0..count.
+    fn push_inverse_permutation<S: ProcessState>(
+        &mut self,
+        process: &S,
+        count: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_inverse_permutation(self, process, count)
+    }
+
+    /// Searches a sorted region of memory for the word located at the top of the operand stack, and
+    /// pushes the result of the search onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [T, ptr, len, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [T, ptr, len, ...]
+    ///   Advice stack: [found, i, ...]
+    ///
+    /// Where memory[ptr..ptr + len] is expected to contain words sorted in non-decreasing order (words
+    /// are compared the same way as in `SortWords`). If T is present in the region, found is set to 1
+    /// and i is the offset of the first occurrence of T, i.e., memory[ptr + i] = T. Otherwise, found is
+    /// set to 0 and i is the offset at which T would have to be inserted to keep the region sorted
+    /// (this is 0 if T is smaller than all words in the region, and len if T is larger than all of them).
+    ///
+    /// The result is unspecified if the region is not sorted; callers are expected to verify it by
+    /// checking that memory[ptr + i - 1] < T <= memory[ptr + i] for the words which are in the region.
+    ///
+    /// # Errors
+    /// Returns an error if ptr + len is greater than 2^32.
+    fn push_binary_search_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_binary_search_result(self, process)
+    }
+
+    /// Pushes the keys currently present in the advice map onto the advice stack, sorted in
+    /// non-decreasing order, followed by the number of keys.
+    ///
+    /// Inputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [...]
+    ///   Advice map: {K_0: values_0, ..., K_{n-1}: values_{n-1}}
+    ///
+    /// Outputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [n, K_0, K_1, ..., K_{n-1}, ...]
+    ///   Advice map: {K_0: values_0, ..., K_{n-1}: values_{n-1}}
+    ///
+    /// Where n is the number of keys in the advice map, and K_0, ..., K_{n-1} are the keys sorted
+    /// lexicographically by the integer values of their elements (the same way as in
+    /// `SortWords`), with K_0 located right below n. Thus, 4 * n + 1 elements are pushed onto the
+    /// advice stack.
+    fn push_map_keys_sorted(&mut self) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_map_keys_sorted(self)
+    }
+
+    /// Draws `count` pseudo-random field elements from a random coin seeded with the word located
+    /// at the top of the operand stack, and pushes them onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [SEED, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [SEED, ...]
+    ///   Advice stack: [r_0, r_1, ..., r_{count - 1}, ...]
+    ///
+    /// Where r_i is the i-th element drawn from an `RpoRandomCoin` instantiated with SEED, i.e.,
+    /// the same coin the prover uses to build its Fiat-Shamir transcript. Thus, r_0 is the first
+    /// element drawn, and it is located at the top of the advice stack.
+    ///
+    /// The drawn values are fully determined by SEED: the same seed always produces the same
+    /// sequence of elements, and a larger `count` extends the sequence produced for a smaller one.
+    fn push_random_values<S: ProcessState>(
+        &mut self,
+        process: &S,
+        count: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_random_values(self, process, count)
+    }
+
+    /// Computes the CRC32 checksum of a byte string located in memory using the specified
+    /// polynomial, and pushes it onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ptr, len, ...]
+    ///   Advice stack: [crc, ...]
+    ///
+    /// Where the byte string consists of the first `len` bytes of memory[ptr..], with every element
+    /// of a word packing 4 bytes in little-endian order. `poly` is given in the normal (MSB-first)
+    /// representation, and the checksum is computed the same way as for the standard CRC-32
+    /// variants (reflected input and output, initial value and final XOR of 0xFFFFFFFF).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - len is not a u32 value.
+    /// - The memory range occupied by the byte string is out of bounds.
+    /// - Any of the elements holding the byte string is not a u32 value.
+    fn push_crc32<S: ProcessState>(
+        &mut self,
+        process: &S,
+        poly: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_crc32(self, process, poly)
+    }
+
     /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, b1),
     /// computes its multiplicative inverse and push the result onto the advice stack.
     ///
@@ -302,6 +787,32 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_ext2_inv_result(self, process)
     }
 
+    /// Given an element in a quadratic extension field on the top of the stack (i.e., a0, a1),
+    /// computes its square root and pushes the result onto the advice stack together with a flag
+    /// indicating whether the square root exists.
+    ///
+    /// Inputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [a1, a0, ...]
+    ///   Advice stack: [flag, r0, r1, ...]
+    ///
+    /// If (a0, a1) is a quadratic residue, flag is set to 1 and (r0, r1) is an extension field element
+    /// such that (r0, r1)^2 = (a0, a1). Either of the two square roots may be returned; it is up to the
+    /// caller to verify the result and to choose between r and -r if needed.
+    ///
+    /// If (a0, a1) is a quadratic non-residue, flag is set to 0 and both r0 and r1 are set to 0. The
+    /// advice stack layout is the same in both cases, so that the caller can always read 3 elements
+    /// from the advice stack.
+    fn push_ext2_sqrt_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_ext2_sqrt_result(self, process)
+    }
+
     /// Given evaluations of a polynomial over some specified domain, interpolates the evaluations
     ///  into a polynomial in coefficient form and pushes the result into the advice stack.
     ///
@@ -581,6 +1092,11 @@ pub trait AdviceProvider: Sized {
     /// Returns a reference to the value(s) associated with the specified key in the advice map.
     fn get_mapped_values(&self, key: &[u8; 32]) -> Option<&[Felt]>;
 
+    /// Returns the keys currently present in the advice map.
+    ///
+    /// The order of the returned keys is not specified.
+    fn get_map_keys(&self) -> Vec<Word>;
+
     /// Inserts the provided value into the advice map under the specified key.
     ///
     /// The values in the advice map can be moved onto the advice stack by invoking
@@ -590,6 +1106,10 @@ pub trait AdviceProvider: Sized {
     /// are replaced with the specified values.
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError>;
 
+    /// Removes the specified key from the advice map, returning the values which were stored
+    /// under the key, or None if the key was not present in the map.
+    fn remove_from_map(&mut self, key: Word) -> Option<Vec<Felt>>;
+
     /// Returns a signature on a message using a public key.
     fn get_signature(
         &self,
@@ -727,6 +1247,10 @@ where
         T::insert_into_map(self, key, values)
     }
 
+    fn remove_from_map(&mut self, key: Word) -> Option<Vec<Felt>> {
+        T::remove_from_map(self, key)
+    }
+
     fn get_signature(
         &self,
         kind: SignatureKind,
@@ -740,6 +1264,10 @@ where
         T::get_mapped_values(self, key)
     }
 
+    fn get_map_keys(&self) -> Vec<Word> {
+        T::get_map_keys(self)
+    }
+
     fn get_tree_node(
         &self,
         root: Word,
@@ -4,7 +4,7 @@ use super::{
     StoreNode, Vec, Word,
 };
 use crate::ProcessState;
-use vm_core::SignatureKind;
+use vm_core::{SignatureKind, ZERO};
 
 // TYPE ALIASES
 // ================================================================================================
@@ -120,6 +120,7 @@ where
 
         match kind {
             SignatureKind::RpoFalcon512 => injectors::dsa::falcon_sign(pk_sk, msg),
+            SignatureKind::EcdsaSecp256k1 => injectors::dsa::ecdsa_secp256k1_sign(pk_sk, msg),
         }
     }
 
@@ -130,11 +131,26 @@ where
         self.map.get(key).map(|v| v.as_slice())
     }
 
+    fn get_map_keys(&self) -> Vec<Word> {
+        let keys = self.map.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+
+        // look up each key so that recording maps include all listed entries in their proofs;
+        // otherwise, re-executing the program from the recorded inputs would list fewer keys
+        keys.iter()
+            .filter(|key| self.map.contains_key(key))
+            .map(bytes_to_word)
+            .collect()
+    }
+
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
         self.map.insert(key.into_bytes(), values);
         Ok(())
     }
 
+    fn remove_from_map(&mut self, key: Word) -> Option<Vec<Felt>> {
+        self.map.remove(&key.into_bytes())
+    }
+
     // MERKLE STORE
     // --------------------------------------------------------------------------------------------
 
@@ -299,6 +315,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.insert_into_map(key, values)
     }
 
+    fn remove_from_map(&mut self, key: Word) -> Option<Vec<Felt>> {
+        self.provider.remove_from_map(key)
+    }
+
     fn get_signature(&self, kind: SignatureKind, pub_key: Word, msg: Word) -> Result<Vec<Felt>, ExecutionError> {
         self.provider.get_signature(kind, pub_key, msg)
     }
@@ -307,6 +327,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.get_mapped_values(key)
     }
 
+    fn get_map_keys(&self) -> Vec<Word> {
+        self.provider.get_map_keys()
+    }
+
     fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt) -> Result<Word, ExecutionError> {
         self.provider.get_tree_node(root, depth, index)
     }
@@ -423,6 +447,10 @@ impl AdviceProvider for RecAdviceProvider {
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
         self.provider.insert_into_map(key, values)
     }
+
+    fn remove_from_map(&mut self, key: Word) -> Option<Vec<Felt>> {
+        self.provider.remove_from_map(key)
+    }
     
     fn get_signature(&self, kind: SignatureKind, pub_key: Word, msg: Word) -> Result<Vec<Felt>, ExecutionError> {
         self.provider.get_signature(kind, pub_key, msg)
@@ -432,6 +460,10 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.get_mapped_values(key)
     }
 
+    fn get_map_keys(&self) -> Vec<Word> {
+        self.provider.get_map_keys()
+    }
+
     fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt) -> Result<Word, ExecutionError> {
         self.provider.get_tree_node(root, depth, index)
     }
@@ -495,3 +527,16 @@ impl RecAdviceProvider {
         (proof, stack, map, store.into())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a key of the advice map back into the word it was derived from.
+fn bytes_to_word(bytes: &[u8; 32]) -> Word {
+    let mut word = [ZERO; 4];
+    for (element, chunk) in word.iter_mut().zip(bytes.chunks_exact(8)) {
+        let value = u64::from_le_bytes(chunk.try_into().expect("chunk is 8 bytes long"));
+        *element = Felt::new(value);
+    }
+    word
+}
@@ -187,10 +187,10 @@ where
         let mut b = self.stack.get(3);
 
         // least significant bit of the number b.
-        let bit = b.as_int() & 1;
+        let bit = Felt::new(b.as_int() & 1);
 
         // value which would be incorporated in the accumulator.
-        let value = Felt::new((exp.as_int() - 1) * bit + 1);
+        let value = (exp - ONE) * bit + ONE;
 
         // current value of acc after including the value based on whether the bit is
         // 1 or not.
@@ -205,7 +205,7 @@ where
         // save val in the decoder helper register.
         self.decoder.set_user_op_helpers(Operation::Expacc, &[value]);
 
-        self.stack.set(0, Felt::new(bit));
+        self.stack.set(0, bit);
         self.stack.set(1, exp);
         self.stack.set(2, acc);
         self.stack.set(3, b);
@@ -554,6 +554,21 @@ mod tests {
         let expected =
             build_expected(&[ONE, Felt::new(390625), Felt::new(3125), Felt::new(a >> 1)]);
         assert_eq!(expected, process.stack.trace_state());
+
+        // --- test when bit from b is 0 & exp is 0 ------------------------------------------------
+
+        let a = 2;
+        let b = 0;
+        let c = 5;
+
+        let advice_inputs = AdviceInputs::default();
+        let stack_inputs = StackInputs::try_from_values([a, c, b, 0]).unwrap();
+        let mut process =
+            Process::new_dummy_with_inputs_and_decoder_helpers(stack_inputs, advice_inputs);
+
+        process.execute_op(Operation::Expacc).unwrap();
+        let expected = build_expected(&[ZERO, ZERO, Felt::new(5), Felt::new(a >> 1)]);
+        assert_eq!(expected, process.stack.trace_state());
     }
 
     // HELPER FUNCTIONS
@@ -275,8 +275,11 @@ mod tests {
         super::{super::AdviceProvider, Operation, STACK_TOP_SIZE},
         Felt, Host, Process,
     };
-    use crate::AdviceSource;
-    use vm_core::{utils::ToElements, Word, ONE, ZERO};
+    use crate::{AdviceInputs, AdviceSource, DefaultHost, MemAdviceProvider, StackInputs};
+    use vm_core::{
+        utils::{IntoBytes, ToElements},
+        AdviceInjector, Decorator, Word, ONE, ZERO,
+    };
 
     #[test]
     fn op_push() {
@@ -582,9 +585,153 @@ mod tests {
         assert_eq!(expected, process.stack.trace_state());
     }
 
+    #[test]
+    fn map_keys_sorted_injector() {
+        // keys are sorted by their first element, and then by the following ones
+        let keys: [Word; 3] = [
+            [7, 0, 0, 0].map(Felt::new),
+            [1, 9, 0, 0].map(Felt::new),
+            [1, 2, 3, 4].map(Felt::new),
+        ];
+        let map = keys.iter().map(|key| (key.into_bytes(), vec![ONE]));
+        let advice_inputs = AdviceInputs::default().with_map(map);
+        let mut process = Process::new_dummy_with_inputs_and_decoder_helpers(
+            StackInputs::default(),
+            advice_inputs,
+        );
+
+        let injector = Decorator::Advice(AdviceInjector::MapKeysSorted);
+        process.execute_decorator(&injector).unwrap();
+
+        // the number of keys is at the top of the advice stack, followed by the sorted keys
+        process.execute_op(Operation::AdvPop).unwrap();
+        assert_eq!(Felt::new(3), process.stack.trace_state()[0]);
+        for mut key in [keys[2], keys[1], keys[0]] {
+            process.execute_op(Operation::AdvPopW).unwrap();
+            key.reverse();
+            assert_eq!(key, process.stack.trace_state()[..4]);
+        }
+        assert!(process.execute_op(Operation::AdvPop).is_err());
+
+        // an empty advice map results in a single zero being pushed
+        let mut process = Process::new_dummy_with_empty_stack();
+        process.execute_decorator(&injector).unwrap();
+        process.execute_op(Operation::Push(ONE)).unwrap();
+        process.execute_op(Operation::AdvPop).unwrap();
+        assert_eq!(build_expected_stack(&[0, 1]), process.stack.trace_state());
+        assert!(process.execute_op(Operation::AdvPop).is_err());
+    }
+
+    #[test]
+    fn crc32_injector() {
+        // check values of the standard CRC-32 and CRC-32C variants
+        let check = b"123456789";
+        let fox = b"The quick brown fox jumps over the lazy dog";
+        let cases: [(u32, &[u8], u32); 5] = [
+            (0x04c11db7, check, 0xcbf43926),
+            (0x1edc6f41, check, 0xe3069283),
+            (0x04c11db7, fox, 0x414fa339),
+            (0x04c11db7, b"", 0),
+            (0x04c11db7, b"a", 0xe8b7be43),
+        ];
+
+        for (poly, bytes, expected) in cases {
+            let ptr = 3;
+            let stack_inputs = StackInputs::try_from_values([bytes.len() as u64, ptr]).unwrap();
+            let mut process = Process::new_dummy_with_inputs_and_decoder_helpers(
+                stack_inputs,
+                AdviceInputs::default(),
+            );
+
+            // pack 4 bytes into every element in little-endian order, and 4 elements into a word;
+            // the last word is padded with 0xff bytes which must not affect the checksum
+            for (i, chunk) in bytes.chunks(16).enumerate() {
+                let mut padded = [0xff; 16];
+                padded[..chunk.len()].copy_from_slice(chunk);
+                let word: Word = core::array::from_fn(|j| {
+                    let value = u32::from_le_bytes(padded[4 * j..4 * j + 4].try_into().unwrap());
+                    Felt::from(value)
+                });
+                process.chiplets.write_mem(0, ptr as u32 + i as u32, word);
+            }
+
+            let injector = Decorator::Advice(AdviceInjector::Crc32 { poly });
+            process.execute_decorator(&injector).unwrap();
+            process.execute_op(Operation::AdvPop).unwrap();
+            assert_eq!(Felt::from(expected), process.stack.trace_state()[0]);
+        }
+
+        // elements which are not u32 values cannot hold the bytes of the string
+        let stack_inputs = StackInputs::try_from_values([4, 0]).unwrap();
+        let mut process = Process::new_dummy_with_inputs_and_decoder_helpers(
+            stack_inputs,
+            AdviceInputs::default(),
+        );
+        process
+            .chiplets
+            .write_mem(0, 0, [Felt::new(u32::MAX as u64 + 1), ZERO, ZERO, ZERO]);
+        let injector = Decorator::Advice(AdviceInjector::Crc32 { poly: 0x04c11db7 });
+        assert!(process.execute_decorator(&injector).is_err());
+    }
+
+    #[test]
+    fn inverse_permutation_injector() {
+        let cases: [&[u64]; 5] = [&[], &[0], &[0, 1, 2, 3, 4], &[1, 0], &[2, 4, 0, 3, 1]];
+        for perm in cases {
+            let mut process = new_process_with_indices(perm);
+            let count = perm.len() as u32;
+            let injector = Decorator::Advice(AdviceInjector::InversePermutation { count });
+            process.execute_decorator(&injector).unwrap();
+
+            let mut inverse = vec![ZERO; perm.len()];
+            for _ in 0..perm.len() {
+                process.execute_op(Operation::AdvPop).unwrap();
+            }
+            // the last popped value is at the top of the operand stack
+            for (i, value) in inverse.iter_mut().rev().enumerate() {
+                *value = process.stack.trace_state()[i];
+            }
+            for (i, &index) in perm.iter().enumerate() {
+                assert_eq!(
+                    inverse[index as usize],
+                    Felt::new(i as u64),
+                    "invalid inverse of {perm:?}"
+                );
+            }
+            assert!(process.execute_op(Operation::AdvPop).is_err());
+        }
+
+        // indices which do not form a permutation are rejected
+        for indices in [&[0, 0][..], &[0, 2], &[1, 2, 3]] {
+            let mut process = new_process_with_indices(indices);
+            let count = indices.len() as u32;
+            let injector = Decorator::Advice(AdviceInjector::InversePermutation { count });
+            assert!(process.execute_decorator(&injector).is_err());
+        }
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns a new process with the pointer 5 at the top of the stack, and the provided indices
+    /// written as the first elements of the words in memory starting at this address.
+    fn new_process_with_indices(indices: &[u64]) -> Process<DefaultHost<MemAdviceProvider>> {
+        let ptr = 5;
+        let stack_inputs = StackInputs::try_from_values([ptr]).unwrap();
+        let mut process = Process::new_dummy_with_inputs_and_decoder_helpers(
+            stack_inputs,
+            AdviceInputs::default(),
+        );
+        for (i, &index) in indices.iter().enumerate() {
+            process.chiplets.write_mem(
+                0,
+                ptr as u32 + i as u32,
+                [Felt::new(index), ZERO, ZERO, ONE],
+            );
+        }
+        process
+    }
+
     fn store_value<H>(process: &mut Process<H>, addr: u64, value: [Felt; 4])
     where
         H: Host,
@@ -1,5 +1,7 @@
 use crate::build_test;
-use test_utils::test_case;
+use miden_air::{Felt, StarkField};
+use processor::Digest;
+use test_utils::{crypto::MerkleStore, test_case, Word};
 
 // Wrapper types for ease of writing parameterized test cases
 struct FieldElement([u32; 8]);
@@ -46,3 +48,44 @@ fn verify(pubkey: Point, h: ScalarField, r: ScalarField, s: ScalarField) {
     let test = build_test!(source, &stack);
     assert!(test.execute().is_ok());
 }
+
+/// Checks that the signature data produced by the `adv.push_sig.ecdsa_secp256k1` decorator is
+/// accepted by `std::crypto::dsa::ecdsa::secp256k1::verify`. Like the test above, this test is
+/// expensive and is ignored by default.
+#[test]
+#[ignore]
+fn verify_with_advice_signature() {
+    let source = "
+    use.std::crypto::dsa::ecdsa::secp256k1
+
+    begin
+        adv.push_sig.ecdsa_secp256k1
+        adv_push.16 adv_push.16 adv_push.16
+        exec.secp256k1::verify
+        dropw dropw
+    end";
+
+    // an arbitrary word under which the secret key is stored in the advice map
+    let pk_key: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let pk_key: Digest = pk_key.into();
+
+    // secret key expressed as eight 32-bit limbs in little-endian order
+    let sk = [
+        0x9e3779b9u64,
+        0x7f4a7c15,
+        0x6a09e667,
+        0xbb67ae85,
+        0x3c6ef372,
+        0xa54ff53a,
+        0x510e527f,
+        0x1f83d9ab,
+    ];
+    let advice_map = vec![(pk_key.as_bytes(), sk.iter().map(|v| Felt::new(*v)).collect())];
+
+    let message = [5u64, 6, 7, 8];
+    let mut op_stack = message.to_vec();
+    op_stack.extend(pk_key.as_elements().iter().map(|v| v.as_int()));
+
+    let test = build_test!(source, &op_stack, &[], MerkleStore::new(), advice_map.into_iter());
+    assert!(test.execute().is_ok());
+}
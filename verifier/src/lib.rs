@@ -1,10 +1,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use air::{HashFunction, ProcessorAir, PublicInputs};
+use air::{trace::MIN_TRACE_LEN, HashFunction, ProcessorAir};
 use core::fmt;
-use vm_core::crypto::{
-    hash::{Blake3_192, Blake3_256, Rpo256},
-    random::{RpoRandomCoin, WinterRandomCoin},
+use vm_core::{
+    crypto::{
+        hash::{Blake3_192, Blake3_256, Rpo256},
+        random::{RpoRandomCoin, WinterRandomCoin},
+    },
+    stack::{strip_padding, STACK_TOP_SIZE},
+    utils::{collections::Vec, string::String, Box},
+    Felt, StarkField,
 };
 use winter_verifier::verify as verify_proof;
 
@@ -16,7 +21,7 @@ pub use winter_verifier::VerifierError;
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, PublicInputs};
 
 // VERIFIER
 // ================================================================================================
@@ -36,19 +41,155 @@ pub use air::ExecutionProof;
 /// `stack_outputs` slice, and the order of the rest of the output elements will also match the
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
+/// Stack outputs must always be supplied by the caller: an [ExecutionProof] does not contain
+/// public inputs, and thus the outputs a proof commits to cannot be recovered from the proof
+/// itself.
+///
+/// The shortest execution trace the AIR can handle is [MIN_TRACE_LEN] rows long; shorter
+/// executions (including that of an empty program) are padded to this length by the prover.
+///
 /// # Errors
-/// Returns an error if the provided proof does not prove a correct execution of the program.
+/// Returns an error if the provided proof does not prove a correct execution of the program. The
+/// returned error is wrapped in [VerificationError::Context] carrying the hash of the program.
 pub fn verify(
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
 ) -> Result<u32, VerificationError> {
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    verify_public_inputs(pub_inputs, proof)
+}
+
+/// Same as [verify()], but takes the hash of the program instead of its [ProgramInfo] (the
+/// program is assumed to be executed against an empty kernel), and the stack inputs and outputs
+/// as raw integers.
+///
+/// `stack_inputs` and `stack_outputs` follow the same ordering conventions as in [verify()]:
+/// the last value in `stack_inputs` is expected to be at the top of the stack at the start of
+/// execution, while the first value in `stack_outputs` is expected to be at the top of the stack
+/// at the end of execution. At most 16 stack outputs can be provided.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the stack inputs or outputs is not a valid field element.
+/// - More than 16 stack outputs are provided.
+/// - The provided proof does not prove a correct execution of the program.
+///
+/// All errors are wrapped in [VerificationError::Context] carrying the hash of the program.
+pub fn verify_ints(
+    program_hash: Digest,
+    stack_inputs: &[u64],
+    stack_outputs: &[u64],
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    // make sure all provided values are valid field elements
+    if let Some(&v) = stack_inputs.iter().find(|&&v| v >= Felt::MODULUS) {
+        return Err(VerificationError::InputNotFieldElement(v).with_program_hash(program_hash));
+    }
+    if let Some(&v) = stack_outputs.iter().find(|&&v| v >= Felt::MODULUS) {
+        return Err(VerificationError::OutputNotFieldElement(v).with_program_hash(program_hash));
+    }
+    if stack_outputs.len() > STACK_TOP_SIZE {
+        return Err(
+            VerificationError::TooManyOutputs(stack_outputs.len()).with_program_hash(program_hash)
+        );
+    }
+
+    // stack inputs are reversed into a stack, while stack outputs are already in stack order
+    let stack_inputs = StackInputs::new(stack_inputs.iter().map(|&v| Felt::new(v)).collect());
+    let stack_outputs = StackOutputs::new(stack_outputs.to_vec(), Vec::new())
+        .expect("stack outputs should be valid");
+
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Verifies the proofs of a program executed as a sequence of linked segments (continuations).
+///
+/// Each segment is verified in the same way as with [verify()]. In addition, the stack inputs of
+/// every segment after the first one must match the stack outputs of the previous segment, i.e.,
+/// each segment must continue execution from the stack state the previous segment ended in. Stack
+/// states are compared with the value at the top of the stack first, and any zeros at the bottom
+/// of the stack are ignored, since the stack is implicitly padded with zeros. All links are
+/// checked before any of the proofs is verified. An empty list of segments is trivially valid.
+///
+/// # Errors
+/// Returns an error if:
+/// - The stack inputs of a segment do not match the stack outputs of the previous segment.
+/// - The proof of any segment does not prove a correct execution of its program; such errors are
+///   wrapped in [VerificationError::Context] carrying the hash of the program of the segment.
+pub fn verify_continuation(segments: &[ContinuationSegment]) -> Result<(), VerificationError> {
+    // make sure each segment starts in the state the previous segment ended in
+    for (index, pair) in segments.windows(2).enumerate() {
+        let outputs = pair[0].stack_outputs.stack();
+        let inputs = pair[1].stack_inputs.values().iter().map(|v| v.as_int()).collect::<Vec<_>>();
+        if strip_padding(outputs) != strip_padding(&inputs) {
+            return Err(VerificationError::ContinuationLinkMismatch(index + 1));
+        }
+    }
+
+    for segment in segments {
+        verify(
+            segment.program_info.clone(),
+            segment.stack_inputs.clone(),
+            segment.stack_outputs.clone(),
+            segment.proof.clone(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// A single segment of a program executed as a sequence of linked segments, as verified by
+/// [verify_continuation()].
+#[derive(Debug, Clone)]
+pub struct ContinuationSegment {
+    /// Information about the program executed in this segment.
+    pub program_info: ProgramInfo,
+    /// Stack inputs of this segment; these must match the stack outputs of the previous segment.
+    pub stack_inputs: StackInputs,
+    /// Stack outputs of this segment.
+    pub stack_outputs: StackOutputs,
+    /// Proof of the execution of this segment.
+    pub proof: ExecutionProof,
+}
+
+/// Same as [verify()], but takes the public inputs of the proof as a single [PublicInputs]
+/// instance rather than building it from the program info, stack inputs and stack outputs.
+///
+/// This is useful for callers which already hold the public inputs, e.g., when verifying a proof
+/// recursively.
+///
+/// # Errors
+/// Returns an error if the provided proof does not prove a correct execution of the program
+/// described by the public inputs.
+pub fn verify_with_public_inputs(
+    pub_inputs: PublicInputs,
+    proof: ExecutionProof,
+) -> Result<(), VerificationError> {
+    verify_public_inputs(pub_inputs, proof)?;
+    Ok(())
+}
+
+/// Verifies the proof against the specified public inputs and returns the security level of the
+/// proof.
+fn verify_public_inputs(
+    pub_inputs: PublicInputs,
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let program_hash = *pub_inputs.program_info().program_hash();
+
+    // make sure the trace described by the proof is long enough for the AIR to handle
+    let trace_len = proof.stark_proof().trace_length();
+    if trace_len < MIN_TRACE_LEN {
+        return Err(VerificationError::TraceTooShort(trace_len).with_program_hash(program_hash));
+    }
+
     // get security level of the proof
     let security_level = proof.security_level();
 
-    // build public inputs and try to verify the proof
-    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    // try to verify the proof
     let (hash_fn, proof) = proof.into_parts();
     match hash_fn {
         HashFunction::Blake3_192 => {
@@ -61,11 +202,140 @@ pub fn verify(
             verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin>(proof, pub_inputs)
         }
     }
-    .map_err(VerificationError::VerifierError)?;
+    .map_err(|err| VerificationError::VerifierError(err).with_program_hash(program_hash))?;
 
     Ok(security_level)
 }
 
+/// Same as [verify()], but also measures the time it takes to verify the proof.
+///
+/// The returned [VerifyStats] contain the security level of the proof (as returned by [verify()]),
+/// the time elapsed while verifying the proof, and the size of the serialized proof.
+///
+/// # Errors
+/// Returns an error if the provided proof does not prove a correct execution of the program.
+#[cfg(feature = "metrics")]
+pub fn verify_timed(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<VerifyStats, VerificationError> {
+    let proof_size = proof.to_bytes().len();
+
+    let now = std::time::Instant::now();
+    let security_level = verify(program_info, stack_inputs, stack_outputs, proof)?;
+    let elapsed = now.elapsed();
+
+    Ok(VerifyStats {
+        security_level,
+        elapsed,
+        proof_size,
+    })
+}
+
+/// Statistics of a successful proof verification returned by [verify_timed()].
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyStats {
+    /// Security level of the verified proof.
+    pub security_level: u32,
+    /// Time elapsed while verifying the proof; the number of microseconds can be obtained via
+    /// [Duration::as_micros()](std::time::Duration::as_micros).
+    pub elapsed: std::time::Duration,
+    /// Size of the serialized proof in bytes.
+    pub proof_size: usize,
+}
+
+/// Same as [verify()], but takes the proof in its serialized form and the hash of the program
+/// instead of its [ProgramInfo] (the program is assumed to be executed against an empty kernel).
+///
+/// This is intended for testing the verifier against arbitrary, potentially malformed, proofs:
+/// any panic raised while deserializing or verifying the proof is caught and reported as
+/// [VerificationError::MalformedProof], so this function never panics.
+///
+/// # Errors
+/// Returns an error if the bytes cannot be deserialized into an [ExecutionProof], or if the proof
+/// does not prove a correct execution of the program.
+#[cfg(any(test, feature = "testing"))]
+pub fn verify_raw(
+    program_hash: Digest,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    bytes: &[u8],
+) -> Result<u32, VerificationError> {
+    use std::{panic, string::ToString};
+
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    panic::catch_unwind(move || {
+        let proof = ExecutionProof::from_bytes(bytes).map_err(|err| {
+            VerificationError::MalformedProof(err.to_string()).with_program_hash(program_hash)
+        })?;
+        verify(program_info, stack_inputs, stack_outputs, proof)
+    })
+    .unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "verifier panicked".to_string());
+        Err(VerificationError::MalformedProof(message).with_program_hash(program_hash))
+    })
+}
+
+// PROOF INFO
+// ================================================================================================
+
+/// Basic parameters of an [ExecutionProof] which can be read without verifying the proof.
+///
+/// This is useful for inspecting a proof before spending resources on its verification - e.g., to
+/// reject proofs whose trace length exceeds some policy limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofInfo {
+    /// Length of the execution trace (number of rows) described by the proof.
+    pub trace_len: usize,
+    /// Number of queries made against the committed trace.
+    pub num_queries: usize,
+    /// Blowup factor of the low-degree extension domain.
+    pub blowup_factor: usize,
+    /// Folding factor used in the FRI protocol.
+    pub fri_folding_factor: usize,
+    /// Number of proof-of-work bits used for query seed grinding.
+    pub grinding_bits: u32,
+}
+
+/// Returns basic parameters of the specified proof.
+///
+/// The returned values are read directly from the proof and are NOT authenticated: the proof
+/// itself may still fail verification.
+///
+/// The hash of the program a proof was generated for cannot be read from the proof: an
+/// [ExecutionProof] does not contain public inputs, and the program hash is bound to the proof
+/// only through the public inputs supplied to [verify()]. Thus, a proof cannot be routed by
+/// program before it is verified; the caller must know which program the proof is for.
+pub fn proof_info(proof: &ExecutionProof) -> ProofInfo {
+    let stark_proof = proof.stark_proof();
+    let options = stark_proof.options();
+    ProofInfo {
+        trace_len: stark_proof.trace_length(),
+        num_queries: options.num_queries(),
+        blowup_factor: options.blowup_factor(),
+        fri_folding_factor: options.to_fri_options().folding_factor(),
+        grinding_bits: options.grinding_factor(),
+    }
+}
+
+/// Returns the degree of the field extension used by the specified proof.
+///
+/// The returned value is 1 if the proof was generated over the base field, 2 if it was generated
+/// over the quadratic extension field, and 3 if it was generated over the cubic extension field.
+///
+/// As with [proof_info()], the returned value is read from the proof options and is NOT
+/// authenticated.
+pub fn proof_extension_degree(proof: &ExecutionProof) -> u32 {
+    proof.stark_proof().options().field_extension().degree()
+}
+
 // ERRORS
 // ================================================================================================
 
@@ -75,6 +345,32 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    /// More stack outputs were provided than can be verified without the overflow table
+    /// addresses; the contained value is the number of provided outputs.
+    TooManyOutputs(usize),
+    /// The trace length described by the proof is smaller than [MIN_TRACE_LEN].
+    TraceTooShort(usize),
+    /// The proof could not be deserialized, or is malformed in a way the STARK verifier cannot
+    /// handle.
+    MalformedProof(String),
+    /// The stack inputs of the continuation segment at the contained index do not match the stack
+    /// outputs of the previous segment.
+    ContinuationLinkMismatch(usize),
+    /// Wraps an error with the hash of the program whose proof failed to verify.
+    Context {
+        program_hash: Digest,
+        source: Box<VerificationError>,
+    },
+}
+
+impl VerificationError {
+    /// Wraps this error with the hash of the program whose proof failed to verify.
+    pub fn with_program_hash(self, program_hash: Digest) -> Self {
+        Self::Context {
+            program_hash,
+            source: Box::new(self),
+        }
+    }
 }
 
 impl fmt::Display for VerificationError {
@@ -84,9 +380,98 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            TooManyOutputs(len) => {
+                write!(f, "expected at most {STACK_TOP_SIZE} stack outputs, but got {len}")
+            }
+            TraceTooShort(len) => write!(
+                f,
+                "trace length must be at least {MIN_TRACE_LEN}, but the proof describes a trace of length {len}"
+            ),
+            MalformedProof(reason) => write!(f, "the proof is malformed: {reason}"),
+            ContinuationLinkMismatch(index) => write!(
+                f,
+                "the stack inputs of segment {index} do not match the stack outputs of segment {}",
+                index - 1
+            ),
+            Context {
+                program_hash,
+                source,
+            } => write!(f, "failed to verify proof for program {program_hash}: {source}"),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for VerificationError {}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{verify_raw, Digest, StackInputs, StackOutputs, VerificationError, VerifierError};
+    use proptest::prelude::*;
+    use vm_core::{Felt, ZERO};
+
+    #[test]
+    fn verification_error_context_display() {
+        let program_hash =
+            Digest::new([Felt::new(1), Felt::new(2), ZERO, Felt::new(u32::MAX as u64)]);
+        let err = VerificationError::VerifierError(VerifierError::InconsistentBaseField)
+            .with_program_hash(program_hash);
+
+        let hex: String = program_hash.into();
+        let message = err.to_string();
+        assert!(message.contains(&hex));
+        assert!(message.ends_with(&VerifierError::InconsistentBaseField.to_string()));
+    }
+
+    #[test]
+    fn verify_raw_catches_panics() {
+        // the proof options in this proof specify zero queries, which makes the STARK proof
+        // deserializer panic rather than return an error
+        #[rustfmt::skip]
+        let proof_bytes = [
+            // hash function
+            0,
+            // trace layout: main trace width, auxiliary segment width and random elements
+            1, 0, 0,
+            // log2 of the trace length, trace metadata length, and field modulus
+            3, 0, 0, 1, 1,
+            // proof options: number of queries, blowup factor, grinding factor, field extension,
+            // FRI folding factor, and FRI remainder max degree
+            0, 8, 0, 1, 8, 255,
+        ];
+
+        let program_hash = Digest::new([Felt::new(1), Felt::new(2), ZERO, ZERO]);
+        let err =
+            verify_raw(program_hash, StackInputs::default(), StackOutputs::default(), &proof_bytes)
+                .unwrap_err();
+        let VerificationError::Context { source, .. } = err else {
+            panic!("expected the error to carry the program hash, but got {err:?}");
+        };
+        assert!(matches!(*source, VerificationError::MalformedProof(_)));
+    }
+
+    proptest! {
+        #[test]
+        fn verify_raw_malformed_proof(
+            hash_fn in 0_u8..4,
+            bytes in prop::collection::vec(any::<u8>(), 0..2048),
+        ) {
+            // the first byte encodes the hash function; make sure most inputs get past it so that
+            // the deserialization of the STARK proof itself is exercised
+            let mut proof_bytes = vec![hash_fn];
+            proof_bytes.extend(bytes);
+
+            let program_hash = Digest::new([Felt::new(1), Felt::new(2), ZERO, ZERO]);
+            let result = verify_raw(
+                program_hash,
+                StackInputs::default(),
+                StackOutputs::default(),
+                &proof_bytes,
+            );
+            prop_assert!(result.is_err());
+        }
+    }
+}